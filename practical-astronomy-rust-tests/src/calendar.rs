@@ -0,0 +1,132 @@
+use practical_astronomy_rust::calendar as CAL;
+
+/// Test conversion of a Gregorian date to and from the Julian, Hebrew, Islamic, Persian, Mayan
+/// and French Republican calendars.
+///
+/// ## Input
+///
+/// Gregorian date, and the expected date in each calendar (day, month, year).
+#[allow(dead_code)]
+pub fn test_calendar_conversions(
+    gregorian: (u32, u32, i64),
+    expected_julian_day_number: i64,
+    expected_julian: (u32, u32, i64),
+    expected_hebrew: (u32, u32, i64),
+    expected_islamic: (u32, u32, i64),
+    expected_persian: (u32, u32, i64),
+    expected_mayan: (i64, u32, u32, u32, u32),
+) {
+    let (day, month, year) = gregorian;
+    let julian_day_number = CAL::gregorian_to_julian_day_number(day, month, year);
+    let julian = CAL::julian_day_number_to_julian(julian_day_number);
+    let hebrew = CAL::julian_day_number_to_hebrew(julian_day_number);
+    let islamic = CAL::julian_day_number_to_islamic(julian_day_number);
+    let persian = CAL::julian_day_number_to_persian(julian_day_number);
+    let mayan = CAL::julian_day_number_to_mayan_long_count(julian_day_number);
+
+    println!(
+		"Calendar conversions: [Gregorian] {}/{}/{} = [JDN] {} [Julian] {:?} [Hebrew] {:?} [Islamic] {:?} [Persian] {:?} [Mayan] {:?}",
+		month, day, year, julian_day_number, julian, hebrew, islamic, persian, mayan
+	);
+
+    assert_eq!(
+        julian_day_number, expected_julian_day_number,
+        "Julian Day Number"
+    );
+    assert_eq!(
+        CAL::julian_day_number_to_gregorian(julian_day_number),
+        gregorian,
+        "Gregorian"
+    );
+    assert_eq!(julian, expected_julian, "Julian");
+    assert_eq!(hebrew, expected_hebrew, "Hebrew");
+    assert_eq!(islamic, expected_islamic, "Islamic");
+    assert_eq!(persian, expected_persian, "Persian");
+    assert_eq!(mayan, expected_mayan, "Mayan long count");
+
+    assert_eq!(
+        CAL::julian_to_julian_day_number(julian.0, julian.1, julian.2),
+        julian_day_number,
+        "Julian (reverse)"
+    );
+    assert_eq!(
+        CAL::hebrew_to_julian_day_number(hebrew.0, hebrew.1, hebrew.2),
+        julian_day_number,
+        "Hebrew (reverse)"
+    );
+    assert_eq!(
+        CAL::islamic_to_julian_day_number(islamic.0, islamic.1, islamic.2),
+        julian_day_number,
+        "Islamic (reverse)"
+    );
+    assert_eq!(
+        CAL::persian_to_julian_day_number(persian.0, persian.1, persian.2),
+        julian_day_number,
+        "Persian (reverse)"
+    );
+    assert_eq!(
+        CAL::mayan_long_count_to_julian_day_number(mayan.0, mayan.1, mayan.2, mayan.3, mayan.4),
+        julian_day_number,
+        "Mayan long count (reverse)"
+    );
+}
+
+/// Test conversion of a Gregorian date to and from the Chinese calendar.
+#[allow(dead_code)]
+pub fn test_chinese_calendar(gregorian: (u32, u32, i64), expected: CAL::ChineseDate) {
+    let (day, month, year) = gregorian;
+    let julian_day_number = CAL::gregorian_to_julian_day_number(day, month, year);
+    let chinese = CAL::julian_day_number_to_chinese(julian_day_number);
+
+    println!(
+        "Chinese calendar: [Gregorian] {}/{}/{} = [Chinese] {:?}",
+        month, day, year, chinese
+    );
+
+    assert_eq!(chinese, expected, "Chinese date");
+    assert_eq!(
+        CAL::chinese_to_julian_day_number(&chinese),
+        julian_day_number,
+        "Chinese date (reverse)"
+    );
+}
+
+/// Test conversion of a Gregorian date to and from the French Republican calendar.
+#[allow(dead_code)]
+pub fn test_french_republican_calendar(gregorian: (u32, u32, i64), expected: (u32, u32, i64)) {
+    let (day, month, year) = gregorian;
+    let julian_day_number = CAL::gregorian_to_julian_day_number(day, month, year);
+    let french = CAL::julian_day_number_to_french_republican(julian_day_number);
+
+    println!(
+        "French Republican calendar: [Gregorian] {}/{}/{} = [French Republican] {:?}",
+        month, day, year, french
+    );
+
+    assert_eq!(french, expected, "French Republican date");
+    assert_eq!(
+        CAL::french_republican_to_julian_day_number(french.0, french.1, french.2),
+        julian_day_number,
+        "French Republican date (reverse)"
+    );
+}
+
+/// Test conversion of a Gregorian date to and from the Persian calendar.
+#[allow(dead_code)]
+pub fn test_persian_calendar(gregorian: (u32, u32, i64), expected: (u32, u32, i64)) {
+    let (day, month, year) = gregorian;
+    let julian_day_number = CAL::gregorian_to_julian_day_number(day, month, year);
+    let persian = CAL::julian_day_number_to_persian(julian_day_number);
+
+    println!(
+        "Persian calendar: [Gregorian] {}/{}/{} = [Persian] {:?}",
+        month, day, year, persian
+    );
+
+    assert_eq!(persian, expected, "Persian date");
+    assert_eq!(
+        CAL::persian_to_julian_day_number(persian.0, persian.1, persian.2),
+        julian_day_number,
+        "Persian date (reverse)"
+    );
+}
@@ -1,4 +1,5 @@
 use practical_astronomy_rust::coordinates as CS;
+use practical_astronomy_rust::types as pa_types;
 use practical_astronomy_rust::util;
 
 pub struct TestAngleDecimalDegreesScaffold {
@@ -498,6 +499,189 @@ impl TestGalacticScaffold {
     }
 }
 
+pub struct TestSupergalacticScaffold {
+    pub ra_hours: f64,
+    pub ra_minutes: f64,
+    pub ra_seconds: f64,
+    pub dec_degrees: f64,
+    pub dec_minutes: f64,
+    pub dec_seconds: f64,
+}
+impl TestSupergalacticScaffold {
+    #[allow(dead_code)]
+    pub fn test_equatorial_coordinate_to_supergalactic_coordinate(&mut self) {
+        let (sg_long_deg, sg_long_min, sg_long_sec, sg_lat_deg, sg_lat_min, sg_lat_sec) =
+            CS::equatorial_coordinate_to_supergalactic_coordinate(
+                self.ra_hours,
+                self.ra_minutes,
+                self.ra_seconds,
+                self.dec_degrees,
+                self.dec_minutes,
+                self.dec_seconds,
+            );
+
+        println!(
+			"Equatorial coordinate to supergalactic coordinate: [RA] {}:{}:{} [Dec] {}d {}m {}s = [Supergalactic] [Long] {}d {}m {}s [Lat] {}d {}m {}s",
+			self.ra_hours,
+			self.ra_minutes,
+			self.ra_seconds,
+			self.dec_degrees,
+			self.dec_minutes,
+			self.dec_seconds,
+			sg_long_deg,
+			sg_long_min,
+			sg_long_sec,
+			sg_lat_deg,
+			sg_lat_min,
+			sg_lat_sec
+		);
+
+        assert_eq!(sg_long_deg, 102.0, "Supergalactic Longitude Degrees");
+        assert_eq!(sg_long_min, 52.0, "Supergalactic Longitude Minutes");
+        assert_eq!(sg_long_sec, 49.99, "Supergalactic Longitude Seconds");
+        assert_eq!(sg_lat_deg, -2.0, "Supergalactic Latitude Degrees");
+        assert_eq!(sg_lat_min, 20.0, "Supergalactic Latitude Minutes");
+        assert_eq!(sg_lat_sec, 52.86, "Supergalactic Latitude Seconds");
+    }
+
+    #[allow(dead_code)]
+    pub fn test_supergalactic_coordinate_to_equatorial_coordinate(&mut self) {
+        let (sg_long_deg, sg_long_min, sg_long_sec, sg_lat_deg, sg_lat_min, sg_lat_sec) =
+            CS::equatorial_coordinate_to_supergalactic_coordinate(
+                self.ra_hours,
+                self.ra_minutes,
+                self.ra_seconds,
+                self.dec_degrees,
+                self.dec_minutes,
+                self.dec_seconds,
+            );
+
+        let (ra_hours, ra_minutes, ra_seconds, dec_degrees, dec_minutes, dec_seconds) =
+            CS::supergalactic_coordinate_to_equatorial_coordinate(
+                sg_long_deg,
+                sg_long_min,
+                sg_long_sec,
+                sg_lat_deg,
+                sg_lat_min,
+                sg_lat_sec,
+            );
+
+        println!(
+			"Supergalactic coordinate to equatorial coordinate: [Supergalactic] [Long] {}d {}m {}s [Lat] {}d {}m {}s = [RA] {}:{}:{} [Dec] {}d {}m {}s",
+			sg_long_deg,
+			sg_long_min,
+			sg_long_sec,
+			sg_lat_deg,
+			sg_lat_min,
+			sg_lat_sec,
+			ra_hours,
+			ra_minutes,
+			ra_seconds,
+			dec_degrees,
+			dec_minutes,
+			dec_seconds,
+		);
+
+        assert_eq!(ra_hours, 12.0, "Right Ascension Hours");
+        assert_eq!(ra_minutes, 30.0, "Right Ascension Minutes");
+        assert_eq!(ra_seconds, 49.4, "Right Ascension Seconds");
+        assert_eq!(dec_degrees, 12.0, "Declination Degrees");
+        assert_eq!(dec_minutes, 23.0, "Declination Minutes");
+        assert_eq!(dec_seconds, 28.0, "Declination Seconds");
+    }
+}
+
+#[allow(dead_code)]
+pub fn test_heliocentric_and_geocentric_ecliptic(
+    helio_ecl_long_deg: f64,
+    helio_ecl_lat_deg: f64,
+    helio_distance_au: f64,
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+) {
+    let (geo_ecl_long_deg, geo_ecl_lat_deg, geo_distance_au) =
+        CS::heliocentric_ecliptic_to_geocentric_ecliptic(
+            helio_ecl_long_deg,
+            helio_ecl_lat_deg,
+            helio_distance_au,
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::EclipticEquinox::OfDate,
+        );
+
+    let (j2000_ecl_long_deg, j2000_ecl_lat_deg) = CS::ecliptic_coordinate_change_of_equinox(
+        geo_ecl_long_deg,
+        geo_ecl_lat_deg,
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        pa_types::EclipticEquinox::OfDate,
+        pa_types::EclipticEquinox::J2000,
+    );
+
+    let (back_helio_long_deg, back_helio_lat_deg, back_helio_distance_au) =
+        CS::geocentric_ecliptic_to_heliocentric_ecliptic(
+            j2000_ecl_long_deg,
+            j2000_ecl_lat_deg,
+            geo_distance_au,
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::EclipticEquinox::J2000,
+        );
+
+    println!(
+		"Heliocentric and geocentric ecliptic: [Heliocentric, Of Date] {}d {}d {} AU [Greenwich Date] {}/{}/{} = [Geocentric, Of Date] {}d {}d {} AU [Geocentric, J2000] {}d {}d [Heliocentric, J2000] {}d {}d {} AU",
+		helio_ecl_long_deg,
+		helio_ecl_lat_deg,
+		helio_distance_au,
+		greenwich_month,
+		greenwich_day,
+		greenwich_year,
+		geo_ecl_long_deg,
+		geo_ecl_lat_deg,
+		geo_distance_au,
+		j2000_ecl_long_deg,
+		j2000_ecl_lat_deg,
+		back_helio_long_deg,
+		back_helio_lat_deg,
+		back_helio_distance_au
+	);
+
+    assert_eq!(
+        util::round_f64(geo_ecl_long_deg, 4),
+        313.0834,
+        "Geocentric Ecliptic Longitude, Of Date (degrees)"
+    );
+    assert_eq!(
+        util::round_f64(geo_ecl_lat_deg, 4),
+        -2.0846,
+        "Geocentric Ecliptic Latitude, Of Date (degrees)"
+    );
+    assert_eq!(
+        util::round_f64(geo_distance_au, 4),
+        0.9108,
+        "Geocentric Distance (AU)"
+    );
+    assert_eq!(
+        util::round_f64(j2000_ecl_long_deg, 4),
+        313.1816,
+        "Geocentric Ecliptic Longitude, J2000 (degrees)"
+    );
+    assert_eq!(
+        util::round_f64(back_helio_long_deg, 4),
+        26.2126,
+        "Heliocentric Ecliptic Longitude, J2000 (degrees)"
+    );
+    assert_eq!(
+        util::round_f64(back_helio_distance_au, 6),
+        helio_distance_au,
+        "Heliocentric Distance (AU)"
+    );
+}
+
 #[allow(dead_code)]
 pub fn test_angle_between_two_objects(
     ra_long_1_hour_deg: f64,
@@ -724,6 +908,99 @@ pub fn test_nutation_in_ecliptic_longitude_and_obliquity(
     assert_eq!(nut_in_obl_deg, 0.0025671, "Nutation in Obliquity (degrees)");
 }
 
+#[allow(dead_code)]
+pub fn test_nutation_in_ecliptic_longitude_and_obliquity_by_model(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+) {
+    let (nut_in_long_deg_1980, nut_in_obl_deg_1980) =
+        CS::nutation_in_ecliptic_longitude_and_obliquity_by_model(
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::NutationModel::Iau1980,
+        );
+    let (nut_in_long_deg_2000b, nut_in_obl_deg_2000b) =
+        CS::nutation_in_ecliptic_longitude_and_obliquity_by_model(
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::NutationModel::Iau2000B,
+        );
+
+    let nut_in_long_arcsec_1980 = util::round_f64(nut_in_long_deg_1980 * 3600.0, 3);
+    let nut_in_obl_arcsec_1980 = util::round_f64(nut_in_obl_deg_1980 * 3600.0, 3);
+    let nut_in_long_arcsec_2000b = util::round_f64(nut_in_long_deg_2000b * 3600.0, 3);
+    let nut_in_obl_arcsec_2000b = util::round_f64(nut_in_obl_deg_2000b * 3600.0, 3);
+
+    println!(
+		"Nutation by model: [Greenwich Date] {}/{}/{} = [IAU 1980] [Longitude] {}\" [Obliquity] {}\" [IAU 2000B] [Longitude] {}\" [Obliquity] {}\"",
+		greenwich_month,
+		greenwich_day,
+		greenwich_year,
+		nut_in_long_arcsec_1980,
+		nut_in_obl_arcsec_1980,
+		nut_in_long_arcsec_2000b,
+		nut_in_obl_arcsec_2000b
+	);
+
+    assert_eq!(
+        nut_in_long_arcsec_1980, -3.788,
+        "IAU 1980 Nutation in Longitude (arcsec)"
+    );
+    assert_eq!(
+        nut_in_obl_arcsec_1980, 9.442,
+        "IAU 1980 Nutation in Obliquity (arcsec)"
+    );
+    assert_eq!(
+        nut_in_long_arcsec_2000b, -3.781,
+        "IAU 2000B Nutation in Longitude (arcsec)"
+    );
+    assert_eq!(
+        nut_in_obl_arcsec_2000b, 9.446,
+        "IAU 2000B Nutation in Obliquity (arcsec)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_true_obliquity_and_equation_of_the_equinoxes(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+) {
+    let true_obliquity_deg = util::round_f64(
+        CS::true_obliquity_of_the_ecliptic(
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::NutationModel::Iau1980,
+        ),
+        6,
+    );
+    let eq_eq_sec = util::round_f64(
+        CS::equation_of_the_equinoxes(
+            greenwich_day,
+            greenwich_month,
+            greenwich_year,
+            pa_types::NutationModel::Iau1980,
+        ),
+        4,
+    );
+
+    println!(
+		"True obliquity and equation of the equinoxes: [Greenwich Date] {}/{}/{} = [True Obliquity] {}d [Equation of the Equinoxes] {}s",
+		greenwich_month,
+		greenwich_day,
+		greenwich_year,
+		true_obliquity_deg,
+		eq_eq_sec
+	);
+
+    assert_eq!(true_obliquity_deg, 23.44357, "True Obliquity (degrees)");
+    assert_eq!(eq_eq_sec, -0.2316, "Equation of the Equinoxes (seconds)");
+}
+
 #[allow(dead_code)]
 pub fn test_correct_for_aberration(
     ut_hour: f64,
@@ -897,6 +1174,212 @@ pub fn test_atmospheric_refraction(
     assert_eq!(corrected_dec_sec, 45.76, "Corrected Declination Seconds");
 }
 
+#[allow(dead_code)]
+pub fn test_atmospheric_refraction_by_model(
+    true_ra_hour: f64,
+    true_ra_min: f64,
+    true_ra_sec: f64,
+    true_dec_deg: f64,
+    true_dec_min: f64,
+    true_dec_sec: f64,
+    coordinate_type: pa_types::CoordinateType,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    daylight_saving_hours: i32,
+    timezone_hours: i32,
+    lcd_day: f64,
+    lcd_month: u32,
+    lcd_year: u32,
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+    wavelength_micrometres: f64,
+) {
+    let (
+        corrected_ra_hour,
+        corrected_ra_min,
+        corrected_ra_sec,
+        corrected_dec_deg,
+        corrected_dec_min,
+        corrected_dec_sec,
+    ) = CS::atmospheric_refraction_by_model(
+        true_ra_hour,
+        true_ra_min,
+        true_ra_sec,
+        true_dec_deg,
+        true_dec_min,
+        true_dec_sec,
+        coordinate_type,
+        geog_long_deg,
+        geog_lat_deg,
+        daylight_saving_hours,
+        timezone_hours,
+        lcd_day,
+        lcd_month,
+        lcd_year,
+        lct_hour,
+        lct_min,
+        lct_sec,
+        atmospheric_pressure_mbar,
+        atmospheric_temperature_celsius,
+        wavelength_micrometres,
+        pa_types::RefractionModel::RayTracing,
+    );
+
+    println!(
+		"Atmospheric refraction by model:  [RA] {}:{}:{} [DEC] {}d {}m {}s [COORD TYPE] {:?} [GEOG LON/LAT] {}d/{}d [DS HOURS] {} [TZ HOURS] {} [LCD] {}/{}/{} [LCT] {}:{}:{} [ATM] [PRESS MBR] {} [TEMP C] {} [WAVELENGTH UM] {} [MODEL] RayTracing = [CORRECTED] [RA] {}:{}:{} [DEC] {}d {}m {}s",
+		true_ra_hour,
+		true_ra_min,
+		true_ra_sec,
+		true_dec_deg,
+		true_dec_min,
+		true_dec_sec,
+		coordinate_type,
+		geog_long_deg,
+		geog_lat_deg,
+		daylight_saving_hours,
+		timezone_hours,
+		lcd_month,
+		lcd_day,
+		lcd_year,
+		lct_hour,
+		lct_min,
+		lct_sec,
+		atmospheric_pressure_mbar,
+		atmospheric_temperature_celsius,
+		wavelength_micrometres,
+		corrected_ra_hour,
+		corrected_ra_min,
+		corrected_ra_sec,
+		corrected_dec_deg,
+		corrected_dec_min,
+		corrected_dec_sec,
+	);
+
+    assert_eq!(corrected_ra_hour, 23.0, "Corrected RA Hours");
+    assert_eq!(corrected_ra_min, 13.0, "Corrected RA Minutes");
+    assert_eq!(corrected_ra_sec, 44.78, "Corrected RA Seconds");
+    assert_eq!(corrected_dec_deg, 40.0, "Corrected Declination Degrees");
+    assert_eq!(corrected_dec_min, 19.0, "Corrected Declination Minutes");
+    assert_eq!(corrected_dec_sec, 44.28, "Corrected Declination Seconds");
+}
+
+#[allow(dead_code)]
+pub fn test_refracted_altitude(
+    apparent_altitude_deg: f64,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+) {
+    let refraction_arcmin = |refraction_model: pa_types::RefractionModel,
+                             wavelength_micrometres: f64| {
+        let true_altitude_deg = CS::refracted_altitude(
+            apparent_altitude_deg,
+            pa_types::CoordinateType::Apparent,
+            atmospheric_pressure_mbar,
+            atmospheric_temperature_celsius,
+            wavelength_micrometres,
+            refraction_model,
+        );
+        util::round_f64((apparent_altitude_deg - true_altitude_deg) * 60.0, 2)
+    };
+
+    let book_arcmin = refraction_arcmin(pa_types::RefractionModel::Book, 0.55);
+    let bennett_arcmin = refraction_arcmin(pa_types::RefractionModel::Bennett, 0.55);
+    let saemundsson_arcmin = refraction_arcmin(pa_types::RefractionModel::Saemundsson, 0.55);
+    let ray_tracing_arcmin = refraction_arcmin(pa_types::RefractionModel::RayTracing, 0.55);
+    let ray_tracing_blue_arcmin = refraction_arcmin(pa_types::RefractionModel::RayTracing, 0.4);
+
+    let true_altitude_deg = CS::refracted_altitude(
+        apparent_altitude_deg,
+        pa_types::CoordinateType::Apparent,
+        atmospheric_pressure_mbar,
+        atmospheric_temperature_celsius,
+        0.55,
+        pa_types::RefractionModel::RayTracing,
+    );
+    let round_trip_altitude_deg = util::round_f64(
+        CS::refracted_altitude(
+            true_altitude_deg,
+            pa_types::CoordinateType::True,
+            atmospheric_pressure_mbar,
+            atmospheric_temperature_celsius,
+            0.55,
+            pa_types::RefractionModel::RayTracing,
+        ),
+        9,
+    );
+
+    println!(
+		"Refracted altitude: [Apparent Altitude] {}d [ATM] [PRESS MBR] {} [TEMP C] {} = [Refraction] [Book] {}m [Bennett] {}m [Saemundsson] {}m [Ray Tracing] {}m [Ray Tracing, 0.4um] {}m [Round Trip Altitude] {}d",
+		apparent_altitude_deg,
+		atmospheric_pressure_mbar,
+		atmospheric_temperature_celsius,
+		book_arcmin,
+		bennett_arcmin,
+		saemundsson_arcmin,
+		ray_tracing_arcmin,
+		ray_tracing_blue_arcmin,
+		round_trip_altitude_deg
+	);
+
+    assert_eq!(book_arcmin, 51.67, "Refraction, Book (arcminutes)");
+    assert_eq!(bennett_arcmin, 49.82, "Refraction, Bennett (arcminutes)");
+    assert_eq!(
+        saemundsson_arcmin, 44.39,
+        "Refraction, Saemundsson (arcminutes)"
+    );
+    assert_eq!(
+        ray_tracing_arcmin, 51.55,
+        "Refraction, Ray Tracing (arcminutes)"
+    );
+    assert_eq!(
+        ray_tracing_blue_arcmin, 52.61,
+        "Refraction, Ray Tracing at 0.4um (arcminutes)"
+    );
+    assert_eq!(
+        round_trip_altitude_deg, apparent_altitude_deg,
+        "Round Trip Altitude (degrees)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_refracted_altitude_round_trip(
+    apparent_altitude_deg: f64,
+    refraction_model: pa_types::RefractionModel,
+) {
+    let true_altitude_deg = CS::refracted_altitude(
+        apparent_altitude_deg,
+        pa_types::CoordinateType::Apparent,
+        1010.0,
+        10.0,
+        0.55,
+        refraction_model,
+    );
+    let round_trip_altitude_deg = util::round_f64(
+        CS::refracted_altitude(
+            true_altitude_deg,
+            pa_types::CoordinateType::True,
+            1010.0,
+            10.0,
+            0.55,
+            refraction_model,
+        ),
+        9,
+    );
+
+    println!(
+		"Refracted altitude round trip: [Apparent Altitude] {}d [Model] {:?} = [True Altitude] {}d [Round Trip Altitude] {}d",
+		apparent_altitude_deg, refraction_model, true_altitude_deg, round_trip_altitude_deg
+	);
+
+    assert_eq!(
+        round_trip_altitude_deg, apparent_altitude_deg,
+        "Round Trip Altitude (degrees)"
+    );
+}
+
 #[allow(dead_code)]
 pub fn test_corrections_for_geocentric_parallax(
     ra_hour: f64,
@@ -1028,6 +1511,83 @@ pub fn test_carrington_rotation_number(gwdate_day: f64, gwdate_month: u32, gwdat
     assert_eq!(crn, 1624, "Carrington Rotation Number");
 }
 
+#[allow(dead_code)]
+pub fn test_heliographic_to_disk_position(
+    helio_long_deg: f64,
+    helio_lat_deg: f64,
+    gwdate_day: f64,
+    gwdate_month: u32,
+    gwdate_year: u32,
+) {
+    let (helio_position_angle_deg, helio_displacement_arcmin) = CS::heliographic_to_disk_position(
+        helio_long_deg,
+        helio_lat_deg,
+        gwdate_day,
+        gwdate_month,
+        gwdate_year,
+    );
+
+    println!(
+		"Heliographic to disk position: [Helio] [Longitude] {}d [Latitude] {}d [Greenwich Date] {}/{}/{} = [Helio] [Position Angle] {}d [Displacement] {} arcmin",
+		helio_long_deg,
+		helio_lat_deg,
+		gwdate_month,
+		gwdate_day,
+		gwdate_year,
+		helio_position_angle_deg,
+		helio_displacement_arcmin
+	);
+
+    assert_eq!(
+        helio_position_angle_deg, 220.0,
+        "Heliographic Position Angle - degrees"
+    );
+    assert_eq!(
+        helio_displacement_arcmin, 10.5,
+        "Heliographic Displacement - arcmin"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_solar_physical_ephemeris(gwdate_day: f64, gwdate_month: u32, gwdate_year: u32) {
+    let (p_deg, b0_deg, l0_deg) =
+        CS::solar_physical_ephemeris(gwdate_day, gwdate_month, gwdate_year);
+
+    println!(
+        "Solar physical ephemeris: [Greenwich Date] {}/{}/{} = [P] {}d [B0] {}d [L0] {}d",
+        gwdate_month, gwdate_day, gwdate_year, p_deg, b0_deg, l0_deg
+    );
+
+    assert_eq!(p_deg, 26.27, "Position Angle of Axis (P)");
+    assert_eq!(b0_deg, 5.99, "Heliographic Latitude of Center (B0)");
+    assert_eq!(l0_deg, 238.64, "Heliographic Longitude of Center (L0)");
+}
+
+#[allow(dead_code)]
+pub fn test_carrington_rotation_start_and_end(carrington_rotation_number: i32) {
+    let (start_day, start_month, start_year, end_day, end_month, end_year) =
+        CS::carrington_rotation_start_and_end(carrington_rotation_number);
+
+    println!(
+        "Carrington rotation start and end: [CRN] {} = [Start] {}/{}/{} [End] {}/{}/{}",
+        carrington_rotation_number,
+        start_month,
+        start_day,
+        start_year,
+        end_month,
+        end_day,
+        end_year
+    );
+
+    // Universal Time: Meeus (example 29.a) gives 29.2230 August 1980 TT, and Delta T is 51 s.
+    assert_eq!(util::round_f64(start_day, 4), 29.2224, "Start Day");
+    assert_eq!(start_month, 8, "Start Month");
+    assert_eq!(start_year, 1980, "Start Year");
+    assert_eq!(util::round_f64(end_day, 4), 25.4874, "End Day");
+    assert_eq!(end_month, 9, "End Month");
+    assert_eq!(end_year, 1980, "End Year");
+}
+
 pub struct TestSelenographicScaffold {
     pub gwdate_day: f64,
     pub gwdate_month: u32,
@@ -1,5 +1,6 @@
 use practical_astronomy_rust::datetime as DT;
 use practical_astronomy_rust::macros as MA;
+use practical_astronomy_rust::types as pa_types;
 use practical_astronomy_rust::util as UT;
 
 /// Test date of Easter.
@@ -25,6 +26,52 @@ pub fn test_easter(expected_month: u32, expected_day: u32, input_year: u32) {
     assert_eq!(year, input_year, "Year of Easter");
 }
 
+/// Test date of Orthodox Easter.
+///
+/// ## Input
+///
+/// 2024
+///
+/// ## Expected Output
+///
+/// 5/5/2024
+#[allow(dead_code)]
+pub fn test_orthodox_easter(expected_month: u32, expected_day: u32, input_year: u32) {
+    let (month, day, year) = DT::get_date_of_orthodox_easter(input_year);
+
+    println!(
+        "Date of Orthodox Easter: [Year] {} = [Date of Orthodox Easter] {}/{}/{}",
+        input_year, month, day, year
+    );
+
+    assert_eq!(month, expected_month, "Month of Orthodox Easter");
+    assert_eq!(day, expected_day, "Day of Orthodox Easter");
+    assert_eq!(year, input_year, "Year of Orthodox Easter");
+}
+
+/// Test date of Passover.
+///
+/// ## Input
+///
+/// 2024
+///
+/// ## Expected Output
+///
+/// 4/23/2024
+#[allow(dead_code)]
+pub fn test_passover(expected_month: u32, expected_day: u32, input_year: u32) {
+    let (month, day, year) = DT::get_date_of_passover(input_year);
+
+    println!(
+        "Date of Passover: [Year] {} = [Date of Passover] {}/{}/{}",
+        input_year, month, day, year
+    );
+
+    assert_eq!(month, expected_month, "Month of Passover");
+    assert_eq!(day, expected_day, "Day of Passover");
+    assert_eq!(year, input_year, "Year of Passover");
+}
+
 /// Test day numbers (various)
 #[allow(dead_code)]
 pub fn test_day_numbers() {
@@ -327,6 +374,167 @@ impl TestUniversalTimeSiderealTimeScaffold {
     }
 }
 
+/// Test conversion of universal time to greenwich apparent sidereal time
+#[allow(dead_code)]
+pub fn test_universal_time_to_greenwich_apparent_sidereal_time(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) {
+    let (gast_hours, gast_minutes, gast_seconds) =
+        DT::universal_time_to_greenwich_apparent_sidereal_time(
+            ut_hours,
+            ut_minutes,
+            ut_seconds,
+            gw_day,
+            gw_month,
+            gw_year,
+            pa_types::NutationModel::Iau1980,
+        );
+
+    println!(
+		"Universal time to greenwich apparent sidereal time: [UT] {}:{}:{} [GWD] {}/{}/{} = [GAST] {}:{}:{}",
+		ut_hours,
+		ut_minutes,
+		ut_seconds,
+		gw_month,
+		gw_day,
+		gw_year,
+		gast_hours,
+		gast_minutes,
+		gast_seconds
+	);
+
+    assert_eq!(gast_hours, 13, "GAST Hours");
+    assert_eq!(gast_minutes, 10, "GAST Minutes");
+    assert_eq!(gast_seconds, 46.14, "GAST Seconds");
+}
+
+#[allow(dead_code)]
+pub fn test_greenwich_mean_and_apparent_sidereal_time_by_model(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) {
+    let (gmst_1982_hours, gmst_1982_minutes, gmst_1982_seconds) = DT::greenwich_mean_sidereal_time(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        pa_types::SiderealTimeModel::Iau1982,
+    );
+
+    let (gmst_2006_hours, gmst_2006_minutes, gmst_2006_seconds) = DT::greenwich_mean_sidereal_time(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        pa_types::SiderealTimeModel::Iau2006,
+    );
+
+    let (gast_hours, gast_minutes, gast_seconds) = DT::greenwich_apparent_sidereal_time(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        pa_types::SiderealTimeModel::Iau2006,
+        pa_types::NutationModel::Iau2000B,
+    );
+
+    println!(
+		"Greenwich mean and apparent sidereal time by model: [UT] {}:{}:{} [GWD] {}/{}/{} = [GMST IAU 1982] {}:{}:{} [GMST IAU 2006] {}:{}:{} [GAST IAU 2006/2000B] {}:{}:{}",
+		ut_hours,
+		ut_minutes,
+		ut_seconds,
+		gw_month,
+		gw_day,
+		gw_year,
+		gmst_1982_hours,
+		gmst_1982_minutes,
+		gmst_1982_seconds,
+		gmst_2006_hours,
+		gmst_2006_minutes,
+		gmst_2006_seconds,
+		gast_hours,
+		gast_minutes,
+		gast_seconds
+	);
+
+    assert_eq!(gmst_1982_hours, 8, "GMST (IAU 1982) Hours");
+    assert_eq!(gmst_1982_minutes, 34, "GMST (IAU 1982) Minutes");
+    assert_eq!(gmst_1982_seconds, 57.09, "GMST (IAU 1982) Seconds");
+    assert_eq!(gmst_2006_hours, 8, "GMST (IAU 2006) Hours");
+    assert_eq!(gmst_2006_minutes, 34, "GMST (IAU 2006) Minutes");
+    assert_eq!(gmst_2006_seconds, 57.09, "GMST (IAU 2006) Seconds");
+    assert_eq!(gast_hours, 8, "GAST Hours");
+    assert_eq!(gast_minutes, 34, "GAST Minutes");
+    assert_eq!(gast_seconds, 56.86, "GAST Seconds");
+}
+
+#[allow(dead_code)]
+pub fn test_earth_rotation_angle(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) {
+    let era_deg =
+        DT::earth_rotation_angle(ut_hours, ut_minutes, ut_seconds, gw_day, gw_month, gw_year);
+
+    println!(
+        "Earth rotation angle: [UT] {}:{}:{} [GWD] {}/{}/{} = [ERA] {}d",
+        ut_hours, ut_minutes, ut_seconds, gw_month, gw_day, gw_year, era_deg
+    );
+
+    assert_eq!(
+        UT::round_f64(era_deg, 8),
+        280.46061838,
+        "Earth Rotation Angle (degrees)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_greenwich_sidereal_time_to_universal_times(
+    gst_hours: f64,
+    gst_minutes: f64,
+    gst_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) {
+    let ut_candidates = DT::greenwich_sidereal_time_to_universal_times(
+        gst_hours,
+        gst_minutes,
+        gst_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+    );
+
+    println!(
+        "Greenwich sidereal time to universal times: [GST] {}:{}:{} [GWD] {}/{}/{} = [UT] {:?}",
+        gst_hours, gst_minutes, gst_seconds, gw_month, gw_day, gw_year, ut_candidates
+    );
+
+    assert_eq!(ut_candidates.len(), 2, "Number of UT Candidates");
+    assert_eq!(ut_candidates[0], (0, 1, 0.0), "First UT Candidate");
+    assert_eq!(ut_candidates[1], (23, 57, 4.09), "Second UT Candidate");
+}
+
 /// Universal Time <-> Sidereal Time tests.
 pub struct TestGreenwichSiderealLocalSiderealScaffold {
     pub gst_hours: f64,
@@ -411,3 +619,15 @@ pub fn test_julian_date_to_day_of_week() {
 
     assert_eq!(day_of_week, "Friday", "Day of Week");
 }
+
+#[allow(dead_code)]
+pub fn test_delta_t(gw_day: f64, gw_month: u32, gw_year: u32) {
+    let delta_t_seconds = UT::round_f64(DT::delta_t(gw_day, gw_month, gw_year), 2);
+
+    println!(
+        "Delta T: [GWD] {}/{}/{} = [Delta T] {}s",
+        gw_month, gw_day, gw_year, delta_t_seconds
+    );
+
+    assert_eq!(delta_t_seconds, 55.44, "Delta T (seconds)");
+}
@@ -1,4 +1,5 @@
 use practical_astronomy_rust::eclipses as ECL;
+use practical_astronomy_rust::types as pa_types;
 use practical_astronomy_rust::util as UTIL;
 
 pub struct TestLunarEclipseScaffold {
@@ -264,3 +265,534 @@ pub fn test_solar_eclipse_circumstances(
         "Last Contact (local hour)"
     );
 }
+
+#[allow(dead_code)]
+pub fn test_eclipse_search(start_year: u32, end_year: u32) {
+    let eclipse_events: Vec<ECL::EclipseEvent> =
+        ECL::eclipse_search(start_year, end_year).collect();
+
+    for eclipse_event in eclipse_events.iter() {
+        println!(
+			"Eclipse search: [Years] {}-{} = [Kind] {:?} [Type] {:?} [Greatest Eclipse] {}/{}/{} {}:{} UT [Gamma] {} [Magnitude] {} [Saros] {}",
+			start_year,
+			end_year,
+			eclipse_event.eclipse_kind,
+			eclipse_event.eclipse_type,
+			eclipse_event.greatest_eclipse_date_month,
+			eclipse_event.greatest_eclipse_date_day,
+			eclipse_event.greatest_eclipse_date_year,
+			eclipse_event.greatest_eclipse_ut_hour,
+			eclipse_event.greatest_eclipse_ut_minutes,
+			eclipse_event.gamma,
+			eclipse_event.magnitude,
+			eclipse_event.saros_number
+		);
+    }
+
+    assert_eq!(eclipse_events.len(), 9, "Number of Eclipses");
+
+    let total_solar = eclipse_events[3];
+    assert_eq!(
+        total_solar.eclipse_kind,
+        pa_types::EclipseKind::Solar,
+        "Eclipse Kind"
+    );
+    assert_eq!(
+        total_solar.eclipse_type,
+        pa_types::EclipseType::Total,
+        "Eclipse Type"
+    );
+    assert_eq!(
+        total_solar.greatest_eclipse_date_day, 21.0,
+        "Greatest Eclipse Day"
+    );
+    assert_eq!(
+        total_solar.greatest_eclipse_date_month, 8,
+        "Greatest Eclipse Month"
+    );
+    assert_eq!(
+        total_solar.greatest_eclipse_ut_hour, 18.0,
+        "Greatest Eclipse Hour"
+    );
+    assert_eq!(
+        total_solar.greatest_eclipse_ut_minutes, 26.0,
+        "Greatest Eclipse Minutes"
+    );
+    assert_eq!(total_solar.gamma, 0.4364, "Gamma");
+    assert_eq!(total_solar.magnitude, 1.031, "Magnitude");
+    assert_eq!(total_solar.saros_number, 145, "Saros Number");
+
+    let total_lunar = eclipse_events[4];
+    assert_eq!(
+        total_lunar.eclipse_kind,
+        pa_types::EclipseKind::Lunar,
+        "Eclipse Kind"
+    );
+    assert_eq!(
+        total_lunar.eclipse_type,
+        pa_types::EclipseType::Total,
+        "Eclipse Type"
+    );
+    assert_eq!(
+        total_lunar.greatest_eclipse_date_day, 31.0,
+        "Greatest Eclipse Day"
+    );
+    assert_eq!(
+        total_lunar.greatest_eclipse_date_month, 1,
+        "Greatest Eclipse Month"
+    );
+    assert_eq!(total_lunar.gamma, -0.302, "Gamma");
+    assert_eq!(total_lunar.magnitude, 1.3146, "Magnitude");
+    assert_eq!(total_lunar.saros_number, 124, "Saros Number");
+
+    assert_eq!(
+        eclipse_events[0].eclipse_type,
+        pa_types::EclipseType::Penumbral,
+        "Eclipse Type"
+    );
+    assert_eq!(
+        eclipse_events[1].eclipse_type,
+        pa_types::EclipseType::Annular,
+        "Eclipse Type"
+    );
+    assert_eq!(
+        eclipse_events[5].eclipse_type,
+        pa_types::EclipseType::Partial,
+        "Eclipse Type"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_solar_eclipse_path(year: u32, month: u32, step_minutes: f64) {
+    let eclipse_event = ECL::eclipse_search(year, year)
+        .find(|eclipse_event| {
+            eclipse_event.eclipse_kind == pa_types::EclipseKind::Solar
+                && eclipse_event.greatest_eclipse_date_month == month
+        })
+        .unwrap();
+
+    let besselian_elements = ECL::besselian_elements(eclipse_event.greatest_eclipse_jd_tt);
+    let solar_eclipse_path = ECL::solar_eclipse_path(&besselian_elements, step_minutes);
+    let geojson = ECL::solar_eclipse_path_to_geojson(&solar_eclipse_path);
+
+    println!(
+		"Solar eclipse path: [Eclipse] {}/{} [Step] {} minutes = [T0] {} TT [Delta T] {} [x0] {} [y0] {} [d0] {} [mu0] {} [l1] {} [l2] {} [tan f1] {} [tan f2] {} [Greatest Eclipse] {:?} [Central Line Points] {} [GeoJSON Length] {}",
+		month,
+		year,
+		step_minutes,
+		besselian_elements.t0_jd_tt,
+		besselian_elements.delta_t_seconds,
+		besselian_elements.x[0],
+		besselian_elements.y[0],
+		besselian_elements.d[0],
+		besselian_elements.mu[0],
+		besselian_elements.l1[0],
+		besselian_elements.l2[0],
+		besselian_elements.tan_f1,
+		besselian_elements.tan_f2,
+		solar_eclipse_path.greatest_eclipse_point,
+		solar_eclipse_path.central_line.len(),
+		geojson.len()
+	);
+
+    assert_eq!(besselian_elements.t0_jd_tt, 2457987.25, "T0");
+    assert_eq!(
+        UTIL::round_f64(besselian_elements.delta_t_seconds, 1),
+        70.3,
+        "Delta T"
+    );
+    assert_eq!(UTIL::round_f64(besselian_elements.x[0], 3), -0.129, "x0");
+    assert_eq!(UTIL::round_f64(besselian_elements.x[1], 4), 0.5406, "x1");
+    assert_eq!(UTIL::round_f64(besselian_elements.y[0], 3), 0.486, "y0");
+    assert_eq!(UTIL::round_f64(besselian_elements.y[1], 4), -0.1416, "y1");
+    assert_eq!(UTIL::round_f64(besselian_elements.d[0], 2), 11.87, "d0");
+    assert_eq!(UTIL::round_f64(besselian_elements.mu[0], 2), 88.95, "mu0");
+    assert_eq!(UTIL::round_f64(besselian_elements.mu[1], 3), 15.004, "mu1");
+    assert_eq!(UTIL::round_f64(besselian_elements.l1[0], 4), 0.5421, "l1");
+    assert_eq!(UTIL::round_f64(besselian_elements.l2[0], 4), -0.004, "l2");
+    assert_eq!(
+        UTIL::round_f64(besselian_elements.tan_f1, 6),
+        0.004622,
+        "tan f1"
+    );
+    assert_eq!(
+        UTIL::round_f64(besselian_elements.tan_f2, 6),
+        0.004599,
+        "tan f2"
+    );
+
+    let (longitude, latitude) = solar_eclipse_path.greatest_eclipse_point;
+    assert_eq!(
+        UTIL::round_f64(longitude, 1),
+        -87.6,
+        "Greatest Eclipse Longitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(latitude, 1),
+        37.0,
+        "Greatest Eclipse Latitude"
+    );
+
+    assert_eq!(
+        solar_eclipse_path.central_line.len(),
+        19,
+        "Central Line Points"
+    );
+    assert_eq!(
+        solar_eclipse_path.northern_umbral_limit.len(),
+        19,
+        "Northern Umbral Limit Points"
+    );
+    assert_eq!(
+        solar_eclipse_path.southern_umbral_limit.len(),
+        19,
+        "Southern Umbral Limit Points"
+    );
+
+    // The umbral limits straddle the central line.
+    let (_, central_latitude) = solar_eclipse_path.central_line[9];
+    let (_, northern_latitude) = solar_eclipse_path.northern_umbral_limit[9];
+    let (_, southern_latitude) = solar_eclipse_path.southern_umbral_limit[9];
+    assert!(
+        northern_latitude > central_latitude,
+        "Northern Umbral Limit"
+    );
+    assert!(
+        southern_latitude < central_latitude,
+        "Southern Umbral Limit"
+    );
+
+    assert!(
+        geojson.starts_with("{\"type\":\"FeatureCollection\",\"features\":["),
+        "GeoJSON"
+    );
+    assert!(
+        geojson.contains("{\"name\":\"Greatest eclipse\"},\"geometry\":{\"type\":\"Point\",\"coordinates\":[-87.6318,37.0154]}"),
+        "GeoJSON Greatest Eclipse"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_local_solar_eclipse_circumstances(
+    year: u32,
+    month: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) {
+    let eclipse_event = ECL::eclipse_search(year, year)
+        .find(|eclipse_event| {
+            eclipse_event.eclipse_kind == pa_types::EclipseKind::Solar
+                && eclipse_event.greatest_eclipse_date_month == month
+        })
+        .unwrap();
+
+    let besselian_elements = ECL::besselian_elements(eclipse_event.greatest_eclipse_jd_tt);
+    let circumstances = ECL::local_solar_eclipse_circumstances(
+        &besselian_elements,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        height_m,
+    )
+    .unwrap();
+    let second_contact = circumstances.second_contact.unwrap();
+    let third_contact = circumstances.third_contact.unwrap();
+
+    println!(
+		"Local solar eclipse circumstances: [Eclipse] {}/{} [Observer] {} {} {} m = [Type] {:?} [First Contact] {}:{}:{} [Second Contact] {}:{}:{} [Maximum] {}:{}:{} [Third Contact] {}:{}:{} [Fourth Contact] {}:{}:{} [Magnitude] {} [Obscuration] {} [Duration] {} s [Sun Altitude at Maximum] {} [Sun Azimuth at Maximum] {} [First Contact P/V] {}/{} [Begins/Ends Below Horizon] {}/{}",
+		month,
+		year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		height_m,
+		circumstances.local_eclipse_type,
+		circumstances.first_contact.ut_hour,
+		circumstances.first_contact.ut_minutes,
+		circumstances.first_contact.ut_seconds,
+		second_contact.ut_hour,
+		second_contact.ut_minutes,
+		second_contact.ut_seconds,
+		circumstances.maximum_eclipse.ut_hour,
+		circumstances.maximum_eclipse.ut_minutes,
+		circumstances.maximum_eclipse.ut_seconds,
+		third_contact.ut_hour,
+		third_contact.ut_minutes,
+		third_contact.ut_seconds,
+		circumstances.fourth_contact.ut_hour,
+		circumstances.fourth_contact.ut_minutes,
+		circumstances.fourth_contact.ut_seconds,
+		circumstances.magnitude,
+		circumstances.obscuration,
+		circumstances.central_duration_seconds,
+		circumstances.maximum_eclipse.sun_altitude_deg,
+		circumstances.maximum_eclipse.sun_azimuth_deg,
+		circumstances.first_contact.position_angle_deg,
+		circumstances.first_contact.vertex_angle_deg,
+		circumstances.begins_below_horizon,
+		circumstances.ends_below_horizon
+	);
+
+    assert_eq!(
+        circumstances.local_eclipse_type,
+        pa_types::EclipseType::Total,
+        "Local Eclipse Type"
+    );
+    assert_eq!(
+        circumstances.first_contact.ut_hour, 16,
+        "First Contact Hour"
+    );
+    assert_eq!(
+        circumstances.first_contact.ut_minutes, 52,
+        "First Contact Minutes"
+    );
+    assert_eq!(second_contact.ut_hour, 18, "Second Contact Hour");
+    assert_eq!(second_contact.ut_minutes, 19, "Second Contact Minutes");
+    assert_eq!(
+        circumstances.maximum_eclipse.ut_minutes, 21,
+        "Maximum Eclipse Minutes"
+    );
+    assert_eq!(third_contact.ut_minutes, 22, "Third Contact Minutes");
+    assert_eq!(
+        circumstances.fourth_contact.ut_hour, 19,
+        "Fourth Contact Hour"
+    );
+    assert_eq!(
+        circumstances.fourth_contact.ut_minutes, 47,
+        "Fourth Contact Minutes"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.magnitude, 3),
+        1.014,
+        "Magnitude"
+    );
+    assert_eq!(circumstances.obscuration, 1.0, "Obscuration");
+    assert_eq!(
+        UTIL::round_f64(circumstances.central_duration_seconds, 0),
+        160.0,
+        "Duration"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.maximum_eclipse.sun_altitude_deg, 1),
+        63.7,
+        "Sun Altitude at Maximum"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.maximum_eclipse.sun_azimuth_deg, 1),
+        191.9,
+        "Sun Azimuth at Maximum"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.first_contact.position_angle_deg, 0),
+        292.0,
+        "First Contact Position Angle"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.first_contact.vertex_angle_deg, 0),
+        320.0,
+        "First Contact Vertex Angle"
+    );
+    assert!(!circumstances.begins_below_horizon, "Begins Below Horizon");
+    assert!(!circumstances.ends_below_horizon, "Ends Below Horizon");
+}
+
+#[allow(dead_code)]
+pub fn test_sunset_solar_eclipse(
+    year: u32,
+    month: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let eclipse_event = ECL::eclipse_search(year, year)
+        .find(|eclipse_event| {
+            eclipse_event.eclipse_kind == pa_types::EclipseKind::Solar
+                && eclipse_event.greatest_eclipse_date_month == month
+        })
+        .unwrap();
+
+    let besselian_elements = ECL::besselian_elements(eclipse_event.greatest_eclipse_jd_tt);
+    let circumstances = ECL::local_solar_eclipse_circumstances(
+        &besselian_elements,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+    )
+    .unwrap();
+
+    println!(
+		"Sunset solar eclipse: [Eclipse] {}/{} [Observer] {} {} = [Type] {:?} [Magnitude] {} [Obscuration] {} [Sun Altitude at Fourth Contact] {} [Begins/Ends Below Horizon] {}/{}",
+		month,
+		year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		circumstances.local_eclipse_type,
+		circumstances.magnitude,
+		circumstances.obscuration,
+		circumstances.fourth_contact.sun_altitude_deg,
+		circumstances.begins_below_horizon,
+		circumstances.ends_below_horizon
+	);
+
+    assert_eq!(
+        circumstances.local_eclipse_type,
+        pa_types::EclipseType::Partial,
+        "Local Eclipse Type"
+    );
+    assert_eq!(circumstances.second_contact, None, "Second Contact");
+    assert_eq!(
+        UTIL::round_f64(circumstances.magnitude, 3),
+        0.105,
+        "Magnitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.obscuration, 3),
+        0.041,
+        "Obscuration"
+    );
+    assert!(!circumstances.begins_below_horizon, "Begins Below Horizon");
+    assert!(circumstances.ends_below_horizon, "Ends Below Horizon");
+}
+
+#[allow(dead_code)]
+pub fn test_local_lunar_eclipse_circumstances(
+    year: u32,
+    month: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    shadow_enlargement: pa_types::ShadowEnlargement,
+) {
+    let eclipse_event = ECL::eclipse_search(year, year)
+        .find(|eclipse_event| {
+            eclipse_event.eclipse_kind == pa_types::EclipseKind::Lunar
+                && eclipse_event.greatest_eclipse_date_month == month
+        })
+        .unwrap();
+
+    let circumstances = ECL::local_lunar_eclipse_circumstances(
+        eclipse_event.greatest_eclipse_jd_tt,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        shadow_enlargement,
+    )
+    .unwrap();
+    let partial_start = circumstances.partial_start.unwrap();
+    let total_start = circumstances.total_start.unwrap();
+    let total_end = circumstances.total_end.unwrap();
+    let partial_end = circumstances.partial_end.unwrap();
+
+    println!(
+		"Local lunar eclipse circumstances: [Eclipse] {}/{} [Observer] {} {} [Shadow] {:?} = [Type] {:?} [Umbral Magnitude] {} [Penumbral Magnitude] {} [P1] {}:{} [U1] {}:{} [U2] {}:{} [Greatest] {}:{} [U3] {}:{} [U4] {}:{} [P4] {}:{} [Moon Altitude at Greatest] {} [Moon Altitude at U4] {} [Visible Phases] {}/{}/{}",
+		month,
+		year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		shadow_enlargement,
+		circumstances.eclipse_type,
+		circumstances.umbral_magnitude,
+		circumstances.penumbral_magnitude,
+		circumstances.penumbral_start.ut_hour,
+		circumstances.penumbral_start.ut_minutes,
+		partial_start.ut_hour,
+		partial_start.ut_minutes,
+		total_start.ut_hour,
+		total_start.ut_minutes,
+		circumstances.greatest_eclipse.ut_hour,
+		circumstances.greatest_eclipse.ut_minutes,
+		total_end.ut_hour,
+		total_end.ut_minutes,
+		partial_end.ut_hour,
+		partial_end.ut_minutes,
+		circumstances.penumbral_end.ut_hour,
+		circumstances.penumbral_end.ut_minutes,
+		circumstances.greatest_eclipse.moon_altitude_deg,
+		partial_end.moon_altitude_deg,
+		circumstances.is_penumbral_phase_visible,
+		circumstances.is_partial_phase_visible,
+		circumstances.is_total_phase_visible
+	);
+
+    assert_eq!(
+        circumstances.eclipse_type,
+        pa_types::EclipseType::Total,
+        "Eclipse Type"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.umbral_magnitude, 3),
+        1.318,
+        "Umbral Magnitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(circumstances.penumbral_magnitude, 3),
+        2.297,
+        "Penumbral Magnitude"
+    );
+    assert_eq!(circumstances.penumbral_start.ut_minutes, 50, "P1 Minutes");
+    assert_eq!(partial_start.ut_minutes, 48, "U1 Minutes");
+    assert_eq!(total_start.ut_minutes, 51, "U2 Minutes");
+    assert_eq!(circumstances.greatest_eclipse.ut_hour, 13, "Greatest Hour");
+    assert_eq!(
+        circumstances.greatest_eclipse.ut_minutes, 29,
+        "Greatest Minutes"
+    );
+    assert_eq!(total_end.ut_minutes, 7, "U3 Minutes");
+    assert_eq!(partial_end.ut_minutes, 10, "U4 Minutes");
+    assert_eq!(circumstances.penumbral_end.ut_minutes, 8, "P4 Minutes");
+    assert_eq!(
+        UTIL::round_f64(circumstances.greatest_eclipse.moon_altitude_deg, 1),
+        15.9,
+        "Moon Altitude at Greatest"
+    );
+    assert!(total_end.is_visible, "U3 Visible");
+    assert!(!partial_end.is_visible, "U4 Visible");
+    assert!(circumstances.is_total_phase_visible, "Total Phase Visible");
+}
+
+#[allow(dead_code)]
+pub fn test_lunar_eclipse_shadow_enlargement(year: u32, month: u32) {
+    let eclipse_event = ECL::eclipse_search(year, year)
+        .find(|eclipse_event| {
+            eclipse_event.eclipse_kind == pa_types::EclipseKind::Lunar
+                && eclipse_event.greatest_eclipse_date_month == month
+        })
+        .unwrap();
+
+    let chauvenet = ECL::local_lunar_eclipse_circumstances(
+        eclipse_event.greatest_eclipse_jd_tt,
+        0.0,
+        0.0,
+        pa_types::ShadowEnlargement::Chauvenet,
+    )
+    .unwrap();
+    let danjon = ECL::local_lunar_eclipse_circumstances(
+        eclipse_event.greatest_eclipse_jd_tt,
+        0.0,
+        0.0,
+        pa_types::ShadowEnlargement::Danjon,
+    )
+    .unwrap();
+
+    println!(
+		"Lunar eclipse shadow enlargement: [Eclipse] {}/{} = [Chauvenet Umbral/Penumbral] {}/{} [Danjon Umbral/Penumbral] {}/{}",
+		month,
+		year,
+		chauvenet.umbral_magnitude,
+		chauvenet.penumbral_magnitude,
+		danjon.umbral_magnitude,
+		danjon.penumbral_magnitude
+	);
+
+    assert_eq!(
+        UTIL::round_f64(chauvenet.umbral_magnitude, 3),
+        1.324,
+        "Chauvenet Umbral Magnitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(chauvenet.penumbral_magnitude, 3),
+        2.322,
+        "Chauvenet Penumbral Magnitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(danjon.umbral_magnitude, 3),
+        1.318,
+        "Danjon Umbral Magnitude"
+    );
+}
@@ -0,0 +1,119 @@
+use practical_astronomy_rust::frames as FR;
+use practical_astronomy_rust::types as pa_types;
+use practical_astronomy_rust::util as UT;
+
+#[allow(dead_code)]
+pub fn test_frame_path() {
+    let path = FR::frame_path(
+        pa_types::ReferenceFrame::Supergalactic,
+        pa_types::ReferenceFrame::Horizon,
+    );
+
+    println!(
+        "Frame path: [From] Supergalactic [To] Horizon = [Path] {:?}",
+        path
+    );
+
+    assert_eq!(
+        path,
+        vec![
+            pa_types::ReferenceFrame::Supergalactic,
+            pa_types::ReferenceFrame::Galactic,
+            pa_types::ReferenceFrame::Icrs,
+            pa_types::ReferenceFrame::MeanOfDate,
+            pa_types::ReferenceFrame::TrueOfDate,
+            pa_types::ReferenceFrame::Horizon,
+        ],
+        "Frame Path"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_transform_coordinates(
+    from_frame: pa_types::ReferenceFrame,
+    to_frame: pa_types::ReferenceFrame,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    julian_date: f64,
+    geographical_longitude_deg: f64,
+    geographical_latitude_deg: f64,
+    expected_longitude_deg: f64,
+    expected_latitude_deg: f64,
+) {
+    let observer = FR::Observer {
+        geographical_longitude_deg,
+        geographical_latitude_deg,
+    };
+
+    let (out_longitude_deg, out_latitude_deg) = FR::transform_coordinates(
+        from_frame,
+        to_frame,
+        longitude_deg,
+        latitude_deg,
+        julian_date,
+        &observer,
+    );
+
+    println!(
+		"Transform coordinates: [From] {:?} [To] {:?} [Longitude/Latitude] {}d/{}d [JD] {} [Observer Longitude/Latitude] {}d/{}d = [Longitude/Latitude] {}d/{}d",
+		from_frame,
+		to_frame,
+		longitude_deg,
+		latitude_deg,
+		julian_date,
+		geographical_longitude_deg,
+		geographical_latitude_deg,
+		out_longitude_deg,
+		out_latitude_deg
+	);
+
+    assert_eq!(
+        UT::round_f64(out_longitude_deg, 4),
+        expected_longitude_deg,
+        "Longitude (degrees)"
+    );
+    assert_eq!(
+        UT::round_f64(out_latitude_deg, 4),
+        expected_latitude_deg,
+        "Latitude (degrees)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_transform_round_trip() {
+    let observer = FR::Observer {
+        geographical_longitude_deg: -77.065556,
+        geographical_latitude_deg: 38.921389,
+    };
+    let julian_date = 2446896.30625;
+
+    let forward = FR::transform(
+        pa_types::ReferenceFrame::EclipticOfDate,
+        pa_types::ReferenceFrame::Horizon,
+        julian_date,
+        &observer,
+    );
+    let backward = FR::transform(
+        pa_types::ReferenceFrame::Horizon,
+        pa_types::ReferenceFrame::EclipticOfDate,
+        julian_date,
+        &observer,
+    );
+    let product = FR::multiply_matrices(&backward, &forward);
+
+    println!(
+        "Transform round trip: [Ecliptic -> Horizon -> Ecliptic] = [Matrix] {:?}",
+        product.m
+    );
+
+    for (row, product_row) in product.m.iter().enumerate() {
+        for (column, element) in product_row.iter().enumerate() {
+            let expected = if row == column { 1.0 } else { 0.0 };
+            assert_eq!(
+                UT::round_f64(*element, 12).abs(),
+                expected,
+                "Round Trip Matrix Element"
+            );
+        }
+    }
+}
@@ -1,30 +1,101 @@
 mod binary;
+mod calendar;
 mod comet;
 mod coordinates;
 mod datetime;
 mod eclipses;
+mod frames;
 mod moon;
+mod occultations;
 mod planet;
+mod riseset;
 mod sun;
+mod transits;
 
 use binary as BINS;
+use calendar as CALT;
 use comet as COMT;
 use coordinates as CST;
 use datetime as DTT;
 use eclipses as ECL;
+use frames as FRT;
 use moon as MOONT;
+use occultations as OCCT;
 use planet as PLANETT;
 use practical_astronomy_rust::types as pa_types;
+use riseset as RST;
 use sun as SUNT;
+use transits as TRNT;
 
 fn main() {
     println!("Succinct tests: `cargo test`");
     println!("Verbose tests: `cargo test -v -- --nocapture`");
 }
 
+#[test]
+pub fn run_calendar_tests() {
+    CALT::test_calendar_conversions(
+        (16, 9, 2023),
+        2460204,
+        (3, 9, 2023),
+        (1, 7, 5784),
+        (1, 3, 1445),
+        (25, 6, 1402),
+        (13, 0, 10, 16, 1),
+    );
+    CALT::test_calendar_conversions(
+        (21, 3, 2025),
+        2460756,
+        (8, 3, 2025),
+        (21, 12, 5785),
+        (21, 9, 1446),
+        (1, 1, 1404),
+        (13, 0, 12, 7, 13),
+    );
+    CALT::test_chinese_calendar(
+        (22, 3, 2023),
+        practical_astronomy_rust::calendar::ChineseDate {
+            cycle: 78,
+            year: 40,
+            month: 2,
+            is_leap_month: true,
+            day: 1,
+        },
+    );
+    CALT::test_french_republican_calendar((9, 11, 1799), (18, 2, 8));
+
+    // Cases that need the apparent solar longitude (with nutation and aberration): the leap
+    // months of 1917 and 1922, and the Persian new year of 2091, are wrong with the book's
+    // `sun_long`.
+    CALT::test_chinese_calendar(
+        (23, 3, 1917),
+        practical_astronomy_rust::calendar::ChineseDate {
+            cycle: 76,
+            year: 54,
+            month: 2,
+            is_leap_month: true,
+            day: 1,
+        },
+    );
+    CALT::test_chinese_calendar(
+        (25, 6, 1922),
+        practical_astronomy_rust::calendar::ChineseDate {
+            cycle: 76,
+            year: 59,
+            month: 5,
+            is_leap_month: true,
+            day: 1,
+        },
+    );
+    CALT::test_persian_calendar((20, 3, 2091), (30, 12, 1469));
+    CALT::test_persian_calendar((21, 3, 2091), (1, 1, 1470));
+}
+
 #[test]
 pub fn run_datetime_tests() {
     DTT::test_easter(4, 20, 2003);
+    DTT::test_orthodox_easter(5, 5, 2024);
+    DTT::test_passover(4, 23, 2024);
     DTT::test_day_numbers();
 
     let mut test_civil_time = DTT::TestCivilTimeScaffold {
@@ -60,6 +131,11 @@ pub fn run_datetime_tests() {
     test_universal_time_sidereal_time.test_universal_time_to_greenwich_sidereal_time();
     test_universal_time_sidereal_time.test_greenwich_sidereal_time_to_universal_time();
 
+    DTT::test_universal_time_to_greenwich_apparent_sidereal_time(0.0, 0.0, 0.0, 10.0, 4, 1987);
+    DTT::test_greenwich_mean_and_apparent_sidereal_time_by_model(19.0, 21.0, 0.0, 10.0, 4, 1987);
+    DTT::test_earth_rotation_angle(12.0, 0.0, 0.0, 1.0, 1, 2000);
+    DTT::test_greenwich_sidereal_time_to_universal_times(14.0, 1.0, 49.68, 22.0, 4, 1980);
+
     let mut test_greenwich_sidereal_local_sidereal =
         DTT::TestGreenwichSiderealLocalSiderealScaffold {
             gst_hours: 4.0,
@@ -71,6 +147,8 @@ pub fn run_datetime_tests() {
     test_greenwich_sidereal_local_sidereal.test_local_sidereal_time_to_greenwich_sidereal_time();
 
     DTT::test_julian_date_to_day_of_week();
+
+    DTT::test_delta_t(10.0, 4, 1987);
 }
 
 #[test]
@@ -138,6 +216,19 @@ pub fn run_coordinate_tests() {
     test_galactic.test_equatorial_coordinate_to_galactic_coordinate();
     test_galactic.test_galactic_coordinate_to_equatorial_coordinate();
 
+    let mut test_supergalactic = CST::TestSupergalacticScaffold {
+        ra_hours: 12.0,
+        ra_minutes: 30.0,
+        ra_seconds: 49.4,
+        dec_degrees: 12.0,
+        dec_minutes: 23.0,
+        dec_seconds: 28.0,
+    };
+    test_supergalactic.test_equatorial_coordinate_to_supergalactic_coordinate();
+    test_supergalactic.test_supergalactic_coordinate_to_equatorial_coordinate();
+
+    CST::test_heliocentric_and_geocentric_ecliptic(26.11428, -2.6207, 0.724603, 20.0, 12, 1992);
+
     CST::test_angle_between_two_objects(
         5.0,
         13.0,
@@ -164,6 +255,10 @@ pub fn run_coordinate_tests() {
 
     CST::test_nutation_in_ecliptic_longitude_and_obliquity(1.0, 9, 1988);
 
+    CST::test_nutation_in_ecliptic_longitude_and_obliquity_by_model(10.0, 4, 1987);
+
+    CST::test_true_obliquity_and_equation_of_the_equinoxes(10.0, 4, 1987);
+
     CST::test_correct_for_aberration(
         0.0, 0.0, 0.0, 8.0, 9, 1988, 352.0, 37.0, 10.1, -1.0, 32.0, 56.4,
     );
@@ -190,6 +285,34 @@ pub fn run_coordinate_tests() {
         21.7,
     );
 
+    CST::test_atmospheric_refraction_by_model(
+        23.0,
+        14.0,
+        0.0,
+        40.0,
+        10.0,
+        0.0,
+        pa_types::CoordinateType::True,
+        0.17,
+        51.2036110,
+        0,
+        0,
+        23.0,
+        3,
+        1987,
+        1.0,
+        1.0,
+        24.0,
+        1012.0,
+        21.7,
+        0.55,
+    );
+
+    CST::test_refracted_altitude(-1.0, 1010.0, 10.0);
+    CST::test_refracted_altitude_round_trip(-4.3, pa_types::RefractionModel::Bennett);
+    CST::test_refracted_altitude_round_trip(-4.3, pa_types::RefractionModel::Saemundsson);
+    CST::test_refracted_altitude_round_trip(-5.2, pa_types::RefractionModel::Book);
+
     CST::test_corrections_for_geocentric_parallax(
         22.0,
         35.0,
@@ -214,8 +337,14 @@ pub fn run_coordinate_tests() {
 
     CST::test_heliographic_coordinates(220.0, 10.5, 1.0, 5, 1988);
 
+    CST::test_heliographic_to_disk_position(142.59, -19.94, 1.0, 5, 1988);
+
+    CST::test_solar_physical_ephemeris(13.0, 10, 1992);
+
     CST::test_carrington_rotation_number(27.0, 1, 1975);
 
+    CST::test_carrington_rotation_start_and_end(1699);
+
     let mut test_selenographic = CST::TestSelenographicScaffold {
         gwdate_day: 1.0,
         gwdate_month: 5,
@@ -246,9 +375,29 @@ pub fn run_sun_tests() {
         pa_types::TwilightType::Astronomical,
     );
 
+    SUNT::test_polar_day_and_night_states(2024, 1, 18.95, 69.65);
+
+    SUNT::test_golden_and_blue_hour(10.0, 3, 1986, false, -5, -71.05, 42.37);
+
+    SUNT::test_solar_altitude_intervals(1.0, 5, 2024, 1, 18.95, 69.65, -6.0, -4.0);
+
     SUNT::test_equation_of_time(27.0, 7, 2010);
 
+    SUNT::test_solar_position_spa(
+        12.0, 30.0, 30.0, 17.0, 10, 2003, -7.0, 67.0, -105.1786, 39.742476, 1830.14,
+    );
+
     SUNT::test_solar_elongation(10.0, 6.0, 45.0, 11.0, 57.0, 27.0, 27.8333333, 7, 2010);
+
+    SUNT::test_equinoxes_and_solstices(2024);
+    SUNT::test_solar_terms_and_cross_quarter_days(2024);
+
+    SUNT::test_equation_of_time_table(2024);
+
+    SUNT::test_analemma(12.0, 0.0, 0.0, false, 0, 0.0, 51.48, 2024, 7);
+
+    SUNT::test_sundial_hour_lines(0.0, 51.48, 0);
+    SUNT::test_horizontal_sundial_hour_line(-71.06, 42.36, -5, 18, 95.84);
 }
 
 #[test]
@@ -267,6 +416,27 @@ pub fn run_planet_tests() {
     test_planet_position.test_approximate_position_of_planet();
     test_planet_position.test_precise_position_of_planet();
     test_planet_position.test_visual_aspects_of_a_planet();
+
+    PLANETT::test_visual_aspects_of_a_planet_by_model(0.0, 0.0, 0.0, false, 0, 7.0, 7, 2023);
+
+    PLANETT::test_planetary_phenomena("Mercury".to_string(), 1, 1, 2024, 30, 4, 2024, false, 0);
+
+    PLANETT::test_planetary_conjunctions(
+        "Venus".to_string(),
+        "Jupiter".to_string(),
+        1,
+        1,
+        2023,
+        31,
+        12,
+        2023,
+        false,
+        0,
+    );
+
+    PLANETT::test_planetary_physical_ephemeris(0.0, 0.0, 0.0, false, 0, 16.0, 12, 1992);
+
+    PLANETT::test_great_red_spot_transits(72.74, 16, 12, 1992, false, 0);
 }
 
 #[test]
@@ -320,6 +490,12 @@ pub fn run_moon_tests() {
 
     MOONT::test_times_of_new_moon_and_full_moon(false, 0, 1.0, 9, 2003);
     MOONT::test_moonrise_and_moonset(6.0, 3, 1986, false, -5, -71.05, 42.3667);
+    MOONT::test_lunar_physical_ephemeris(0.0, 0.0, 0.0, false, 0, 12.0, 4, 1992);
+    MOONT::test_lunar_feature_visibility(16.0, 0.0, 0.0, false, -5, 11.0, 4, 1992, -71.05, 42.37);
+    MOONT::test_lunar_events(1, 8, 2023, 31, 8, 2023, false, 0);
+    MOONT::test_lunar_standstills(2024, 2026);
+    MOONT::test_lunation_numbers(false, 0, 17.0, 7, 2023);
+    MOONT::test_crescent_visibility(22.0, 3, 2023, false, 3, 39.8262, 21.4225);
 }
 
 #[test]
@@ -344,4 +520,177 @@ pub fn run_eclipse_tests() {
     test_solar_eclipse.test_solar_eclipse_occurrence();
 
     ECL::test_solar_eclipse_circumstances(20.0, 3, 2015, false, 0, 0.0, 68.65);
+
+    ECL::test_eclipse_search(2017, 2018);
+
+    ECL::test_solar_eclipse_path(2017, 8, 10.0);
+
+    ECL::test_local_solar_eclipse_circumstances(2017, 8, -89.2167, 37.7272, 120.0);
+
+    ECL::test_sunset_solar_eclipse(2017, 8, -0.1276, 51.5072);
+
+    ECL::test_local_lunar_eclipse_circumstances(
+        2018,
+        1,
+        -118.24,
+        34.05,
+        pa_types::ShadowEnlargement::Danjon,
+    );
+
+    ECL::test_lunar_eclipse_shadow_enlargement(2018, 1);
+}
+
+#[test]
+pub fn run_frames_tests() {
+    FRT::test_frame_path();
+
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::TrueOfDate,
+        pa_types::ReferenceFrame::Horizon,
+        347.3193375,
+        -6.719892,
+        2446896.30625,
+        -77.065556,
+        38.921389,
+        248.0336,
+        15.125,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::Icrs,
+        pa_types::ReferenceFrame::EclipticOfDate,
+        116.328942,
+        28.026183,
+        2451545.0,
+        0.0,
+        0.0,
+        113.2156,
+        6.6842,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::Icrs,
+        pa_types::ReferenceFrame::EclipticJ2000,
+        116.328942,
+        28.026183,
+        2451545.0,
+        0.0,
+        0.0,
+        113.2156,
+        6.6842,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::Icrs,
+        pa_types::ReferenceFrame::MeanOfDate,
+        41.054063,
+        49.22775,
+        2462088.69,
+        0.0,
+        0.0,
+        41.5472,
+        49.3485,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::MeanOfDate,
+        pa_types::ReferenceFrame::TrueOfDate,
+        41.547214,
+        49.348483,
+        2462088.69,
+        0.0,
+        0.0,
+        41.5516,
+        49.3502,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::Galactic,
+        pa_types::ReferenceFrame::Icrs,
+        0.0,
+        0.0,
+        2451545.0,
+        0.0,
+        0.0,
+        266.405,
+        -28.9362,
+    );
+    FRT::test_transform_coordinates(
+        pa_types::ReferenceFrame::Supergalactic,
+        pa_types::ReferenceFrame::Galactic,
+        0.0,
+        90.0,
+        2451545.0,
+        0.0,
+        0.0,
+        47.37,
+        6.32,
+    );
+
+    FRT::test_transform_round_trip();
+}
+
+#[test]
+pub fn run_occultations_tests() {
+    OCCT::test_lunar_occultations(1.0, 3, 2017, 30, -87.63, 41.88, 180.0);
+
+    OCCT::test_lunar_occultation_of_planet(8.0, 11, 2023, 2.35, 48.85);
+}
+
+#[test]
+pub fn run_transits_tests() {
+    TRNT::test_transit_search("Mercury".to_string(), 2000, 2020);
+
+    TRNT::test_local_transit_circumstances("Venus".to_string(), 2004, -0.1276, 51.5072, 0.0);
+}
+
+#[test]
+pub fn run_riseset_tests() {
+    RST::test_rise_transit_set(
+        pa_types::RiseSetBodyKind::Sun,
+        "Sun".to_string(),
+        10.0,
+        3,
+        1986,
+        false,
+        -5,
+        -71.05,
+        42.37,
+    );
+    RST::test_rise_transit_set(
+        pa_types::RiseSetBodyKind::Moon,
+        "Moon".to_string(),
+        6.0,
+        3,
+        1986,
+        false,
+        -5,
+        -71.05,
+        42.3667,
+    );
+    RST::test_rise_transit_set(
+        pa_types::RiseSetBodyKind::Planet,
+        "Jupiter".to_string(),
+        6.0,
+        3,
+        1986,
+        false,
+        -5,
+        -71.05,
+        42.3667,
+    );
+
+    RST::test_rise_transit_set_of_star(
+        "Capella".to_string(),
+        5.278155,
+        45.997991,
+        6.0,
+        3,
+        1986,
+        -5,
+        -71.05,
+        42.3667,
+    );
+
+    // Halley's comet was just south of the celestial equator.
+    RST::test_rise_transit_set_of_comet("Halley".to_string(), 26.0, 12, 1985, -5, -71.05, 42.3667);
+
+    RST::test_rise_transit_set_polar(21.0, 12, 2024, 18.95, 69.65, 50.0);
+
+    RST::test_rise_set_events(3.0, 6, 2024, 1, 18.95, 69.65);
 }
@@ -1,4 +1,7 @@
+use practical_astronomy_rust::calendar as CAL;
 use practical_astronomy_rust::moon as M;
+use practical_astronomy_rust::types as pa_types;
+use practical_astronomy_rust::util as UTIL;
 
 pub struct TestMoonPositionInfoScaffold {
     pub lct_hour: f64,
@@ -346,3 +349,443 @@ pub fn test_moonrise_and_moonset(
     assert_eq!(ms_local_date_year, 1986, "Moonset - Local Date (year)");
     assert_eq!(ms_azimuth_deg, 234.05, "Moonset - Azimuth (degrees)");
 }
+
+#[allow(dead_code)]
+pub fn test_lunar_physical_ephemeris(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) {
+    let ephemeris = M::lunar_physical_ephemeris(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    println!(
+		"Lunar physical ephemeris: [Local Time] {}:{}:{} [DST?] {} [Zone Correction] {} hours [Local Date] {}/{}/{} = [Optical Libration] {} / {} degrees [Physical Libration] {} / {} degrees [Total Libration] {} / {} degrees [Position Angle of Axis] {} degrees [Subsolar Latitude] {} degrees [Colongitude] {} degrees",
+		lct_hour,
+		lct_min,
+		lct_sec,
+		is_daylight_saving,
+		zone_correction_hours,
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		ephemeris.optical_libration_longitude_deg,
+		ephemeris.optical_libration_latitude_deg,
+		ephemeris.physical_libration_longitude_deg,
+		ephemeris.physical_libration_latitude_deg,
+		ephemeris.total_libration_longitude_deg,
+		ephemeris.total_libration_latitude_deg,
+		ephemeris.position_angle_of_axis_deg,
+		ephemeris.subsolar_latitude_deg,
+		ephemeris.selenographic_colongitude_deg
+	);
+
+    assert_eq!(
+        ephemeris.optical_libration_longitude_deg, -1.21,
+        "Optical libration in longitude"
+    );
+    assert_eq!(
+        ephemeris.optical_libration_latitude_deg, 4.19,
+        "Optical libration in latitude"
+    );
+    assert_eq!(
+        ephemeris.physical_libration_longitude_deg, -0.03,
+        "Physical libration in longitude"
+    );
+    assert_eq!(
+        ephemeris.physical_libration_latitude_deg, 0.01,
+        "Physical libration in latitude"
+    );
+    assert_eq!(
+        ephemeris.total_libration_longitude_deg, -1.23,
+        "Total libration in longitude"
+    );
+    assert_eq!(
+        ephemeris.total_libration_latitude_deg, 4.2,
+        "Total libration in latitude"
+    );
+    assert_eq!(
+        ephemeris.position_angle_of_axis_deg, 15.08,
+        "Position angle of axis"
+    );
+    assert_eq!(ephemeris.subsolar_latitude_deg, 1.46, "Subsolar latitude");
+    assert_eq!(
+        ephemeris.selenographic_colongitude_deg, 22.11,
+        "Colongitude"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_lunar_feature_visibility(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+) {
+    let expected = [
+        (
+            "Copernicus",
+            "OK",
+            pa_types::LunarFeatureLighting::Terminator,
+            0.74,
+            true,
+        ),
+        (
+            "Tycho",
+            "OK",
+            pa_types::LunarFeatureLighting::Illuminated,
+            5.69,
+            true,
+        ),
+        (
+            "Grimaldi",
+            "OK",
+            pa_types::LunarFeatureLighting::Dark,
+            -47.62,
+            false,
+        ),
+        (
+            "Nowhere",
+            "NotFound",
+            pa_types::LunarFeatureLighting::Dark,
+            -99.0,
+            false,
+        ),
+    ];
+
+    for (feature_name, status, lighting, solar_altitude_deg, is_visible) in expected {
+        let visibility = M::lunar_feature_visibility(
+            feature_name.to_string(),
+            lct_hour,
+            lct_min,
+            lct_sec,
+            is_daylight_saving,
+            zone_correction_hours,
+            local_date_day,
+            local_date_month,
+            local_date_year,
+            geog_long_deg,
+            geog_lat_deg,
+        );
+
+        println!(
+			"Lunar feature visibility: [Feature] {} [Local Time] {}:{}:{} [DST?] {} [Zone Correction] {} hours [Local Date] {}/{}/{} [Geographical Longitude/Latitude] {} degrees / {} degrees = [Status] {} [Lighting] {:?} [Solar Altitude] {} degrees [Faces Observer?] {} [Moon Altitude] {} degrees [Visible?] {}",
+			feature_name,
+			lct_hour,
+			lct_min,
+			lct_sec,
+			is_daylight_saving,
+			zone_correction_hours,
+			local_date_month,
+			local_date_day,
+			local_date_year,
+			geog_long_deg,
+			geog_lat_deg,
+			visibility.status,
+			visibility.lighting,
+			visibility.solar_altitude_deg,
+			visibility.faces_observer,
+			visibility.moon_altitude_deg,
+			visibility.is_visible
+		);
+
+        assert_eq!(visibility.status, status, "Status");
+        assert_eq!(visibility.lighting, lighting, "Lighting");
+        assert_eq!(
+            visibility.solar_altitude_deg, solar_altitude_deg,
+            "Solar altitude"
+        );
+        assert_eq!(visibility.is_visible, is_visible, "Visible?");
+    }
+}
+
+#[allow(dead_code)]
+pub fn test_lunar_events(
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) {
+    let events: Vec<M::LunarEvent> = M::lunar_events(
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+    )
+    .collect();
+
+    for event in &events {
+        println!(
+			"Lunar events: [Start Date] {}/{}/{} [End Date] {}/{}/{} [DST?] {} [Zone Correction] {} hours = [Event] {:?} [Local Date] {}/{}/{} [Local Time] {}:{}:{} [Distance] {} km [Declination] {} degrees",
+			start_date_month,
+			start_date_day,
+			start_date_year,
+			end_date_month,
+			end_date_day,
+			end_date_year,
+			is_daylight_saving,
+			zone_correction_hours,
+			event.kind,
+			event.local_date_month,
+			event.local_date_day,
+			event.local_date_year,
+			event.lct_hour,
+			event.lct_minutes,
+			UTIL::round_f64(event.lct_seconds, 2),
+			event.distance_km,
+			event.declination_deg
+		);
+    }
+
+    let kinds: Vec<pa_types::LunarEventKind> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            pa_types::LunarEventKind::FullMoon,
+            pa_types::LunarEventKind::Supermoon,
+            pa_types::LunarEventKind::Perigee,
+            pa_types::LunarEventKind::AscendingNode,
+            pa_types::LunarEventKind::LastQuarter,
+            pa_types::LunarEventKind::MaximumNorthDeclination,
+            pa_types::LunarEventKind::NewMoon,
+            pa_types::LunarEventKind::Apogee,
+            pa_types::LunarEventKind::DescendingNode,
+            pa_types::LunarEventKind::FirstQuarter,
+            pa_types::LunarEventKind::MaximumSouthDeclination,
+            pa_types::LunarEventKind::Perigee,
+            pa_types::LunarEventKind::FullMoon,
+            pa_types::LunarEventKind::Supermoon,
+            pa_types::LunarEventKind::BlueMoon,
+        ],
+        "Event kinds"
+    );
+
+    assert_eq!(events[0].local_date_day, 1, "Full Moon - Local Date (day)");
+    assert_eq!(events[0].lct_hour, 18, "Full Moon - Local Time (hours)");
+    assert_eq!(
+        events[0].lct_minutes, 31,
+        "Full Moon - Local Time (minutes)"
+    );
+    assert_eq!(events[2].distance_km, 357338.0, "Perigee - Distance (km)");
+    assert_eq!(
+        events[5].declination_deg, 27.99,
+        "Maximum North Declination (degrees)"
+    );
+    assert_eq!(
+        events[14].local_date_day, 31,
+        "Blue Moon - Local Date (day)"
+    );
+    assert_eq!(events[14].lct_hour, 1, "Blue Moon - Local Time (hours)");
+    assert_eq!(
+        events[14].lct_minutes, 35,
+        "Blue Moon - Local Time (minutes)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_lunar_standstills(start_date_year: u32, end_date_year: u32) {
+    let standstills: Vec<M::LunarEvent> =
+        M::lunar_events(1, 1, start_date_year, 31, 12, end_date_year, false, 0)
+            .filter(|event| {
+                event.kind == pa_types::LunarEventKind::MajorStandstill
+                    || event.kind == pa_types::LunarEventKind::MinorStandstill
+            })
+            .collect();
+
+    for event in &standstills {
+        println!(
+            "Lunar standstills: [Years] {} - {} = [Event] {:?} [Date] {}/{}/{}",
+            start_date_year,
+            end_date_year,
+            event.kind,
+            event.local_date_month,
+            event.local_date_day,
+            event.local_date_year
+        );
+    }
+
+    assert_eq!(standstills.len(), 1, "Number of standstills");
+    assert_eq!(
+        standstills[0].kind,
+        pa_types::LunarEventKind::MajorStandstill,
+        "Standstill kind"
+    );
+    assert_eq!(standstills[0].local_date_month, 1, "Standstill - Month");
+    assert_eq!(standstills[0].local_date_year, 2025, "Standstill - Year");
+}
+
+#[allow(dead_code)]
+pub fn test_lunation_numbers(
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) {
+    let lunation = M::lunation_numbers(
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    println!(
+		"Lunation numbers: [DST?] {} [Zone Correction] {} hours [Local Date] {}/{}/{} = [New Moon JD] {} [Meeus] {} [Brown] {} [Islamic] {} ({}/{}) [Hebrew] {} ({}/{})",
+		is_daylight_saving,
+		zone_correction_hours,
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		lunation.new_moon_jd_ut,
+		lunation.meeus,
+		lunation.brown,
+		lunation.islamic,
+		lunation.islamic_month,
+		lunation.islamic_year,
+		lunation.hebrew,
+		lunation.hebrew_month,
+		lunation.hebrew_year
+	);
+
+    assert_eq!(
+        UTIL::round_f64(lunation.new_moon_jd_ut, 2),
+        2460143.27,
+        "New Moon (JD)"
+    );
+    assert_eq!(lunation.meeus, 291, "Meeus lunation number");
+    assert_eq!(lunation.brown, 1244, "Brown lunation number");
+    assert_eq!(lunation.islamic, 17329, "Islamic lunation number");
+    assert_eq!(lunation.islamic_month, 1, "Islamic month");
+    assert_eq!(lunation.islamic_year, 1445, "Islamic year");
+    assert_eq!(lunation.hebrew, 71525, "Hebrew lunation number");
+    assert_eq!(lunation.hebrew_month, 5, "Hebrew month");
+    assert_eq!(
+        CAL::julian_day_number_to_hebrew(2460145).1,
+        lunation.hebrew_month,
+        "Hebrew month (calendar)"
+    );
+    assert_eq!(lunation.hebrew_year, 5783, "Hebrew year");
+}
+
+#[allow(dead_code)]
+pub fn test_crescent_visibility(
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+) {
+    let before = M::crescent_visibility(
+        local_date_day - 1.0,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+        geog_long_deg,
+        geog_lat_deg,
+    );
+    let visibility = M::crescent_visibility(
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+        geog_long_deg,
+        geog_lat_deg,
+    );
+
+    println!(
+		"Crescent visibility: [Local Date] {}/{}/{} [DST?] {} [Zone Correction] {} hours [Geographical Longitude/Latitude] {} degrees / {} degrees = [Status] {} [Sunset] {}:{} [Moonset] {}:{} [Best Time] {}:{} [Lag] {} minutes [Age] {} hours [ARCL] {} degrees [ARCV] {} degrees [DAZ] {} degrees [W] {} arcmin [Yallop] {} ({:?}) [Odeh] {} ({:?})",
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		is_daylight_saving,
+		zone_correction_hours,
+		geog_long_deg,
+		geog_lat_deg,
+		visibility.status,
+		visibility.sunset_hour,
+		visibility.sunset_minutes,
+		visibility.moonset_hour,
+		visibility.moonset_minutes,
+		visibility.best_time_hour,
+		visibility.best_time_minutes,
+		visibility.lag_time_minutes,
+		visibility.moon_age_hours,
+		visibility.arcl_deg,
+		visibility.arcv_deg,
+		visibility.daz_deg,
+		visibility.crescent_width_arcmin,
+		visibility.yallop_q,
+		visibility.yallop_visibility,
+		visibility.odeh_v,
+		visibility.odeh_visibility
+	);
+
+    assert_eq!(
+        before.status, "Moon sets before Sun",
+        "Evening before - Status"
+    );
+    assert_eq!(
+        before.yallop_visibility,
+        pa_types::YallopVisibility::F,
+        "Evening before - Yallop"
+    );
+    assert_eq!(visibility.status, "OK", "Status");
+    assert_eq!(visibility.sunset_hour, 18, "Sunset (hour)");
+    assert_eq!(visibility.sunset_minutes, 32, "Sunset (minutes)");
+    assert_eq!(visibility.moonset_hour, 19, "Moonset (hour)");
+    assert_eq!(visibility.moonset_minutes, 23, "Moonset (minutes)");
+    assert_eq!(visibility.best_time_hour, 18, "Best time (hour)");
+    assert_eq!(visibility.best_time_minutes, 55, "Best time (minutes)");
+    assert_eq!(visibility.lag_time_minutes, 51.0, "Lag time (minutes)");
+    assert_eq!(visibility.moon_age_hours, 22.47, "Moon age (hours)");
+    assert_eq!(visibility.arcl_deg, 12.71, "ARCL (degrees)");
+    assert_eq!(visibility.arcv_deg, 12.63, "ARCV (degrees)");
+    assert_eq!(
+        visibility.crescent_width_arcmin, 0.34,
+        "Crescent width (arcmin)"
+    );
+    assert_eq!(visibility.yallop_q, 0.319, "Yallop q");
+    assert_eq!(
+        visibility.yallop_visibility,
+        pa_types::YallopVisibility::A,
+        "Yallop visibility"
+    );
+    assert_eq!(visibility.odeh_v, 6.54, "Odeh V");
+    assert_eq!(
+        visibility.odeh_visibility,
+        pa_types::OdehVisibility::NakedEye,
+        "Odeh visibility"
+    );
+}
@@ -0,0 +1,217 @@
+use practical_astronomy_rust::occultations as OCC;
+use practical_astronomy_rust::types as pa_types;
+use practical_astronomy_rust::util as UTIL;
+
+#[allow(dead_code)]
+pub fn test_lunar_occultations(
+    start_date_day: f64,
+    start_date_month: u32,
+    start_date_year: u32,
+    number_of_days: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) {
+    let targets = vec![
+        OCC::OccultationTarget {
+            kind: pa_types::OccultationTargetKind::Star,
+            name: "Aldebaran".to_string(),
+            right_ascension_hours: 4.598677,
+            declination_deg: 16.509303,
+        },
+        OCC::OccultationTarget {
+            kind: pa_types::OccultationTargetKind::Star,
+            name: "Regulus".to_string(),
+            right_ascension_hours: 10.139531,
+            declination_deg: 11.967208,
+        },
+        OCC::OccultationTarget {
+            kind: pa_types::OccultationTargetKind::Planet,
+            name: "Venus".to_string(),
+            right_ascension_hours: 0.0,
+            declination_deg: 0.0,
+        },
+    ];
+
+    let occultations = OCC::lunar_occultations(
+        &targets,
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        number_of_days,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        height_m,
+    );
+
+    for occultation in occultations.iter() {
+        println!(
+			"Lunar occultations: [Start] {}/{}/{} [Days] {} [Observer] {} {} {} m = [Target] {} [Disappearance] {}/{}/{} {}:{}:{} PA {} cusp angle {} dark limb {} [Reappearance] {}:{}:{} PA {} cusp angle {} dark limb {}",
+			start_date_month,
+			start_date_day,
+			start_date_year,
+			number_of_days,
+			geog_longitude_deg,
+			geog_latitude_deg,
+			height_m,
+			occultation.target.name,
+			occultation.disappearance.date_month,
+			occultation.disappearance.date_day,
+			occultation.disappearance.date_year,
+			occultation.disappearance.ut_hour,
+			occultation.disappearance.ut_minutes,
+			occultation.disappearance.ut_seconds,
+			occultation.disappearance.position_angle_deg,
+			occultation.disappearance.cusp_angle_deg,
+			occultation.disappearance.is_dark_limb,
+			occultation.reappearance.ut_hour,
+			occultation.reappearance.ut_minutes,
+			occultation.reappearance.ut_seconds,
+			occultation.reappearance.position_angle_deg,
+			occultation.reappearance.cusp_angle_deg,
+			occultation.reappearance.is_dark_limb
+		);
+    }
+
+    assert_eq!(occultations.len(), 1, "Number of Occultations");
+
+    let occultation = &occultations[0];
+    assert_eq!(occultation.target.name, "Aldebaran", "Target");
+    assert_eq!(occultation.disappearance.date_day, 5.0, "Disappearance Day");
+    assert_eq!(occultation.disappearance.ut_hour, 3, "Disappearance Hour");
+    assert_eq!(
+        occultation.disappearance.ut_minutes, 57,
+        "Disappearance Minutes"
+    );
+    assert_eq!(
+        UTIL::round_f64(occultation.disappearance.position_angle_deg, 0),
+        30.0,
+        "Disappearance Position Angle"
+    );
+    assert_eq!(
+        UTIL::round_f64(occultation.disappearance.cusp_angle_deg, 0),
+        38.0,
+        "Disappearance Cusp Angle"
+    );
+    assert!(
+        occultation.disappearance.is_north_cusp,
+        "Disappearance North Cusp"
+    );
+    assert!(
+        occultation.disappearance.is_dark_limb,
+        "Disappearance Dark Limb"
+    );
+    assert_eq!(occultation.reappearance.ut_hour, 4, "Reappearance Hour");
+    assert_eq!(
+        occultation.reappearance.ut_minutes, 33,
+        "Reappearance Minutes"
+    );
+    assert_eq!(
+        UTIL::round_f64(occultation.reappearance.position_angle_deg, 0),
+        321.0,
+        "Reappearance Position Angle"
+    );
+    assert!(
+        !occultation.reappearance.is_dark_limb,
+        "Reappearance Dark Limb"
+    );
+    assert_eq!(
+        UTIL::round_f64(occultation.disappearance.moon_altitude_deg, 1),
+        28.7,
+        "Moon Altitude"
+    );
+
+    let (northern_limit, southern_limit) = OCC::occultation_graze_lines(occultation, 20.0);
+
+    println!(
+        "Occultation graze lines: [Target] {} [Step] 20 minutes = [Northern Limit] {:?} [Southern Limit] {:?}",
+        occultation.target.name, northern_limit, southern_limit
+    );
+
+    assert_eq!(northern_limit.len(), 9, "Northern Limit Points");
+    assert_eq!(southern_limit.len(), 11, "Southern Limit Points");
+
+    let (longitude, latitude) = northern_limit[6];
+    assert_eq!(
+        UTIL::round_f64(longitude, 1),
+        -119.8,
+        "Northern Limit Longitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(latitude, 1),
+        49.0,
+        "Northern Limit Latitude"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_lunar_occultation_of_planet(
+    start_date_day: f64,
+    start_date_month: u32,
+    start_date_year: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let targets = vec![OCC::OccultationTarget {
+        kind: pa_types::OccultationTargetKind::Planet,
+        name: "Venus".to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    }];
+
+    let occultations = OCC::lunar_occultations(
+        &targets,
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        3,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+    );
+    let occultation = &occultations[0];
+
+    println!(
+		"Lunar occultation of planet: [Start] {}/{}/{} [Observer] {} {} = [Target] {} [Disappearance] {}:{}:{} bright limb {} [Reappearance] {}:{}:{} dark limb {} [Sun Altitude] {}",
+		start_date_month,
+		start_date_day,
+		start_date_year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		occultation.target.name,
+		occultation.disappearance.ut_hour,
+		occultation.disappearance.ut_minutes,
+		occultation.disappearance.ut_seconds,
+		!occultation.disappearance.is_dark_limb,
+		occultation.reappearance.ut_hour,
+		occultation.reappearance.ut_minutes,
+		occultation.reappearance.ut_seconds,
+		occultation.reappearance.is_dark_limb,
+		occultation.disappearance.sun_altitude_deg
+	);
+
+    assert_eq!(occultations.len(), 1, "Number of Occultations");
+    assert_eq!(occultation.disappearance.ut_hour, 9, "Disappearance Hour");
+    assert_eq!(
+        occultation.disappearance.ut_minutes, 55,
+        "Disappearance Minutes"
+    );
+    assert!(
+        !occultation.disappearance.is_dark_limb,
+        "Disappearance Dark Limb"
+    );
+    assert_eq!(occultation.reappearance.ut_hour, 10, "Reappearance Hour");
+    assert_eq!(
+        occultation.reappearance.ut_minutes, 56,
+        "Reappearance Minutes"
+    );
+    assert!(
+        occultation.reappearance.is_dark_limb,
+        "Reappearance Dark Limb"
+    );
+    assert_eq!(
+        UTIL::round_f64(occultation.disappearance.sun_altitude_deg, 1),
+        20.7,
+        "Sun Altitude"
+    );
+}
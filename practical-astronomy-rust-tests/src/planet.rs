@@ -1,4 +1,5 @@
 use practical_astronomy_rust::planet as CP;
+use practical_astronomy_rust::types as pa_types;
 
 pub struct TestPositionOfPlanetScaffold {
     pub lct_hour: f64,
@@ -165,3 +166,406 @@ impl TestPositionOfPlanetScaffold {
         assert_eq!(approximate_magnitude, -2.0, "Approximate Magnitude");
     }
 }
+
+#[allow(dead_code)]
+pub fn test_planetary_phenomena(
+    planet_name: String,
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) {
+    let phenomena: Vec<CP::PlanetaryPhenomenon> = CP::planetary_phenomena(
+        planet_name.clone(),
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+    )
+    .collect();
+
+    for phenomenon in phenomena.iter() {
+        println!(
+			"Planetary phenomena: [Planet] {} [Range] {}/{}/{} - {}/{}/{} = [Kind] {:?} [Local Date] {}/{}/{} [LCT] {}:{} [Elongation] {} degrees [Distance] {} AU [Heliocentric Distance] {} AU",
+			planet_name, start_date_month, start_date_day, start_date_year, end_date_month, end_date_day, end_date_year,
+			phenomenon.kind, phenomenon.local_date_month, phenomenon.local_date_day, phenomenon.local_date_year,
+			phenomenon.lct_hour, phenomenon.lct_minutes, phenomenon.elongation_deg, phenomenon.distance_au, phenomenon.heliocentric_distance_au
+		);
+    }
+
+    let expected = [
+        (pa_types::PlanetaryPhenomenonKind::StationaryDirect, 1, 2, 3),
+        (
+            pa_types::PlanetaryPhenomenonKind::GreatestWesternElongation,
+            1,
+            12,
+            14,
+        ),
+        (pa_types::PlanetaryPhenomenonKind::Aphelion, 2, 2, 16),
+        (
+            pa_types::PlanetaryPhenomenonKind::SuperiorConjunction,
+            2,
+            28,
+            8,
+        ),
+        (pa_types::PlanetaryPhenomenonKind::Perihelion, 3, 17, 16),
+        (
+            pa_types::PlanetaryPhenomenonKind::GreatestEasternElongation,
+            3,
+            24,
+            22,
+        ),
+        (
+            pa_types::PlanetaryPhenomenonKind::StationaryRetrograde,
+            4,
+            1,
+            22,
+        ),
+        (
+            pa_types::PlanetaryPhenomenonKind::InferiorConjunction,
+            4,
+            11,
+            23,
+        ),
+        (
+            pa_types::PlanetaryPhenomenonKind::StationaryDirect,
+            4,
+            25,
+            12,
+        ),
+        (pa_types::PlanetaryPhenomenonKind::Aphelion, 4, 30, 16),
+    ];
+    assert_eq!(phenomena.len(), expected.len(), "Number of phenomena");
+    for (phenomenon, (kind, month, day, hour)) in phenomena.iter().zip(expected.iter()) {
+        assert_eq!(phenomenon.kind, *kind, "{:?} - Kind", kind);
+        assert_eq!(phenomenon.local_date_month, *month, "{:?} - Month", kind);
+        assert_eq!(phenomenon.local_date_day, *day, "{:?} - Day", kind);
+        assert_eq!(phenomenon.lct_hour, *hour, "{:?} - Hour", kind);
+    }
+
+    assert_eq!(
+        phenomena[5].elongation_deg, 18.7,
+        "Greatest eastern elongation - Elongation"
+    );
+    assert_eq!(
+        phenomena[5].other_body_name, "Sun",
+        "Greatest eastern elongation - Other body"
+    );
+    assert_eq!(
+        phenomena[4].heliocentric_distance_au, 0.3075,
+        "Perihelion - Heliocentric distance"
+    );
+    assert_eq!(
+        phenomena[4].separation_deg, -99.0,
+        "Perihelion - Separation"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_planetary_conjunctions(
+    planet_name: String,
+    other_body_name: String,
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) {
+    let conjunctions: Vec<CP::PlanetaryPhenomenon> = CP::planetary_conjunctions(
+        planet_name.clone(),
+        other_body_name.clone(),
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+    )
+    .collect();
+
+    for conjunction in conjunctions.iter() {
+        println!(
+			"Planetary conjunctions: [Planet] {} [Other Body] {} [Range] {}/{}/{} - {}/{}/{} = [Local Date] {}/{}/{} [LCT] {}:{} [Separation] {} degrees",
+			planet_name, other_body_name, start_date_month, start_date_day, start_date_year, end_date_month, end_date_day, end_date_year,
+			conjunction.local_date_month, conjunction.local_date_day, conjunction.local_date_year,
+			conjunction.lct_hour, conjunction.lct_minutes, conjunction.separation_deg
+		);
+    }
+
+    assert_eq!(conjunctions.len(), 1, "Number of conjunctions");
+    assert_eq!(
+        conjunctions[0].kind,
+        pa_types::PlanetaryPhenomenonKind::Conjunction,
+        "Kind"
+    );
+    assert_eq!(conjunctions[0].local_date_month, 3, "Local date - Month");
+    assert_eq!(conjunctions[0].local_date_day, 2, "Local date - Day");
+    assert_eq!(conjunctions[0].lct_hour, 5, "Local civil time - Hour");
+    assert_eq!(conjunctions[0].separation_deg, 0.49, "Separation");
+
+    let moon_conjunctions: Vec<CP::PlanetaryPhenomenon> = CP::planetary_conjunctions(
+        other_body_name.clone(),
+        "Moon".to_string(),
+        1,
+        1,
+        start_date_year,
+        31,
+        3,
+        start_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+    )
+    .collect();
+
+    assert_eq!(moon_conjunctions.len(), 3, "Moon - Number of conjunctions");
+    assert_eq!(moon_conjunctions[2].local_date_day, 22, "Moon - Day");
+    assert_eq!(
+        moon_conjunctions[2].separation_deg, 0.47,
+        "Moon - Separation"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_planetary_physical_ephemeris(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) {
+    let mars = CP::planetary_physical_ephemeris(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        9.0,
+        11,
+        1992,
+        "Mars".to_string(),
+    );
+    let jupiter = CP::planetary_physical_ephemeris(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        "Jupiter".to_string(),
+    );
+    let saturn = CP::planetary_physical_ephemeris(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        "Saturn".to_string(),
+    );
+    let venus = CP::planetary_physical_ephemeris(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        "Venus".to_string(),
+    );
+
+    for ephemeris in [&mars, &jupiter, &saturn, &venus] {
+        println!(
+			"Planetary physical ephemeris: [Planet] {} = [Status] {} [Sub-Earth Latitude] {} degrees [Sub-Solar Latitude] {} degrees [Position Angle of Axis] {} degrees [Central Meridian] {} degrees [CM System I] {} degrees [CM System II] {} degrees [Ring Axes] {}/{} arcsec",
+			ephemeris.planet_name,
+			ephemeris.status,
+			ephemeris.sub_earth_latitude_deg,
+			ephemeris.sub_solar_latitude_deg,
+			ephemeris.position_angle_of_axis_deg,
+			ephemeris.central_meridian_deg,
+			ephemeris.central_meridian_system_i_deg,
+			ephemeris.central_meridian_system_ii_deg,
+			ephemeris.ring_major_axis_arcsec,
+			ephemeris.ring_minor_axis_arcsec
+		);
+    }
+
+    assert_eq!(mars.status, "OK", "Mars - Status");
+    assert_eq!(
+        mars.sub_earth_latitude_deg, 12.44,
+        "Mars - Sub-Earth latitude"
+    );
+    assert_eq!(
+        mars.sub_solar_latitude_deg, -2.76,
+        "Mars - Sub-solar latitude"
+    );
+    assert_eq!(
+        mars.position_angle_of_axis_deg, 347.64,
+        "Mars - Position angle of axis"
+    );
+    assert_eq!(mars.central_meridian_deg, 111.53, "Mars - Central meridian");
+    assert_eq!(mars.ring_major_axis_arcsec, -99.0, "Mars - Ring major axis");
+
+    assert_eq!(
+        jupiter.sub_earth_latitude_deg, -2.48,
+        "Jupiter - Sub-Earth latitude"
+    );
+    assert_eq!(
+        jupiter.position_angle_of_axis_deg, 24.8,
+        "Jupiter - Position angle of axis"
+    );
+    assert_eq!(
+        jupiter.central_meridian_system_i_deg, 267.64,
+        "Jupiter - Central meridian (System I)"
+    );
+    assert_eq!(
+        jupiter.central_meridian_system_ii_deg, 72.26,
+        "Jupiter - Central meridian (System II)"
+    );
+    assert_eq!(
+        jupiter.central_meridian_deg, 349.62,
+        "Jupiter - Central meridian (System III)"
+    );
+
+    assert_eq!(saturn.sub_earth_latitude_deg, 16.44, "Saturn - Ring tilt B");
+    assert_eq!(
+        saturn.sub_solar_latitude_deg, 14.68,
+        "Saturn - Ring tilt B'"
+    );
+    assert_eq!(
+        saturn.ring_major_axis_arcsec, 35.87,
+        "Saturn - Ring major axis"
+    );
+    assert_eq!(
+        saturn.ring_minor_axis_arcsec, 10.15,
+        "Saturn - Ring minor axis"
+    );
+
+    assert_eq!(venus.status, "NotSupported", "Venus - Status");
+}
+
+#[allow(dead_code)]
+pub fn test_great_red_spot_transits(
+    grs_longitude_deg: f64,
+    local_date_day: u32,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) {
+    let transits = CP::great_red_spot_transits(
+        grs_longitude_deg,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+    );
+
+    for (lct_hour, lct_minutes, lct_seconds) in transits.iter() {
+        println!(
+			"Great Red Spot transits: [GRS Longitude] {} degrees [Local Date] {}/{}/{} [DST?] {} [Zone Correction] {} = [LCT] {}:{}:{}",
+			grs_longitude_deg, local_date_month, local_date_day, local_date_year, is_daylight_saving, zone_correction_hours,
+			lct_hour, lct_minutes, lct_seconds
+		);
+    }
+
+    assert_eq!(transits.len(), 3, "Number of transits");
+    assert_eq!(transits[1].0, 9, "Second transit - Hour");
+    assert_eq!(transits[1].1, 56, "Second transit - Minutes");
+    assert_eq!(transits[2].0, 19, "Third transit - Hour");
+    assert_eq!(transits[2].1, 52, "Third transit - Minutes");
+}
+
+#[allow(dead_code)]
+pub fn test_visual_aspects_of_a_planet_by_model(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) {
+    let magnitude = |planet_name: &str, magnitude_model: pa_types::MagnitudeModel| {
+        CP::visual_aspects_of_a_planet_by_model(
+            lct_hour,
+            lct_min,
+            lct_sec,
+            is_daylight_saving,
+            zone_correction_hours,
+            local_date_day,
+            local_date_month,
+            local_date_year,
+            planet_name.to_string(),
+            magnitude_model,
+        )
+        .7
+    };
+
+    for planet_name in [
+        "Mercury", "Venus", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune",
+    ] {
+        println!(
+			"Visual aspects of a planet by model: [Local Civil Time] {}:{}:{} [DST?] {} [Zone Correction] {} [Local Date] {}/{}/{} [Planet] {} = [Book Magnitude] {} [Mallama & Hilton 2018 Magnitude] {}",
+			lct_hour, lct_min, lct_sec, is_daylight_saving, zone_correction_hours,
+			local_date_month, local_date_day, local_date_year, planet_name,
+			magnitude(planet_name, pa_types::MagnitudeModel::Book),
+			magnitude(planet_name, pa_types::MagnitudeModel::MallamaHilton2018)
+		);
+    }
+
+    assert_eq!(
+        magnitude("Venus", pa_types::MagnitudeModel::Book),
+        CP::visual_aspects_of_a_planet(
+            lct_hour,
+            lct_min,
+            lct_sec,
+            is_daylight_saving,
+            zone_correction_hours,
+            local_date_day,
+            local_date_month,
+            local_date_year,
+            "Venus".to_string(),
+        )
+        .7,
+        "Venus - Book magnitude"
+    );
+    for (planet_name, expected_magnitude) in [
+        ("Mercury", -1.5),
+        ("Venus", -4.7),
+        ("Mars", 1.7),
+        ("Jupiter", -2.2),
+        ("Saturn", 0.7),
+        ("Uranus", 5.8),
+        ("Neptune", 7.7),
+    ] {
+        assert_eq!(
+            magnitude(planet_name, pa_types::MagnitudeModel::MallamaHilton2018),
+            expected_magnitude,
+            "{} - Mallama & Hilton magnitude",
+            planet_name
+        );
+    }
+}
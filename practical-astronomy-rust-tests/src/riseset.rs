@@ -0,0 +1,376 @@
+use practical_astronomy_rust::riseset as RS;
+use practical_astronomy_rust::types as PA_TYPES;
+use practical_astronomy_rust::util as UTIL;
+
+#[allow(dead_code)]
+pub fn test_rise_transit_set(
+    body_kind: PA_TYPES::RiseSetBodyKind,
+    body_name: String,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let body = RS::RiseSetBody {
+        kind: body_kind,
+        name: body_name.to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+    let rise_transit_set = RS::rise_transit_set(
+        &body,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+        RS::standard_horizon_altitude(body_kind),
+        false,
+    );
+
+    let rise = rise_transit_set.rise.unwrap();
+    let upper_transit = rise_transit_set.upper_transit.unwrap();
+    let set = rise_transit_set.set.unwrap();
+
+    println!(
+		"Rise, transit and set: [Body] {} [Local Date] {}/{}/{} [DST] {} [Zone Correction] {} [Geographical Longitude/Latitude] {} degrees / {} degrees = [Status] {} [Rise] {}:{} [Azimuth] {} degrees [Upper Transit] {}:{} [Altitude] {} degrees [Set] {}:{} [Azimuth] {} degrees",
+		body_name,
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		is_daylight_saving,
+		zone_correction_hours,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		rise_transit_set.status,
+		rise.lct_hour,
+		rise.lct_minutes,
+		rise.azimuth_deg,
+		upper_transit.lct_hour,
+		upper_transit.lct_minutes,
+		upper_transit.altitude_deg,
+		set.lct_hour,
+		set.lct_minutes,
+		set.azimuth_deg
+	);
+
+    match body_kind {
+        PA_TYPES::RiseSetBodyKind::Sun => {
+            assert_eq!(rise_transit_set.status, "OK", "Status");
+            assert_eq!(rise.lct_hour, 6, "Rise Hour");
+            assert_eq!(rise.lct_minutes, 5, "Rise Minutes");
+            assert_eq!(UTIL::round_f64(rise.azimuth_deg, 2), 94.83, "Rise Azimuth");
+            assert_eq!(upper_transit.lct_hour, 11, "Upper Transit Hour");
+            assert_eq!(upper_transit.lct_minutes, 54, "Upper Transit Minutes");
+            assert_eq!(
+                UTIL::round_f64(upper_transit.altitude_deg, 2),
+                43.6,
+                "Upper Transit Altitude"
+            );
+            assert_eq!(set.lct_hour, 17, "Set Hour");
+            assert_eq!(set.lct_minutes, 44, "Set Minutes");
+            assert_eq!(UTIL::round_f64(set.azimuth_deg, 2), 265.43, "Set Azimuth");
+        }
+        PA_TYPES::RiseSetBodyKind::Moon => {
+            assert_eq!(rise_transit_set.status, "OK", "Status");
+            assert_eq!(rise.lct_hour, 4, "Rise Hour");
+            assert_eq!(rise.lct_minutes, 20, "Rise Minutes");
+            assert_eq!(UTIL::round_f64(rise.azimuth_deg, 2), 127.33, "Rise Azimuth");
+            assert_eq!(upper_transit.lct_hour, 8, "Upper Transit Hour");
+            assert_eq!(upper_transit.lct_minutes, 41, "Upper Transit Minutes");
+            assert_eq!(
+                UTIL::round_f64(upper_transit.altitude_deg, 2),
+                20.64,
+                "Upper Transit Altitude"
+            );
+            assert_eq!(set.lct_hour, 13, "Set Hour");
+            assert_eq!(set.lct_minutes, 7, "Set Minutes");
+        }
+        _ => {
+            assert_eq!(rise.lct_hour, 5, "Rise Hour");
+            assert_eq!(rise.lct_minutes, 48, "Rise Minutes");
+            assert_eq!(upper_transit.lct_hour, 11, "Upper Transit Hour");
+            assert_eq!(upper_transit.lct_minutes, 10, "Upper Transit Minutes");
+            assert_eq!(set.lct_hour, 16, "Set Hour");
+            assert_eq!(set.lct_minutes, 31, "Set Minutes");
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub fn test_rise_transit_set_of_star(
+    star_name: String,
+    right_ascension_hours: f64,
+    declination_deg: f64,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let body = RS::RiseSetBody {
+        kind: PA_TYPES::RiseSetBodyKind::Star,
+        name: star_name.to_string(),
+        right_ascension_hours,
+        declination_deg,
+    };
+    let rise_transit_set = RS::rise_transit_set(
+        &body,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        false,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+        RS::standard_horizon_altitude(PA_TYPES::RiseSetBodyKind::Star),
+        false,
+    );
+
+    let upper_transit = rise_transit_set.upper_transit.unwrap();
+    let lower_transit = rise_transit_set.lower_transit.unwrap();
+
+    println!(
+		"Rise, transit and set of a star: [Star] {} [RA/Dec] {} hours / {} degrees [Local Date] {}/{}/{} [Zone Correction] {} [Geographical Longitude/Latitude] {} degrees / {} degrees = [Status] {} [Upper Transit] {}:{} [Altitude] {} degrees [Lower Transit] {}:{} [Altitude] {} degrees",
+		star_name,
+		right_ascension_hours,
+		declination_deg,
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		zone_correction_hours,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		rise_transit_set.status,
+		upper_transit.lct_hour,
+		upper_transit.lct_minutes,
+		upper_transit.altitude_deg,
+		lower_transit.lct_hour,
+		lower_transit.lct_minutes,
+		lower_transit.altitude_deg
+	);
+
+    assert_eq!(rise_transit_set.status, "OK", "Status");
+    assert_eq!(upper_transit.lct_hour, 18, "Upper Transit Hour");
+    assert_eq!(upper_transit.lct_minutes, 2, "Upper Transit Minutes");
+    assert_eq!(
+        UTIL::round_f64(upper_transit.altitude_deg, 2),
+        86.38,
+        "Upper Transit Altitude"
+    );
+    assert_eq!(lower_transit.lct_hour, 6, "Lower Transit Hour");
+    assert_eq!(lower_transit.lct_minutes, 4, "Lower Transit Minutes");
+    assert_eq!(
+        UTIL::round_f64(lower_transit.altitude_deg, 2),
+        -1.65,
+        "Lower Transit Altitude"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_rise_transit_set_of_comet(
+    comet_name: String,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let body = RS::RiseSetBody {
+        kind: PA_TYPES::RiseSetBodyKind::Comet,
+        name: comet_name.to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+    let rise_transit_set = RS::rise_transit_set(
+        &body,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        false,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+        RS::standard_horizon_altitude(PA_TYPES::RiseSetBodyKind::Comet),
+        false,
+    );
+
+    let rise = rise_transit_set.rise.unwrap();
+    let upper_transit = rise_transit_set.upper_transit.unwrap();
+    let set = rise_transit_set.set.unwrap();
+
+    println!(
+		"Rise, transit and set of a comet: [Comet] {} [Local Date] {}/{}/{} [Zone Correction] {} [Geographical Longitude/Latitude] {} degrees / {} degrees = [Status] {} [Rise] {}:{} [Upper Transit] {}:{} [Altitude] {} degrees [Set] {}:{}",
+		comet_name,
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		zone_correction_hours,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		rise_transit_set.status,
+		rise.lct_hour,
+		rise.lct_minutes,
+		upper_transit.lct_hour,
+		upper_transit.lct_minutes,
+		upper_transit.altitude_deg,
+		set.lct_hour,
+		set.lct_minutes
+	);
+
+    assert_eq!(rise_transit_set.status, "OK", "Status");
+    assert_eq!(rise.lct_hour, 9, "Rise Hour");
+    assert_eq!(rise.lct_minutes, 59, "Rise Minutes");
+    assert_eq!(upper_transit.lct_hour, 15, "Upper Transit Hour");
+    assert_eq!(upper_transit.lct_minutes, 59, "Upper Transit Minutes");
+    assert_eq!(
+        UTIL::round_f64(upper_transit.altitude_deg, 2),
+        47.02,
+        "Upper Transit Altitude"
+    );
+    assert_eq!(set.lct_hour, 21, "Set Hour");
+    assert_eq!(set.lct_minutes, 54, "Set Minutes");
+}
+
+#[allow(dead_code)]
+pub fn test_rise_transit_set_polar(
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    observer_height_m: f64,
+) {
+    let body = RS::RiseSetBody {
+        kind: PA_TYPES::RiseSetBodyKind::Sun,
+        name: "Sun".to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+    let rise_transit_set = RS::rise_transit_set(
+        &body,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        false,
+        1,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        observer_height_m,
+        RS::standard_horizon_altitude(PA_TYPES::RiseSetBodyKind::Sun),
+        true,
+    );
+
+    println!(
+		"Rise, transit and set (polar): [Local Date] {}/{}/{} [Geographical Longitude/Latitude] {} degrees / {} degrees [Height] {} m = [Status] {} [Horizon Altitude] {} degrees [Upper Transit Altitude] {} degrees",
+		local_date_month,
+		local_date_day,
+		local_date_year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		observer_height_m,
+		rise_transit_set.status,
+		rise_transit_set.horizon_altitude_deg,
+		rise_transit_set.upper_transit.unwrap().altitude_deg
+	);
+
+    assert_eq!(rise_transit_set.status, "never rises", "Status");
+    assert!(rise_transit_set.rise.is_none(), "Rise");
+    assert!(rise_transit_set.set.is_none(), "Set");
+    assert_eq!(
+        UTIL::round_f64(rise_transit_set.horizon_altitude_deg, 4),
+        -1.0405,
+        "Horizon Altitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(rise_transit_set.upper_transit.unwrap().altitude_deg, 2),
+        -3.09,
+        "Upper Transit Altitude"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_rise_set_events(
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) {
+    let body = RS::RiseSetBody {
+        kind: PA_TYPES::RiseSetBodyKind::Moon,
+        name: "Moon".to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+    let events = RS::rise_set_events(
+        &body,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        false,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        0.0,
+        RS::standard_horizon_altitude(PA_TYPES::RiseSetBodyKind::Moon),
+        false,
+    );
+
+    for event in events.iter() {
+        println!(
+			"Rise and set events: [Body] Moon [Local Date] {}/{}/{} [Zone Correction] {} [Geographical Longitude/Latitude] {} degrees / {} degrees = [Event] {:?} {}:{} [Altitude] {} degrees [Azimuth] {} degrees",
+			local_date_month,
+			local_date_day,
+			local_date_year,
+			zone_correction_hours,
+			geog_longitude_deg,
+			geog_latitude_deg,
+			event.kind,
+			event.lct_hour,
+			event.lct_minutes,
+			event.altitude_deg,
+			event.azimuth_deg
+		);
+    }
+
+    let kinds: Vec<PA_TYPES::RiseSetEventKind> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            PA_TYPES::RiseSetEventKind::Rise,
+            PA_TYPES::RiseSetEventKind::UpperTransit,
+            PA_TYPES::RiseSetEventKind::Set,
+            PA_TYPES::RiseSetEventKind::LowerTransit,
+            PA_TYPES::RiseSetEventKind::Rise,
+        ],
+        "Event Kinds"
+    );
+    assert_eq!(events[0].lct_hour, 0, "First Rise Hour");
+    assert_eq!(events[0].lct_minutes, 26, "First Rise Minutes");
+    assert_eq!(
+        UTIL::round_f64(events[0].azimuth_deg, 2),
+        56.92,
+        "First Rise Azimuth"
+    );
+    assert_eq!(events[2].lct_hour, 18, "Set Hour");
+    assert_eq!(events[2].lct_minutes, 22, "Set Minutes");
+    assert_eq!(
+        UTIL::round_f64(events[3].altitude_deg, 2),
+        -4.99,
+        "Lower Transit Altitude"
+    );
+    assert_eq!(events[4].lct_hour, 23, "Second Rise Hour");
+    assert_eq!(events[4].lct_minutes, 42, "Second Rise Minutes");
+}
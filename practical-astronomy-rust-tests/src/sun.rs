@@ -1,5 +1,7 @@
 use practical_astronomy_rust::sun as CS;
+use practical_astronomy_rust::sun::spa as SPA;
 use practical_astronomy_rust::types as pa_types;
+use practical_astronomy_rust::util as UTIL;
 
 #[allow(dead_code)]
 pub fn test_approximate_position_of_sun(
@@ -260,6 +262,296 @@ pub fn test_morning_and_evening_twilight(
     assert_eq!(status, "OK", "Status of Calculation");
 }
 
+#[allow(dead_code)]
+pub fn test_polar_day_and_night_states(
+    local_year: u32,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+) {
+    let (_, _, _, _, _, _, midsummer_status) = CS::sunrise_and_sunset(
+        21.0,
+        6,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+    );
+    let (_, _, _, _, _, _, midwinter_status) = CS::sunrise_and_sunset(
+        21.0,
+        12,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+    );
+    let (_, _, _, _, midsummer_twilight_status) = CS::morning_and_evening_twilight(
+        21.0,
+        6,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        pa_types::TwilightType::Civil,
+    );
+    let (am_twilight_begins_hour, _, _, _, white_night_status) = CS::morning_and_evening_twilight(
+        1.0,
+        5,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        pa_types::TwilightType::Civil,
+    );
+    let (_, _, _, _, midwinter_twilight_status) = CS::morning_and_evening_twilight(
+        21.0,
+        12,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        pa_types::TwilightType::Civil,
+    );
+
+    println!(
+		"Polar day and night states: [Year] {} [Zone Correction] {} [Geographical Longitude/Latitude] {}d/{}d = [Sunrise and Sunset] [June 21] {} [December 21] {} [Civil Twilight] [June 21] {} [May 1] {} [December 21] {}",
+		local_year,
+		zone_correction,
+		geographical_long_deg,
+		geographical_lat_deg,
+		midsummer_status,
+		midwinter_status,
+		midsummer_twilight_status,
+		white_night_status,
+		midwinter_twilight_status
+	);
+
+    assert_eq!(midsummer_status, "** polar day", "Sunrise Status (June 21)");
+    assert_eq!(
+        midwinter_status, "** polar night",
+        "Sunrise Status (December 21)"
+    );
+    assert_eq!(
+        midsummer_twilight_status, "** polar day",
+        "Twilight Status (June 21)"
+    );
+    assert_eq!(
+        white_night_status, "** continuous twilight",
+        "Twilight Status (May 1)"
+    );
+    assert_eq!(am_twilight_begins_hour, -99.0, "AM Twilight Begins (May 1)");
+    assert_eq!(
+        midwinter_twilight_status, "OK",
+        "Twilight Status (December 21)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_golden_and_blue_hour(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+) {
+    let golden_hours = CS::golden_and_blue_hour(
+        local_day,
+        local_month,
+        local_year,
+        is_daylight_saving,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        pa_types::PhotographicLight::GoldenHour,
+    );
+    let blue_hours = CS::golden_and_blue_hour(
+        local_day,
+        local_month,
+        local_year,
+        is_daylight_saving,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        pa_types::PhotographicLight::BlueHour,
+    );
+
+    let morning_golden_start = golden_hours[0].start.unwrap();
+    let morning_golden_end = golden_hours[0].end.unwrap();
+    let evening_golden_start = golden_hours[1].start.unwrap();
+    let evening_golden_end = golden_hours[1].end.unwrap();
+    let morning_blue_start = blue_hours[0].start.unwrap();
+    let evening_blue_end = blue_hours[1].end.unwrap();
+
+    println!(
+		"Golden and blue hour: [Local Date] {}/{}/{} [DST?] {} [Zone Correction] {} [Geographical Longitude/Latitude] {}d/{}d = [Golden Hour] {}:{}-{}:{} and {}:{}-{}:{} [Blue Hour] {}:{}-{}:{} and {}:{}-{}:{}",
+		local_month,
+		local_day,
+		local_year,
+		is_daylight_saving,
+		zone_correction,
+		geographical_long_deg,
+		geographical_lat_deg,
+		morning_golden_start.lct_hour,
+		morning_golden_start.lct_minutes,
+		morning_golden_end.lct_hour,
+		morning_golden_end.lct_minutes,
+		evening_golden_start.lct_hour,
+		evening_golden_start.lct_minutes,
+		evening_golden_end.lct_hour,
+		evening_golden_end.lct_minutes,
+		morning_blue_start.lct_hour,
+		morning_blue_start.lct_minutes,
+		blue_hours[0].end.unwrap().lct_hour,
+		blue_hours[0].end.unwrap().lct_minutes,
+		blue_hours[1].start.unwrap().lct_hour,
+		blue_hours[1].start.unwrap().lct_minutes,
+		evening_blue_end.lct_hour,
+		evening_blue_end.lct_minutes
+	);
+
+    assert_eq!(golden_hours.len(), 2, "Golden Hour Periods");
+    assert!(golden_hours[0].is_rising, "Morning Golden Hour Rising");
+    assert!(!golden_hours[1].is_rising, "Evening Golden Hour Rising");
+    assert_eq!(
+        morning_golden_start.lct_hour, 5,
+        "Morning Golden Hour Start (hour)"
+    );
+    assert_eq!(
+        morning_golden_start.lct_minutes, 47,
+        "Morning Golden Hour Start (minutes)"
+    );
+    assert_eq!(
+        morning_golden_end.lct_hour, 6,
+        "Morning Golden Hour End (hour)"
+    );
+    assert_eq!(
+        morning_golden_end.lct_minutes, 42,
+        "Morning Golden Hour End (minutes)"
+    );
+    assert_eq!(
+        evening_golden_start.lct_hour, 17,
+        "Evening Golden Hour Start (hour)"
+    );
+    assert_eq!(
+        evening_golden_start.lct_minutes, 7,
+        "Evening Golden Hour Start (minutes)"
+    );
+    assert_eq!(
+        evening_golden_end.lct_hour, 18,
+        "Evening Golden Hour End (hour)"
+    );
+    assert_eq!(
+        evening_golden_end.lct_minutes, 1,
+        "Evening Golden Hour End (minutes)"
+    );
+    assert_eq!(blue_hours.len(), 2, "Blue Hour Periods");
+    assert_eq!(
+        morning_blue_start.lct_hour, 5,
+        "Morning Blue Hour Start (hour)"
+    );
+    assert_eq!(
+        morning_blue_start.lct_minutes, 37,
+        "Morning Blue Hour Start (minutes)"
+    );
+    assert_eq!(
+        blue_hours[0].end.unwrap().jd_ut,
+        morning_golden_start.jd_ut,
+        "Morning Blue Hour End"
+    );
+    assert_eq!(
+        evening_blue_end.lct_hour, 18,
+        "Evening Blue Hour End (hour)"
+    );
+    assert_eq!(
+        evening_blue_end.lct_minutes, 12,
+        "Evening Blue Hour End (minutes)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_solar_altitude_intervals(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+    lower_altitude_deg: f64,
+    upper_altitude_deg: f64,
+) {
+    let intervals = CS::solar_altitude_intervals(
+        local_day,
+        local_month,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        lower_altitude_deg,
+        upper_altitude_deg,
+    );
+    let crossings = CS::solar_altitude_crossings(
+        local_day,
+        local_month,
+        local_year,
+        false,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        upper_altitude_deg,
+    );
+
+    let morning_end = intervals[0].end.unwrap();
+    let evening_start = intervals[1].start.unwrap();
+
+    println!(
+		"Solar altitude intervals: [Local Date] {}/{}/{} [Zone Correction] {} [Geographical Longitude/Latitude] {}d/{}d [Altitudes] {}d to {}d = [Periods] until {}:{} and from {}:{} [Crossings of {}d] {}",
+		local_month,
+		local_day,
+		local_year,
+		zone_correction,
+		geographical_long_deg,
+		geographical_lat_deg,
+		lower_altitude_deg,
+		upper_altitude_deg,
+		morning_end.lct_hour,
+		morning_end.lct_minutes,
+		evening_start.lct_hour,
+		evening_start.lct_minutes,
+		upper_altitude_deg,
+		crossings.len()
+	);
+
+    assert_eq!(intervals.len(), 2, "Periods");
+    assert!(intervals[0].start.is_none(), "Morning Period Start");
+    assert_eq!(morning_end.lct_hour, 1, "Morning Period End (hour)");
+    assert_eq!(morning_end.lct_minutes, 2, "Morning Period End (minutes)");
+    assert_eq!(evening_start.lct_hour, 22, "Evening Period Start (hour)");
+    assert_eq!(
+        evening_start.lct_minutes, 30,
+        "Evening Period Start (minutes)"
+    );
+    assert!(intervals[1].end.is_none(), "Evening Period End");
+    assert_eq!(crossings.len(), 2, "Crossings");
+    assert_eq!(
+        crossings[0].kind,
+        pa_types::RiseSetEventKind::Rise,
+        "First Crossing"
+    );
+    assert_eq!(
+        UTIL::round_f64(crossings[1].altitude_deg, 4),
+        upper_altitude_deg,
+        "Altitude at Crossing"
+    );
+}
+
 #[allow(dead_code)]
 pub fn test_equation_of_time(gwdate_day: f64, gwdate_month: u32, gwdate_year: u32) {
     let (equation_of_time_min, equation_of_time_sec) =
@@ -313,3 +605,373 @@ pub fn test_solar_elongation(
 
     assert_eq!(solar_elongation_deg, 24.78, "Solar Elongation (degrees)");
 }
+
+#[allow(dead_code)]
+pub fn test_solar_position_spa(
+    local_hour: f64,
+    local_minute: f64,
+    local_second: f64,
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    zone_correction_hours: f64,
+    delta_t_seconds: f64,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    elevation_m: f64,
+) {
+    let solar_position = SPA::solar_position(
+        local_hour,
+        local_minute,
+        local_second,
+        local_day,
+        local_month,
+        local_year,
+        zone_correction_hours,
+        0.0,
+        delta_t_seconds,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        elevation_m,
+        820.0,
+        11.0,
+        30.0,
+        -10.0,
+        0.5667,
+    );
+
+    println!(
+		"Solar position (SPA): [Local Date/Time] {}/{}/{} {}:{}:{} [Zone] {} [Delta T] {} [Geographical Longitude/Latitude] {}d/{}d [Elevation] {} m = [Zenith] {}d [Azimuth] {}d [Incidence] {}d [Equation of Time] {} min [Sunrise] {} h [Transit] {} h [Sunset] {} h",
+		local_month,
+		local_day,
+		local_year,
+		local_hour,
+		local_minute,
+		local_second,
+		zone_correction_hours,
+		delta_t_seconds,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		elevation_m,
+		solar_position.zenith_deg,
+		solar_position.azimuth_deg,
+		solar_position.incidence_deg,
+		solar_position.equation_of_time_min,
+		solar_position.sunrise_hours,
+		solar_position.sun_transit_hours,
+		solar_position.sunset_hours
+	);
+
+    assert_eq!(
+        UTIL::round_f64(solar_position.julian_day, 6),
+        2452930.312847,
+        "Julian Day"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.heliocentric_longitude_deg, 6),
+        24.018262,
+        "Heliocentric Longitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.radius_vector_au, 6),
+        0.996542,
+        "Radius Vector"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.topocentric_declination_deg, 6),
+        -9.316179,
+        "Topocentric Declination"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.zenith_deg, 5),
+        50.11162,
+        "Zenith"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.azimuth_deg, 5),
+        194.34024,
+        "Azimuth"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.incidence_deg, 5),
+        25.187,
+        "Incidence"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.equation_of_time_min, 4),
+        14.6415,
+        "Equation of Time"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.sunrise_hours, 6),
+        6.212067,
+        "Sunrise"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.sun_transit_hours, 6),
+        11.768045,
+        "Sun Transit"
+    );
+    assert_eq!(
+        UTIL::round_f64(solar_position.sunset_hours, 6),
+        17.338667,
+        "Sunset"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_equinoxes_and_solstices(year: u32) {
+    let seasons = CS::equinoxes_and_solstices(year);
+
+    for season in &seasons {
+        println!(
+			"Equinoxes and solstices: [Year] {} = [{}] [TT] {}/{}/{} {}:{}:{} [UT] {}/{}/{} {}:{}:{}",
+			year,
+			season.name,
+			season.tt_date_month,
+			season.tt_date_day,
+			season.tt_date_year,
+			season.tt_hour,
+			season.tt_minutes,
+			season.tt_seconds,
+			season.ut_date_month,
+			season.ut_date_day,
+			season.ut_date_year,
+			season.ut_hour,
+			season.ut_minutes,
+			season.ut_seconds
+		);
+    }
+
+    let expected = [
+        ("March equinox", 3, 20, 3, 6),
+        ("June solstice", 6, 20, 20, 50),
+        ("September equinox", 9, 22, 12, 43),
+        ("December solstice", 12, 21, 9, 20),
+    ];
+    for (season, (name, month, day, hour, minutes)) in seasons.iter().zip(expected) {
+        assert_eq!(season.name, name, "Name");
+        assert_eq!(season.ut_date_month, month, "{} - Month (UT)", name);
+        assert_eq!(season.ut_date_day, day, "{} - Day (UT)", name);
+        assert_eq!(season.ut_hour, hour, "{} - Hour (UT)", name);
+        assert_eq!(season.ut_minutes, minutes, "{} - Minutes (UT)", name);
+    }
+
+    assert_eq!(seasons[0].tt_hour, 3, "March equinox - Hour (TT)");
+    assert_eq!(seasons[0].tt_minutes, 7, "March equinox - Minutes (TT)");
+    assert_eq!(
+        UTIL::round_f64(seasons[0].jd_tt, 5),
+        2460389.63026,
+        "March equinox - JD (TT)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_solar_terms_and_cross_quarter_days(year: u32) {
+    let terms = CS::solar_terms(year);
+    let cross_quarter_days = CS::cross_quarter_days(year);
+
+    for term in terms.iter().chain(cross_quarter_days.iter()) {
+        println!(
+			"Solar terms and cross-quarter days: [Year] {} = [{}] [Solar Longitude] {} degrees [UT] {}/{}/{} {}:{}",
+			year,
+			term.name,
+			term.solar_longitude_deg,
+			term.ut_date_month,
+			term.ut_date_day,
+			term.ut_date_year,
+			term.ut_hour,
+			term.ut_minutes
+		);
+    }
+
+    assert_eq!(terms.len(), 24, "Number of solar terms");
+    assert_eq!(terms[0].name, "Xiaohan", "First solar term");
+    assert_eq!(terms[0].ut_date_day, 5, "Xiaohan - Day (UT)");
+    assert_eq!(terms[2].name, "Lichun", "Third solar term");
+    assert_eq!(terms[2].ut_date_month, 2, "Lichun - Month (UT)");
+    assert_eq!(terms[2].ut_date_day, 4, "Lichun - Day (UT)");
+    assert_eq!(terms[2].ut_hour, 8, "Lichun - Hour (UT)");
+    assert_eq!(terms[2].ut_minutes, 27, "Lichun - Minutes (UT)");
+    assert_eq!(terms[23].name, "Dongzhi", "Last solar term");
+    assert_eq!(terms[23].ut_date_month, 12, "Dongzhi - Month (UT)");
+    assert!(
+        terms.windows(2).all(|pair| pair[0].jd_tt < pair[1].jd_tt),
+        "Solar terms in date order"
+    );
+
+    assert_eq!(cross_quarter_days[3].name, "Samhain", "Samhain");
+    assert_eq!(
+        cross_quarter_days[3].ut_date_month, 11,
+        "Samhain - Month (UT)"
+    );
+    assert_eq!(cross_quarter_days[3].ut_date_day, 6, "Samhain - Day (UT)");
+    assert_eq!(cross_quarter_days[3].ut_hour, 22, "Samhain - Hour (UT)");
+    assert_eq!(
+        cross_quarter_days[3].ut_minutes, 19,
+        "Samhain - Minutes (UT)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_equation_of_time_table(year: u32) {
+    let table = CS::equation_of_time_table(year);
+
+    for entry in table.iter().step_by(30) {
+        println!(
+			"Equation of time table: [Date] {}/{}/{} = [Equation of Time] {} minutes [Declination] {} degrees",
+			entry.month, entry.day, entry.year, entry.equation_of_time_min, entry.declination_deg
+		);
+    }
+
+    assert_eq!(table.len(), 366, "Number of days");
+    assert_eq!(table[0].day, 1, "First entry - Day");
+    assert_eq!(table[365].month, 12, "Last entry - Month");
+    assert_eq!(table[365].day, 31, "Last entry - Day");
+    assert_eq!(
+        UTIL::round_f64(table[0].equation_of_time_min * 60.0, 1),
+        -198.7,
+        "1 January - Equation of time (seconds)"
+    );
+    assert_eq!(
+        UTIL::round_f64(table[0].declination_deg, 4),
+        -23.0192,
+        "1 January - Declination"
+    );
+    assert_eq!(
+        UTIL::round_f64(table[306].equation_of_time_min * 60.0, 1),
+        987.4,
+        "2 November - Equation of time (seconds)"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_analemma(
+    lct_hours: f64,
+    lct_minutes: f64,
+    lct_seconds: f64,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    year: u32,
+    interval_days: u32,
+) {
+    let points = CS::analemma(
+        lct_hours,
+        lct_minutes,
+        lct_seconds,
+        is_daylight_saving,
+        zone_correction,
+        geog_long_deg,
+        geog_lat_deg,
+        year,
+        interval_days,
+    );
+
+    for point in points.iter() {
+        println!(
+			"Analemma: [Local Civil Time] {}:{}:{} [DST?] {} [Zone Correction] {} [Geographical Longitude/Latitude] {}/{} degrees [Date] {}/{}/{} = [Altitude] {} degrees [Azimuth] {} degrees",
+			lct_hours, lct_minutes, lct_seconds, is_daylight_saving, zone_correction, geog_long_deg, geog_lat_deg,
+			point.month, point.day, point.year, point.altitude_deg, point.azimuth_deg
+		);
+    }
+
+    assert_eq!(points.len(), 53, "Number of points");
+    assert_eq!(
+        UTIL::round_f64(points[0].altitude_deg, 2),
+        15.49,
+        "1 January - Altitude"
+    );
+    assert_eq!(
+        UTIL::round_f64(points[0].azimuth_deg, 2),
+        179.21,
+        "1 January - Azimuth"
+    );
+    assert_eq!(points[25].day, 24, "Point 25 - Day");
+    assert_eq!(points[25].month, 6, "Point 25 - Month");
+    assert_eq!(
+        UTIL::round_f64(points[25].altitude_deg, 2),
+        61.91,
+        "24 June - Altitude"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_sundial_hour_lines(geog_long_deg: f64, geog_lat_deg: f64, zone_correction: i32) {
+    let (horizontal_style_height, horizontal) = CS::sundial_hour_lines(
+        geog_long_deg,
+        geog_lat_deg,
+        zone_correction,
+        pa_types::SundialType::Horizontal,
+        0.0,
+    );
+    let (vertical_style_height, vertical) = CS::sundial_hour_lines(
+        geog_long_deg,
+        geog_lat_deg,
+        zone_correction,
+        pa_types::SundialType::Vertical,
+        30.0,
+    );
+
+    for line in horizontal.iter().chain(vertical.iter()) {
+        println!(
+			"Sundial hour lines: [Geographical Longitude/Latitude] {}/{} degrees [Zone Correction] {} [Hour] {} = [Hour Angle] {} degrees [Line Angle] {} degrees",
+			geog_long_deg, geog_lat_deg, zone_correction, line.hour, line.hour_angle_deg, line.line_angle_deg
+		);
+    }
+
+    assert_eq!(horizontal_style_height, 51.48, "Horizontal - Style height");
+    assert_eq!(horizontal.len(), 13, "Horizontal - Number of lines");
+    assert_eq!(horizontal[7].hour, 13, "Horizontal - 13h - Hour");
+    assert_eq!(
+        horizontal[7].line_angle_deg, 11.84,
+        "Horizontal - 13h - Line angle"
+    );
+    assert_eq!(
+        horizontal[9].line_angle_deg, 38.04,
+        "Horizontal - 15h - Line angle"
+    );
+
+    assert_eq!(vertical_style_height, 32.64, "Vertical - Style height");
+    assert_eq!(vertical.len(), 11, "Vertical - Number of lines");
+    assert_eq!(vertical[0].hour, 8, "Vertical - First hour");
+    assert_eq!(
+        vertical[0].line_angle_deg, -80.09,
+        "Vertical - 8h - Line angle"
+    );
+    assert_eq!(
+        vertical[10].line_angle_deg, 57.87,
+        "Vertical - 18h - Line angle"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_horizontal_sundial_hour_line(
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    zone_correction: i32,
+    hour: u32,
+    expected_line_angle_deg: f64,
+) {
+    let (_, hour_lines) = CS::sundial_hour_lines(
+        geog_long_deg,
+        geog_lat_deg,
+        zone_correction,
+        pa_types::SundialType::Horizontal,
+        0.0,
+    );
+    let line = hour_lines.iter().find(|line| line.hour == hour);
+
+    println!(
+		"Horizontal sundial hour line: [Geographical Longitude/Latitude] {}/{} degrees [Zone Correction] {} [Hour] {} = {:?}",
+		geog_long_deg, geog_lat_deg, zone_correction, hour, line
+	);
+
+    assert_eq!(hour_lines.len(), 13, "Number of lines");
+    assert_eq!(
+        line.map(|line| line.line_angle_deg),
+        Some(expected_line_angle_deg),
+        "Line angle"
+    );
+}
@@ -0,0 +1,151 @@
+use practical_astronomy_rust::transits as TRN;
+use practical_astronomy_rust::util as UTIL;
+
+#[allow(dead_code)]
+pub fn test_transit_search(planet_name: String, start_year: u32, end_year: u32) {
+    let transits = TRN::transit_search(planet_name.to_string(), start_year, end_year);
+
+    for transit in transits.iter() {
+        println!(
+			"Transit search: [Planet] {} [Years] {}-{} = [Greatest Transit] {}/{}/{} {}:{} UT [Minimum Separation] {} arcsec",
+			planet_name,
+			start_year,
+			end_year,
+			transit.greatest_transit.date_month,
+			transit.greatest_transit.date_day,
+			transit.greatest_transit.date_year,
+			transit.greatest_transit.ut_hour,
+			transit.greatest_transit.ut_minutes,
+			transit.minimum_separation_arcsec
+		);
+    }
+
+    let dates: Vec<(u32, u32)> = transits
+        .iter()
+        .map(|transit| {
+            (
+                transit.greatest_transit.date_year,
+                transit.greatest_transit.date_month,
+            )
+        })
+        .collect();
+    assert_eq!(
+        dates,
+        vec![(2003, 5), (2006, 11), (2016, 5), (2019, 11)],
+        "Transit Dates"
+    );
+
+    let transit = &transits[3];
+    let second_contact = transit.second_contact.unwrap();
+    let third_contact = transit.third_contact.unwrap();
+
+    assert_eq!(transit.first_contact.ut_hour, 12, "First Contact Hour");
+    assert_eq!(
+        transit.first_contact.ut_minutes, 35,
+        "First Contact Minutes"
+    );
+    assert_eq!(second_contact.ut_minutes, 37, "Second Contact Minutes");
+    assert_eq!(
+        transit.greatest_transit.ut_hour, 15,
+        "Greatest Transit Hour"
+    );
+    assert_eq!(
+        transit.greatest_transit.ut_minutes, 19,
+        "Greatest Transit Minutes"
+    );
+    assert_eq!(third_contact.ut_hour, 18, "Third Contact Hour");
+    assert_eq!(third_contact.ut_minutes, 2, "Third Contact Minutes");
+    assert_eq!(
+        transit.fourth_contact.ut_minutes, 4,
+        "Fourth Contact Minutes"
+    );
+    assert_eq!(
+        UTIL::round_f64(transit.minimum_separation_arcsec, 0),
+        77.0,
+        "Minimum Separation"
+    );
+    assert_eq!(
+        UTIL::round_f64(transit.first_contact.position_angle_deg, 0),
+        110.0,
+        "First Contact Position Angle"
+    );
+    assert_eq!(
+        UTIL::round_f64(transit.fourth_contact.position_angle_deg, 0),
+        299.0,
+        "Fourth Contact Position Angle"
+    );
+}
+
+#[allow(dead_code)]
+pub fn test_local_transit_circumstances(
+    planet_name: String,
+    year: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) {
+    let transits = TRN::transit_search(planet_name.to_string(), year, year);
+    let transit = TRN::local_transit_circumstances(
+        &transits[0],
+        geog_longitude_deg,
+        geog_latitude_deg,
+        height_m,
+    )
+    .unwrap();
+    let second_contact = transit.second_contact.unwrap();
+    let third_contact = transit.third_contact.unwrap();
+
+    println!(
+		"Local transit circumstances: [Planet] {} [Year] {} [Observer] {} {} {} m = [I] {}:{}:{} [II] {}:{}:{} [Greatest] {}:{}:{} [III] {}:{}:{} [IV] {}:{}:{} [Minimum Separation] {} arcsec [Sun Altitude at I] {}",
+		planet_name,
+		year,
+		geog_longitude_deg,
+		geog_latitude_deg,
+		height_m,
+		transit.first_contact.ut_hour,
+		transit.first_contact.ut_minutes,
+		transit.first_contact.ut_seconds,
+		second_contact.ut_hour,
+		second_contact.ut_minutes,
+		second_contact.ut_seconds,
+		transit.greatest_transit.ut_hour,
+		transit.greatest_transit.ut_minutes,
+		transit.greatest_transit.ut_seconds,
+		third_contact.ut_hour,
+		third_contact.ut_minutes,
+		third_contact.ut_seconds,
+		transit.fourth_contact.ut_hour,
+		transit.fourth_contact.ut_minutes,
+		transit.fourth_contact.ut_seconds,
+		transit.minimum_separation_arcsec,
+		transit.first_contact.sun_altitude_deg
+	);
+
+    assert_eq!(transit.first_contact.ut_hour, 5, "First Contact Hour");
+    assert_eq!(
+        transit.first_contact.ut_minutes, 19,
+        "First Contact Minutes"
+    );
+    assert_eq!(second_contact.ut_minutes, 39, "Second Contact Minutes");
+    assert_eq!(transit.greatest_transit.ut_hour, 8, "Greatest Transit Hour");
+    assert_eq!(
+        transit.greatest_transit.ut_minutes, 22,
+        "Greatest Transit Minutes"
+    );
+    assert_eq!(third_contact.ut_hour, 11, "Third Contact Hour");
+    assert_eq!(third_contact.ut_minutes, 4, "Third Contact Minutes");
+    assert_eq!(
+        transit.fourth_contact.ut_minutes, 23,
+        "Fourth Contact Minutes"
+    );
+    assert_eq!(
+        UTIL::round_f64(transit.minimum_separation_arcsec, 0),
+        640.0,
+        "Minimum Separation"
+    );
+    assert_eq!(
+        UTIL::round_f64(transit.first_contact.sun_altitude_deg, 1),
+        11.8,
+        "Sun Altitude at First Contact"
+    );
+}
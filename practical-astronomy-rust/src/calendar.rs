@@ -0,0 +1,646 @@
+use crate::datetime as pa_dt;
+use crate::macros as pa_m;
+use crate::moon as pa_mo;
+use crate::sun::spa as pa_spa;
+
+// Calendar conversions via the Julian Day Number (JDN), the integer day count starting at noon.
+// Years use astronomical numbering (1 BC = year 0). The Hebrew, Islamic, Persian, Chinese and
+// French Republican algorithms follow Reingold & Dershowitz, Calendrical Calculations.
+
+const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+const HEBREW_EPOCH_JDN: i64 = 347998;
+const ISLAMIC_EPOCH_JDN: i64 = 1948440;
+const PERSIAN_EPOCH_JDN: i64 = 1948321;
+const CHINESE_EPOCH_JDN: i64 = 758326;
+const MAYAN_EPOCH_JDN: i64 = 584283;
+const FRENCH_EPOCH_JDN: i64 = 2375840;
+
+/// Meridian of the Iranian calendar (52.5 degrees east, Iran Standard Time).
+const TEHRAN_LONGITUDE_DEG: f64 = 52.5;
+/// Meridian of the Paris Observatory.
+const PARIS_LONGITUDE_DEG: f64 = 2.3372292;
+
+/// Info about a date in the Chinese lunisolar calendar:
+/// * `cycle` -- Sexagenary cycle, counted from 2637 BC.
+/// * `year` -- Year within the cycle (1-60).
+/// * `month` -- Month (1-12).
+/// * `is_leap_month` -- Is the month a leap (intercalary) month?
+/// * `day` -- Day of the month (1-30).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChineseDate {
+    pub cycle: i64,
+    pub year: u32,
+    pub month: u32,
+    pub is_leap_month: bool,
+    pub day: u32,
+}
+
+/// Convert a Gregorian calendar date to a Julian Day Number.
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month (1-12).
+/// * `year` -- Year (astronomical numbering).
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn gregorian_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+
+    return day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100)
+        + y.div_euclid(400)
+        - 32045;
+}
+
+/// Convert a Julian Day Number to a Gregorian calendar date.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month, year (astronomical numbering)
+pub fn julian_day_number_to_gregorian(julian_day_number: i64) -> (u32, u32, i64) {
+    let f = julian_day_number
+        + 1401
+        + ((4 * julian_day_number + 274277).div_euclid(146097) * 3).div_euclid(4)
+        - 38;
+
+    return day_month_year_from_f(f);
+}
+
+/// Convert a Julian calendar date to a Julian Day Number.
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month (1-12).
+/// * `year` -- Year (astronomical numbering).
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn julian_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+
+    return day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+}
+
+/// Convert a Julian Day Number to a Julian calendar date.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month, year (astronomical numbering)
+pub fn julian_day_number_to_julian(julian_day_number: i64) -> (u32, u32, i64) {
+    return day_month_year_from_f(julian_day_number + 1401);
+}
+
+/// Convert a Hebrew calendar date to a Julian Day Number.
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month, counted from Nisan (1 = Nisan, 7 = Tishri, 12 = Adar or Adar I, 13 = Adar II).
+/// * `year` -- Year (AM).
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn hebrew_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let mut days = hebrew_new_year(year) + day as i64 - 1;
+
+    if month < 7 {
+        for m in 7..=hebrew_last_month_of_year(year) {
+            days += hebrew_last_day_of_month(m, year) as i64;
+        }
+        for m in 1..month {
+            days += hebrew_last_day_of_month(m, year) as i64;
+        }
+    } else {
+        for m in 7..month {
+            days += hebrew_last_day_of_month(m, year) as i64;
+        }
+    }
+
+    return days;
+}
+
+/// Convert a Julian Day Number to a Hebrew calendar date.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month (1 = Nisan, 7 = Tishri), year (AM)
+pub fn julian_day_number_to_hebrew(julian_day_number: i64) -> (u32, u32, i64) {
+    let approx =
+        ((julian_day_number - HEBREW_EPOCH_JDN) as f64 / (35975351.0 / 98496.0)).floor() as i64 + 1;
+    let mut year = approx - 1;
+    while hebrew_new_year(year + 1) <= julian_day_number {
+        year += 1;
+    }
+
+    let start = if julian_day_number < hebrew_to_julian_day_number(1, 1, year) {
+        7
+    } else {
+        1
+    };
+    let mut month = start;
+    while julian_day_number
+        > hebrew_to_julian_day_number(hebrew_last_day_of_month(month, year), month, year)
+    {
+        month += 1;
+    }
+    let day = julian_day_number - hebrew_to_julian_day_number(1, month, year) + 1;
+
+    return (day as u32, month, year);
+}
+
+/// Convert a date in the tabular (arithmetical) Islamic calendar to a Julian Day Number.
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month (1 = Muharram).
+/// * `year` -- Year (AH).
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn islamic_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let month = month as i64;
+
+    return day as i64
+        + 29 * (month - 1)
+        + (6 * month - 1).div_euclid(11)
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + ISLAMIC_EPOCH_JDN
+        - 1;
+}
+
+/// Convert a Julian Day Number to a date in the tabular (arithmetical) Islamic calendar.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month (1 = Muharram), year (AH)
+pub fn julian_day_number_to_islamic(julian_day_number: i64) -> (u32, u32, i64) {
+    let year = (30 * (julian_day_number - ISLAMIC_EPOCH_JDN) + 10646).div_euclid(10631);
+    let prior_days = julian_day_number - islamic_to_julian_day_number(1, 1, year);
+    let month = (11 * prior_days + 330).div_euclid(325);
+    let day = julian_day_number - islamic_to_julian_day_number(1, month as u32, year) + 1;
+
+    return (day as u32, month as u32, year);
+}
+
+/// Convert a date in the astronomical Persian (Solar Hijri) calendar to a Julian Day Number.
+///
+/// The year begins on the day of the March equinox if it falls before apparent noon on the
+/// 52.5 degrees east meridian, and on the next day otherwise.
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month (1 = Farvardin).
+/// * `year` -- Year (AP).
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn persian_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let elapsed_years = if year > 0 { year - 1 } else { year };
+    let new_year = persian_new_year_on_or_before(
+        PERSIAN_EPOCH_JDN + 180 + (MEAN_TROPICAL_YEAR * elapsed_years as f64).floor() as i64,
+    );
+    let month = month as i64;
+    let days_before_month = if month <= 7 {
+        31 * (month - 1)
+    } else {
+        30 * (month - 1) + 6
+    };
+
+    return new_year - 1 + days_before_month + day as i64;
+}
+
+/// Convert a Julian Day Number to a date in the astronomical Persian (Solar Hijri) calendar.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month (1 = Farvardin), year (AP)
+pub fn julian_day_number_to_persian(julian_day_number: i64) -> (u32, u32, i64) {
+    let new_year = persian_new_year_on_or_before(julian_day_number);
+    let y = ((new_year - PERSIAN_EPOCH_JDN) as f64 / MEAN_TROPICAL_YEAR).round() as i64 + 1;
+    let year = if y > 0 { y } else { y - 1 };
+    let day_of_year = julian_day_number - persian_to_julian_day_number(1, 1, year) + 1;
+    let month = if day_of_year <= 186 {
+        (day_of_year as f64 / 31.0).ceil() as u32
+    } else {
+        ((day_of_year - 6) as f64 / 30.0).ceil() as u32
+    };
+    let day = julian_day_number - persian_to_julian_day_number(1, month, year) + 1;
+
+    return (day as u32, month, year);
+}
+
+/// Convert a date in the Chinese lunisolar calendar to a Julian Day Number.
+///
+/// Months begin on the day of the new Moon at the meridian of 120 degrees east (Beijing before
+/// 1929), and a month without a major solar term is a leap month.
+///
+/// ## Arguments
+/// * `chinese_date` -- Chinese date.
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn chinese_to_julian_day_number(chinese_date: &ChineseDate) -> i64 {
+    let mid_year = (CHINESE_EPOCH_JDN as f64
+        + ((chinese_date.cycle - 1) as f64 * 60.0 + (chinese_date.year - 1) as f64 + 0.5)
+            * MEAN_TROPICAL_YEAR)
+        .floor() as i64;
+    let new_year = chinese_new_year_on_or_before(mid_year);
+    let p = chinese_new_moon_on_or_after(new_year + (chinese_date.month as i64 - 1) * 29);
+    let d = julian_day_number_to_chinese(p);
+    let prior_new_moon =
+        if chinese_date.month == d.month && chinese_date.is_leap_month == d.is_leap_month {
+            p
+        } else {
+            chinese_new_moon_on_or_after(p + 1)
+        };
+
+    return prior_new_moon + chinese_date.day as i64 - 1;
+}
+
+/// Convert a Julian Day Number to a date in the Chinese lunisolar calendar.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * `ChineseDate`
+pub fn julian_day_number_to_chinese(julian_day_number: i64) -> ChineseDate {
+    let s1 = chinese_winter_solstice_on_or_before(julian_day_number);
+    let s2 = chinese_winter_solstice_on_or_before(s1 + 370);
+    let m12 = chinese_new_moon_on_or_after(s1 + 1);
+    let next_m11 = chinese_new_moon_before(s2 + 1);
+    let m = chinese_new_moon_before(julian_day_number + 1);
+    let is_leap_year = ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 == 12;
+
+    let month_offset = if is_leap_year && chinese_prior_leap_month(m12, m) {
+        1
+    } else {
+        0
+    };
+    let month = ((m - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 - month_offset;
+    let month = (month - 1).rem_euclid(12) + 1;
+    let is_leap_month = is_leap_year
+        && chinese_no_major_solar_term(m)
+        && !chinese_prior_leap_month(m12, chinese_new_moon_before(m));
+    let elapsed_years = (1.5 - month as f64 / 12.0
+        + (julian_day_number - CHINESE_EPOCH_JDN) as f64 / MEAN_TROPICAL_YEAR)
+        .floor() as i64;
+
+    return ChineseDate {
+        cycle: (elapsed_years - 1).div_euclid(60) + 1,
+        year: ((elapsed_years - 1).rem_euclid(60) + 1) as u32,
+        month: month as u32,
+        is_leap_month,
+        day: (julian_day_number - m + 1) as u32,
+    };
+}
+
+/// Convert a Mayan long count to a Julian Day Number (GMT correlation, 584283).
+///
+/// ## Arguments
+/// * `baktun`, `katun`, `tun`, `uinal`, `kin` -- Long count.
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn mayan_long_count_to_julian_day_number(
+    baktun: i64,
+    katun: u32,
+    tun: u32,
+    uinal: u32,
+    kin: u32,
+) -> i64 {
+    return MAYAN_EPOCH_JDN
+        + baktun * 144000
+        + katun as i64 * 7200
+        + tun as i64 * 360
+        + uinal as i64 * 20
+        + kin as i64;
+}
+
+/// Convert a Julian Day Number to a Mayan long count (GMT correlation, 584283).
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * baktun, katun, tun, uinal, kin
+pub fn julian_day_number_to_mayan_long_count(julian_day_number: i64) -> (i64, u32, u32, u32, u32) {
+    let days = julian_day_number - MAYAN_EPOCH_JDN;
+    let baktun = days.div_euclid(144000);
+    let day_of_baktun = days.rem_euclid(144000);
+
+    return (
+        baktun,
+        (day_of_baktun / 7200) as u32,
+        (day_of_baktun % 7200 / 360) as u32,
+        (day_of_baktun % 360 / 20) as u32,
+        (day_of_baktun % 20) as u32,
+    );
+}
+
+/// Convert a French Republican calendar date to a Julian Day Number.
+///
+/// The year begins on the day of the September equinox, in apparent time at the Paris
+/// Observatory. Month 13 holds the complementary days (sansculottides).
+///
+/// ## Arguments
+/// * `day` -- Day of the month.
+/// * `month` -- Month (1 = Vendémiaire, 13 = complementary days).
+/// * `year` -- Year of the Republic.
+///
+/// ## Returns
+/// * Julian Day Number.
+pub fn french_republican_to_julian_day_number(day: u32, month: u32, year: i64) -> i64 {
+    let new_year = french_new_year_on_or_before(
+        FRENCH_EPOCH_JDN + 180 + (MEAN_TROPICAL_YEAR * (year - 1) as f64).floor() as i64,
+    );
+
+    return new_year - 1 + 30 * (month as i64 - 1) + day as i64;
+}
+
+/// Convert a Julian Day Number to a French Republican calendar date.
+///
+/// ## Arguments
+/// * `julian_day_number` -- Julian Day Number.
+///
+/// ## Returns
+/// * day, month (1 = Vendémiaire, 13 = complementary days), year of the Republic
+pub fn julian_day_number_to_french_republican(julian_day_number: i64) -> (u32, u32, i64) {
+    let new_year = french_new_year_on_or_before(julian_day_number);
+    let year = ((new_year - FRENCH_EPOCH_JDN) as f64 / MEAN_TROPICAL_YEAR).round() as i64 + 1;
+    let day_of_year = julian_day_number - new_year;
+
+    return (
+        (day_of_year % 30 + 1) as u32,
+        (day_of_year / 30 + 1) as u32,
+        year,
+    );
+}
+
+/// Day, month and year from the intermediate value of Richards' algorithm.
+fn day_month_year_from_f(f: i64) -> (u32, u32, i64) {
+    let e = 4 * f + 3;
+    let g = e.rem_euclid(1461) / 4;
+    let h = 5 * g + 2;
+    let day = h.rem_euclid(153) / 5 + 1;
+    let month = (h / 153 + 2).rem_euclid(12) + 1;
+    let year = e.div_euclid(1461) - 4716 + (14 - month) / 12;
+
+    return (day as u32, month as u32, year);
+}
+
+fn hebrew_is_leap_year(year: i64) -> bool {
+    return (7 * year + 1).rem_euclid(19) < 7;
+}
+
+fn hebrew_last_month_of_year(year: i64) -> u32 {
+    return if hebrew_is_leap_year(year) { 13 } else { 12 };
+}
+
+/// Days from the Hebrew epoch to the molad of Tishri, with the molad postponements.
+fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12084 + 13753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+
+    return if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    };
+}
+
+/// JDN of 1 Tishri of a Hebrew year.
+fn hebrew_new_year(year: i64) -> i64 {
+    let ny0 = hebrew_calendar_elapsed_days(year - 1);
+    let ny1 = hebrew_calendar_elapsed_days(year);
+    let ny2 = hebrew_calendar_elapsed_days(year + 1);
+    let correction = if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    };
+
+    return HEBREW_EPOCH_JDN + ny1 + correction;
+}
+
+fn hebrew_last_day_of_month(month: u32, year: i64) -> u32 {
+    let days_in_year = hebrew_new_year(year + 1) - hebrew_new_year(year);
+    let is_long_marheshvan = days_in_year % 10 == 5;
+    let is_short_kislev = days_in_year % 10 == 3;
+
+    return if [2, 4, 6, 10, 13].contains(&month)
+        || (month == 12 && !hebrew_is_leap_year(year))
+        || (month == 8 && !is_long_marheshvan)
+        || (month == 9 && is_short_kislev)
+    {
+        29
+    } else {
+        30
+    };
+}
+
+/// Apparent solar longitude (degrees) at a Julian date (UT).
+fn solar_longitude(jd_ut: f64) -> f64 {
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+
+    return pa_spa::apparent_longitude(jd_tt);
+}
+
+/// The last moment (Julian date, UT) at or before a Julian date when the apparent solar
+/// longitude was a given value.
+fn solar_longitude_on_or_before(longitude_deg: f64, jd_ut: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let mut t = jd_ut - (solar_longitude(jd_ut) - longitude_deg).rem_euclid(360.0) * rate;
+
+    for _ in 0..5 {
+        t += ((longitude_deg - solar_longitude(t) + 180.0).rem_euclid(360.0) - 180.0) * rate;
+    }
+    if t > jd_ut {
+        t = solar_longitude_on_or_before(longitude_deg, t - 30.0);
+    }
+
+    return t;
+}
+
+/// Offset (days) of apparent solar time behind mean solar time at a Julian date (UT).
+fn apparent_time_offset(jd_ut: f64) -> f64 {
+    let day = pa_m::jdc_day(jd_ut);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_ut);
+    let gy = pa_m::jdc_year(jd_ut);
+    let sun_long_deg = pa_m::sun_long((day - gd) * 24.0, 0.0, 0.0, 0, 0, gd, gm, gy);
+    let sun_ra_hours = pa_m::dd_dh(pa_m::ec_ra(
+        sun_long_deg,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        gd,
+        gm,
+        gy,
+    ));
+    let transit_ut_hours = pa_m::gst_ut(sun_ra_hours, 0.0, 0.0, gd, gm, gy);
+
+    return (transit_ut_hours.rem_euclid(24.0) - 12.0) / 24.0;
+}
+
+/// The first day at or before a JDN whose given apparent local hour, at a meridian, follows the
+/// moment when the apparent solar longitude reached a value.
+fn astronomical_new_year_on_or_before(
+    julian_day_number: i64,
+    solar_longitude_deg: f64,
+    meridian_deg: f64,
+    local_hour: f64,
+) -> i64 {
+    let moment = |jdn: i64| {
+        let mean = jdn as f64 - 0.5 + local_hour / 24.0 - meridian_deg / 360.0;
+        mean + apparent_time_offset(mean)
+    };
+    let equinox = solar_longitude_on_or_before(solar_longitude_deg, moment(julian_day_number));
+
+    let mut new_year = (equinox + 0.5 - local_hour / 24.0 + meridian_deg / 360.0).ceil() as i64;
+    while moment(new_year - 1) >= equinox {
+        new_year -= 1;
+    }
+    while moment(new_year) < equinox {
+        new_year += 1;
+    }
+
+    return new_year;
+}
+
+fn persian_new_year_on_or_before(julian_day_number: i64) -> i64 {
+    return astronomical_new_year_on_or_before(julian_day_number, 0.0, TEHRAN_LONGITUDE_DEG, 12.0);
+}
+
+fn french_new_year_on_or_before(julian_day_number: i64) -> i64 {
+    return astronomical_new_year_on_or_before(julian_day_number, 180.0, PARIS_LONGITUDE_DEG, 24.0);
+}
+
+/// Time zone (hours) of the Chinese calendar for a JDN: Beijing mean time before 1929, and
+/// the 120 degrees east meridian after.
+fn chinese_zone_hours(julian_day_number: i64) -> f64 {
+    return if julian_day_number_to_gregorian(julian_day_number).2 < 1929 {
+        1397.0 / 180.0
+    } else {
+        8.0
+    };
+}
+
+/// Julian date (UT) of the midnight that begins a JDN in China.
+fn midnight_in_china(julian_day_number: i64) -> f64 {
+    return julian_day_number as f64 - 0.5 - chinese_zone_hours(julian_day_number) / 24.0;
+}
+
+/// JDN of the day in China containing a Julian date (UT).
+fn chinese_day_of(jd_ut: f64, julian_day_number: i64) -> i64 {
+    return (jd_ut + 0.5 + chinese_zone_hours(julian_day_number) / 24.0).floor() as i64;
+}
+
+/// Julian date (UT) of the first new Moon at or after a Julian date (UT).
+fn new_moon_at_or_after(jd_ut: f64) -> f64 {
+    let elongation = |t: f64| {
+        let (moon_long_deg, _, _, sun_long_deg) = pa_mo::moon_and_sun_at(t);
+        (moon_long_deg - sun_long_deg).rem_euclid(360.0)
+    };
+    let rate = MEAN_SYNODIC_MONTH / 360.0;
+    let mut t = jd_ut + (360.0 - elongation(jd_ut)) * rate;
+
+    for _ in 0..5 {
+        t -= ((elongation(t) + 180.0).rem_euclid(360.0) - 180.0) * rate;
+    }
+    if t < jd_ut {
+        t = new_moon_at_or_after(t + 15.0);
+    }
+
+    return t;
+}
+
+fn chinese_new_moon_on_or_after(julian_day_number: i64) -> i64 {
+    return chinese_day_of(
+        new_moon_at_or_after(midnight_in_china(julian_day_number)),
+        julian_day_number,
+    );
+}
+
+fn chinese_new_moon_before(julian_day_number: i64) -> i64 {
+    let new_moon = new_moon_at_or_after(midnight_in_china(julian_day_number) - 35.0);
+    let mut previous = new_moon;
+    let mut next = new_moon;
+    while next < midnight_in_china(julian_day_number) {
+        previous = next;
+        next = new_moon_at_or_after(next + 1.0);
+    }
+
+    return chinese_day_of(previous, julian_day_number);
+}
+
+fn chinese_winter_solstice_on_or_before(julian_day_number: i64) -> i64 {
+    let solstice = solar_longitude_on_or_before(270.0, midnight_in_china(julian_day_number + 1));
+
+    return chinese_day_of(solstice, julian_day_number);
+}
+
+/// Index (1-12) of the last major solar term at the start of a JDN in China.
+fn chinese_current_major_solar_term(julian_day_number: i64) -> i64 {
+    let s = solar_longitude(midnight_in_china(julian_day_number));
+
+    return (2 + (s / 30.0).floor() as i64 - 1).rem_euclid(12) + 1;
+}
+
+fn chinese_no_major_solar_term(julian_day_number: i64) -> bool {
+    return chinese_current_major_solar_term(julian_day_number)
+        == chinese_current_major_solar_term(chinese_new_moon_on_or_after(julian_day_number + 1));
+}
+
+fn chinese_prior_leap_month(m_prime: i64, m: i64) -> bool {
+    return m >= m_prime
+        && (chinese_no_major_solar_term(m)
+            || chinese_prior_leap_month(m_prime, chinese_new_moon_before(m)));
+}
+
+fn chinese_new_year_in_sui(julian_day_number: i64) -> i64 {
+    let s1 = chinese_winter_solstice_on_or_before(julian_day_number);
+    let s2 = chinese_winter_solstice_on_or_before(s1 + 370);
+    let m12 = chinese_new_moon_on_or_after(s1 + 1);
+    let m13 = chinese_new_moon_on_or_after(m12 + 1);
+    let next_m11 = chinese_new_moon_before(s2 + 1);
+
+    return if ((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH).round() as i64 == 12
+        && (chinese_no_major_solar_term(m12) || chinese_no_major_solar_term(m13))
+    {
+        chinese_new_moon_on_or_after(m13 + 1)
+    } else {
+        m13
+    };
+}
+
+fn chinese_new_year_on_or_before(julian_day_number: i64) -> i64 {
+    let new_year = chinese_new_year_in_sui(julian_day_number);
+
+    return if julian_day_number >= new_year {
+        new_year
+    } else {
+        chinese_new_year_in_sui(julian_day_number - 180)
+    };
+}
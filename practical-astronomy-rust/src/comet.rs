@@ -32,6 +32,46 @@ pub fn position_of_elliptical_comet(
     local_date_year: u32,
     comet_name: String,
 ) -> (f64, f64, f64, f64, f64) {
+    let (comet_ra_hours1, comet_dec_deg1, comet_distance_au) = elliptical_comet_position(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        comet_name,
+    );
+
+    let comet_ra_hour = pa_m::dh_hour(comet_ra_hours1 + 0.008333);
+    let comet_ra_min = pa_m::dh_min(comet_ra_hours1 + 0.008333);
+    let comet_dec_deg = pa_m::dd_deg(comet_dec_deg1 + 0.008333);
+    let comet_dec_min = pa_m::dd_min(comet_dec_deg1 + 0.008333);
+    let comet_dist_earth = pa_u::round_f64(comet_distance_au, 2);
+
+    return (
+        comet_ra_hour as f64,
+        comet_ra_min as f64,
+        comet_dec_deg,
+        comet_dec_min,
+        comet_dist_earth,
+    );
+}
+
+/// Right ascension (hours), declination (degrees) and distance from the Earth (AU) of an
+/// elliptical comet, without rounding.
+pub(crate) fn elliptical_comet_position(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    comet_name: String,
+) -> (f64, f64, f64) {
     let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
 
     let greenwich_date_day = pa_m::lct_gday(
@@ -161,19 +201,7 @@ pub fn position_of_elliptical_comet(
             * (psi_rad).cos())
     .sqrt();
 
-    let comet_ra_hour = pa_m::dh_hour(comet_ra_hours1 + 0.008333);
-    let comet_ra_min = pa_m::dh_min(comet_ra_hours1 + 0.008333);
-    let comet_dec_deg = pa_m::dd_deg(comet_dec_deg1 + 0.008333);
-    let comet_dec_min = pa_m::dd_min(comet_dec_deg1 + 0.008333);
-    let comet_dist_earth = pa_u::round_f64(comet_distance_au, 2);
-
-    return (
-        comet_ra_hour as f64,
-        comet_ra_min as f64,
-        comet_dec_deg,
-        comet_dec_min,
-        comet_dist_earth,
-    );
+    return (comet_ra_hours1, comet_dec_deg1, comet_distance_au);
 }
 
 /// Calculate position of a parabolic comet.
@@ -208,6 +236,50 @@ pub fn position_of_parabolic_comet(
     local_date_year: u32,
     comet_name: String,
 ) -> (f64, f64, f64, f64, f64, f64, f64) {
+    let (comet_ra_hours, comet_dec_deg1, comet_dist_au) = parabolic_comet_position(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        comet_name,
+    );
+
+    let comet_ra_hour = pa_m::dh_hour(comet_ra_hours);
+    let comet_ra_min = pa_m::dh_min(comet_ra_hours);
+    let comet_ra_sec = pa_m::dh_sec(comet_ra_hours);
+    let comet_dec_deg = pa_m::dd_deg(comet_dec_deg1);
+    let comet_dec_min = pa_m::dd_min(comet_dec_deg1);
+    let comet_dec_sec = pa_m::dd_sec(comet_dec_deg1);
+    let comet_dist_earth = pa_u::round_f64(comet_dist_au, 2);
+
+    return (
+        comet_ra_hour as f64,
+        comet_ra_min as f64,
+        comet_ra_sec,
+        comet_dec_deg,
+        comet_dec_min,
+        comet_dec_sec,
+        comet_dist_earth,
+    );
+}
+
+/// Right ascension (hours), declination (degrees) and distance from the Earth (AU) of a
+/// parabolic comet, without rounding.
+pub(crate) fn parabolic_comet_position(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    comet_name: String,
+) -> (f64, f64, f64) {
     let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
 
     let greenwich_date_day = pa_m::lct_gday(
@@ -303,21 +375,5 @@ pub fn position_of_parabolic_comet(
         greenwich_date_year,
     );
 
-    let comet_ra_hour = pa_m::dh_hour(comet_ra_hours);
-    let comet_ra_min = pa_m::dh_min(comet_ra_hours);
-    let comet_ra_sec = pa_m::dh_sec(comet_ra_hours);
-    let comet_dec_deg = pa_m::dd_deg(comet_dec_deg1);
-    let comet_dec_min = pa_m::dd_min(comet_dec_deg1);
-    let comet_dec_sec = pa_m::dd_sec(comet_dec_deg1);
-    let comet_dist_earth = pa_u::round_f64(comet_dist_au, 2);
-
-    return (
-        comet_ra_hour as f64,
-        comet_ra_min as f64,
-        comet_ra_sec,
-        comet_dec_deg,
-        comet_dec_min,
-        comet_dec_sec,
-        comet_dist_earth,
-    );
+    return (comet_ra_hours, comet_dec_deg1, comet_dist_au);
 }
@@ -1,4 +1,8 @@
+use crate::datetime as pa_dt;
+use crate::frames as pa_f;
 use crate::macros as pa_m;
+use crate::nutationdata as pa_nd;
+use crate::types as pa_t;
 use crate::util as pa_u;
 
 /// Convert an Angle (degrees, minutes, and seconds) to Decimal Degrees.
@@ -429,6 +433,251 @@ pub fn galactic_coordinate_to_equatorial_coordinate(
     );
 }
 
+/// Convert Equatorial Coordinates (J2000) to Supergalactic Coordinates.
+pub fn equatorial_coordinate_to_supergalactic_coordinate(
+    ra_hours: f64,
+    ra_minutes: f64,
+    ra_seconds: f64,
+    dec_degrees: f64,
+    dec_minutes: f64,
+    dec_seconds: f64,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let ra_deg = pa_m::dh_dd(pa_m::hms_dh(ra_hours, ra_minutes, ra_seconds));
+    let dec_deg = pa_m::dms_dd(dec_degrees, dec_minutes, dec_seconds);
+
+    let (sg_long_deg, sg_lat_deg) = pa_f::transform_coordinates(
+        pa_t::ReferenceFrame::Icrs,
+        pa_t::ReferenceFrame::Supergalactic,
+        ra_deg,
+        dec_deg,
+        2451545.0,
+        &pa_f::Observer {
+            geographical_longitude_deg: 0.0,
+            geographical_latitude_deg: 0.0,
+        },
+    );
+
+    return (
+        pa_m::dd_deg(sg_long_deg),
+        pa_m::dd_min(sg_long_deg),
+        pa_m::dd_sec(sg_long_deg),
+        pa_m::dd_deg(sg_lat_deg),
+        pa_m::dd_min(sg_lat_deg),
+        pa_m::dd_sec(sg_lat_deg),
+    );
+}
+
+/// Convert Supergalactic Coordinates to Equatorial Coordinates (J2000).
+pub fn supergalactic_coordinate_to_equatorial_coordinate(
+    sg_long_deg: f64,
+    sg_long_min: f64,
+    sg_long_sec: f64,
+    sg_lat_deg: f64,
+    sg_lat_min: f64,
+    sg_lat_sec: f64,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let sglong_deg = pa_m::dms_dd(sg_long_deg, sg_long_min, sg_long_sec);
+    let sglat_deg = pa_m::dms_dd(sg_lat_deg, sg_lat_min, sg_lat_sec);
+
+    let (ra_deg, dec_deg) = pa_f::transform_coordinates(
+        pa_t::ReferenceFrame::Supergalactic,
+        pa_t::ReferenceFrame::Icrs,
+        sglong_deg,
+        sglat_deg,
+        2451545.0,
+        &pa_f::Observer {
+            geographical_longitude_deg: 0.0,
+            geographical_latitude_deg: 0.0,
+        },
+    );
+    let ra_hours1 = pa_m::dd_dh(ra_deg);
+
+    return (
+        pa_m::dh_hour(ra_hours1) as f64,
+        pa_m::dh_min(ra_hours1) as f64,
+        pa_m::dh_sec(ra_hours1),
+        pa_m::dd_deg(dec_deg),
+        pa_m::dd_min(dec_deg),
+        pa_m::dd_sec(dec_deg),
+    );
+}
+
+/// Convert ecliptic coordinates between the J2000 equinox and the equinox of date.
+///
+/// ## Arguments
+/// * `ecl_long_deg` -- Ecliptic longitude (degrees).
+/// * `ecl_lat_deg` -- Ecliptic latitude (degrees).
+/// * `greenwich_day`, `greenwich_month`, `greenwich_year` -- Date of the equinox of date.
+/// * `from_equinox` -- Equinox of the input coordinates.
+/// * `to_equinox` -- Equinox of the output coordinates.
+///
+/// ## Returns
+/// * ecliptic longitude (degrees)
+/// * ecliptic latitude (degrees)
+pub fn ecliptic_coordinate_change_of_equinox(
+    ecl_long_deg: f64,
+    ecl_lat_deg: f64,
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    from_equinox: pa_t::EclipticEquinox,
+    to_equinox: pa_t::EclipticEquinox,
+) -> (f64, f64) {
+    return pa_f::transform_coordinates(
+        ecliptic_frame(from_equinox),
+        ecliptic_frame(to_equinox),
+        ecl_long_deg,
+        ecl_lat_deg,
+        pa_m::cd_jd(greenwich_day, greenwich_month, greenwich_year),
+        &pa_f::Observer {
+            geographical_longitude_deg: 0.0,
+            geographical_latitude_deg: 0.0,
+        },
+    );
+}
+
+/// Convert geocentric ecliptic coordinates to heliocentric ecliptic coordinates.
+///
+/// ## Arguments
+/// * `geo_ecl_long_deg` -- Geocentric ecliptic longitude (degrees).
+/// * `geo_ecl_lat_deg` -- Geocentric ecliptic latitude (degrees).
+/// * `geo_distance_au` -- Distance from the Earth (AU).
+/// * `greenwich_day`, `greenwich_month`, `greenwich_year` -- Greenwich date (day may be fractional).
+/// * `ecliptic_equinox` -- Equinox the input and output coordinates are referred to.
+///
+/// ## Returns
+/// * heliocentric ecliptic longitude (degrees)
+/// * heliocentric ecliptic latitude (degrees)
+/// * distance from the Sun (AU)
+pub fn geocentric_ecliptic_to_heliocentric_ecliptic(
+    geo_ecl_long_deg: f64,
+    geo_ecl_lat_deg: f64,
+    geo_distance_au: f64,
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    ecliptic_equinox: pa_t::EclipticEquinox,
+) -> (f64, f64, f64) {
+    let sun = geocentric_sun_vector(
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        ecliptic_equinox,
+    );
+    let object = pa_f::spherical_to_vector(geo_ecl_long_deg, geo_ecl_lat_deg);
+    let helio = pa_f::Vector3 {
+        x: object.x * geo_distance_au - sun.x,
+        y: object.y * geo_distance_au - sun.y,
+        z: object.z * geo_distance_au - sun.z,
+    };
+    let (helio_long_deg, helio_lat_deg) = pa_f::vector_to_spherical(&helio);
+
+    return (
+        helio_long_deg,
+        helio_lat_deg,
+        (helio.x * helio.x + helio.y * helio.y + helio.z * helio.z).sqrt(),
+    );
+}
+
+/// Convert heliocentric ecliptic coordinates to geocentric ecliptic coordinates.
+///
+/// ## Arguments
+/// * `helio_ecl_long_deg` -- Heliocentric ecliptic longitude (degrees).
+/// * `helio_ecl_lat_deg` -- Heliocentric ecliptic latitude (degrees).
+/// * `helio_distance_au` -- Distance from the Sun (AU).
+/// * `greenwich_day`, `greenwich_month`, `greenwich_year` -- Greenwich date (day may be fractional).
+/// * `ecliptic_equinox` -- Equinox the input and output coordinates are referred to.
+///
+/// ## Returns
+/// * geocentric ecliptic longitude (degrees)
+/// * geocentric ecliptic latitude (degrees)
+/// * distance from the Earth (AU)
+pub fn heliocentric_ecliptic_to_geocentric_ecliptic(
+    helio_ecl_long_deg: f64,
+    helio_ecl_lat_deg: f64,
+    helio_distance_au: f64,
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    ecliptic_equinox: pa_t::EclipticEquinox,
+) -> (f64, f64, f64) {
+    let sun = geocentric_sun_vector(
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        ecliptic_equinox,
+    );
+    let object = pa_f::spherical_to_vector(helio_ecl_long_deg, helio_ecl_lat_deg);
+    let geo = pa_f::Vector3 {
+        x: object.x * helio_distance_au + sun.x,
+        y: object.y * helio_distance_au + sun.y,
+        z: object.z * helio_distance_au + sun.z,
+    };
+    let (geo_long_deg, geo_lat_deg) = pa_f::vector_to_spherical(&geo);
+
+    return (
+        geo_long_deg,
+        geo_lat_deg,
+        (geo.x * geo.x + geo.y * geo.y + geo.z * geo.z).sqrt(),
+    );
+}
+
+/// Reference frame for ecliptic coordinates with the selected equinox.
+fn ecliptic_frame(ecliptic_equinox: pa_t::EclipticEquinox) -> pa_t::ReferenceFrame {
+    return match ecliptic_equinox {
+        pa_t::EclipticEquinox::J2000 => pa_t::ReferenceFrame::EclipticJ2000,
+        pa_t::EclipticEquinox::OfDate => pa_t::ReferenceFrame::EclipticOfDate,
+    };
+}
+
+/// Geocentric ecliptic position vector of the Sun (AU), referred to the selected equinox.
+fn geocentric_sun_vector(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    ecliptic_equinox: pa_t::EclipticEquinox,
+) -> pa_f::Vector3 {
+    let ut_hours = (greenwich_day - greenwich_day.floor()) * 24.0;
+    let gd = greenwich_day.floor();
+    let sun_long_deg = pa_m::sun_long(
+        ut_hours,
+        0.0,
+        0.0,
+        0,
+        0,
+        gd,
+        greenwich_month,
+        greenwich_year,
+    );
+    let sun_dist_au = pa_m::sun_dist(
+        ut_hours,
+        0.0,
+        0.0,
+        0,
+        0,
+        gd,
+        greenwich_month,
+        greenwich_year,
+    );
+
+    let (sun_long_deg, sun_lat_deg) = ecliptic_coordinate_change_of_equinox(
+        sun_long_deg,
+        0.0,
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        pa_t::EclipticEquinox::OfDate,
+        ecliptic_equinox,
+    );
+    let sun = pa_f::spherical_to_vector(sun_long_deg, sun_lat_deg);
+
+    return pa_f::Vector3 {
+        x: sun.x * sun_dist_au,
+        y: sun.y * sun_dist_au,
+        z: sun.z * sun_dist_au,
+    };
+}
+
 /// Calculate the angle between two celestial objects.
 pub fn angle_between_two_objects(
     ra_long_1_hour_deg: f64,
@@ -691,6 +940,131 @@ pub fn nutation_in_ecliptic_longitude_and_obliquity(
     return (nut_in_long_deg, nut_in_obl_deg);
 }
 
+/// Calculate nutation in ecliptic longitude and obliquity for a Greenwich date, using a full nutation series.
+///
+/// ## Arguments
+/// * `greenwich_day` -- Greenwich date, day part (may include a fraction of a day).
+/// * `greenwich_month` -- Greenwich date, month part.
+/// * `greenwich_year` -- Greenwich date, year part.
+/// * `nutation_model` -- Nutation series to use: IAU 1980 (106 terms) or IAU 2000B (77 terms).
+///
+/// ## Returns
+/// * nutation in ecliptic longitude (degrees)
+/// * nutation in obliquity (degrees)
+pub fn nutation_in_ecliptic_longitude_and_obliquity_by_model(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    nutation_model: pa_t::NutationModel,
+) -> (f64, f64) {
+    let jd_days = pa_m::cd_jd(greenwich_day, greenwich_month, greenwich_year);
+    let t_centuries = (jd_days - 2451545.0) / 36525.0;
+    let t = t_centuries;
+
+    // Fundamental arguments (l, l', F, D, Omega), in arcseconds.
+    let fundamental_args_arcsec = match nutation_model {
+        pa_t::NutationModel::Iau1980 => [
+            485866.733 + (1717915922.633 + (31.310 + 0.064 * t) * t) * t,
+            1287099.804 + (129596581.224 + (-0.577 - 0.012 * t) * t) * t,
+            335778.877 + (1739527263.137 + (-13.257 + 0.011 * t) * t) * t,
+            1072261.307 + (1602961601.328 + (-6.891 + 0.019 * t) * t) * t,
+            450160.280 + (-6962890.539 + (7.455 + 0.008 * t) * t) * t,
+        ],
+        pa_t::NutationModel::Iau2000B => [
+            485868.249036 + 1717915923.2178 * t,
+            1287104.79305 + 129596581.0481 * t,
+            335779.526232 + 1739527262.8478 * t,
+            1072260.70369 + 1602961601.2090 * t,
+            450160.398036 - 6962890.5431 * t,
+        ],
+    };
+    let fundamental_args_rad =
+        fundamental_args_arcsec.map(|arg_arcsec| ((arg_arcsec % 1296000.0) / 3600.0).to_radians());
+
+    let mut nut_in_long_arcsec = 0.0;
+    let mut nut_in_obl_arcsec = 0.0;
+    for term in pa_nd::get_nutation_terms(&nutation_model).iter().rev() {
+        let arg_rad: f64 = term
+            .multipliers
+            .iter()
+            .zip(fundamental_args_rad.iter())
+            .map(|(multiplier, fundamental_arg_rad)| multiplier * fundamental_arg_rad)
+            .sum();
+        nut_in_long_arcsec = nut_in_long_arcsec
+            + (term.long_sin + term.long_sin_t * t) * arg_rad.sin()
+            + term.long_cos * arg_rad.cos();
+        nut_in_obl_arcsec = nut_in_obl_arcsec
+            + (term.obl_cos + term.obl_cos_t * t) * arg_rad.cos()
+            + term.obl_sin * arg_rad.sin();
+    }
+
+    // IAU 2000B adds fixed offsets standing in for the planetary terms.
+    if let pa_t::NutationModel::Iau2000B = nutation_model {
+        nut_in_long_arcsec = nut_in_long_arcsec - 0.000135;
+        nut_in_obl_arcsec = nut_in_obl_arcsec + 0.000388;
+    }
+
+    let nut_in_long_deg = nut_in_long_arcsec / 3600.0;
+    let nut_in_obl_deg = nut_in_obl_arcsec / 3600.0;
+
+    return (nut_in_long_deg, nut_in_obl_deg);
+}
+
+/// Calculate true obliquity of the ecliptic (mean obliquity plus nutation in obliquity) for a Greenwich date.
+///
+/// ## Returns
+/// true obliquity of the ecliptic, in degrees
+pub fn true_obliquity_of_the_ecliptic(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    nutation_model: pa_t::NutationModel,
+) -> f64 {
+    let mean_obliquity_deg =
+        mean_obliquity_of_the_ecliptic(greenwich_day, greenwich_month, greenwich_year);
+    let (_nut_in_long_deg, nut_in_obl_deg) = nutation_in_ecliptic_longitude_and_obliquity_by_model(
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        nutation_model,
+    );
+
+    return mean_obliquity_deg + nut_in_obl_deg;
+}
+
+/// Calculate the equation of the equinoxes (apparent minus mean sidereal time) for a Greenwich date.
+///
+/// Includes the complementary terms in the longitude of the Moon's node adopted by the IAU in 1994.
+///
+/// ## Returns
+/// equation of the equinoxes, in seconds of time
+pub fn equation_of_the_equinoxes(
+    greenwich_day: f64,
+    greenwich_month: u32,
+    greenwich_year: u32,
+    nutation_model: pa_t::NutationModel,
+) -> f64 {
+    let jd_days = pa_m::cd_jd(greenwich_day, greenwich_month, greenwich_year);
+    let t_centuries = (jd_days - 2451545.0) / 36525.0;
+    let mean_obliquity_deg =
+        mean_obliquity_of_the_ecliptic(greenwich_day, greenwich_month, greenwich_year);
+    let (nut_in_long_deg, _nut_in_obl_deg) = nutation_in_ecliptic_longitude_and_obliquity_by_model(
+        greenwich_day,
+        greenwich_month,
+        greenwich_year,
+        nutation_model,
+    );
+    let node_arcsec =
+        450160.280 + (-6962890.539 + (7.455 + 0.008 * t_centuries) * t_centuries) * t_centuries;
+    let node_rad = ((node_arcsec % 1296000.0) / 3600.0).to_radians();
+
+    let eq_eq_arcsec = nut_in_long_deg * 3600.0 * (mean_obliquity_deg.to_radians()).cos()
+        + 0.00264 * (node_rad).sin()
+        + 0.000063 * (2.0 * node_rad).sin();
+
+    return eq_eq_arcsec / 15.0;
+}
+
 /// Correct ecliptic coordinates for the effects of aberration.
 ///
 /// ## Returns
@@ -857,6 +1231,337 @@ pub fn atmospheric_refraction(
     );
 }
 
+/// Calculate corrected RA/Dec, accounting for atmospheric refraction with a selectable model.
+///
+/// ## Returns
+/// * corrected RA hours,minutes,seconds
+/// * corrected Declination degrees,minutes,seconds
+pub fn atmospheric_refraction_by_model(
+    true_ra_hour: f64,
+    true_ra_min: f64,
+    true_ra_sec: f64,
+    true_dec_deg: f64,
+    true_dec_min: f64,
+    true_dec_sec: f64,
+    coordinate_type: pa_t::CoordinateType,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    daylight_saving_hours: i32,
+    timezone_hours: i32,
+    lcd_day: f64,
+    lcd_month: u32,
+    lcd_year: u32,
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+    wavelength_micrometres: f64,
+    refraction_model: pa_t::RefractionModel,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let ha_hour = pa_m::ra_ha(
+        true_ra_hour,
+        true_ra_min,
+        true_ra_sec,
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving_hours,
+        timezone_hours,
+        lcd_day,
+        lcd_month,
+        lcd_year,
+        geog_long_deg,
+    );
+    let azimuth_deg = pa_m::eq_az(
+        ha_hour,
+        0.0,
+        0.0,
+        true_dec_deg,
+        true_dec_min,
+        true_dec_sec,
+        geog_lat_deg,
+    );
+    let altitude_deg = pa_m::eq_alt(
+        ha_hour,
+        0.0,
+        0.0,
+        true_dec_deg,
+        true_dec_min,
+        true_dec_sec,
+        geog_lat_deg,
+    );
+    let corrected_altitude_deg = refracted_altitude(
+        altitude_deg,
+        coordinate_type,
+        atmospheric_pressure_mbar,
+        atmospheric_temperature_celsius,
+        wavelength_micrometres,
+        refraction_model,
+    );
+
+    let corrected_ha_hour = pa_m::hor_ha(
+        azimuth_deg,
+        0.0,
+        0.0,
+        corrected_altitude_deg,
+        0.0,
+        0.0,
+        geog_lat_deg,
+    );
+    let corrected_ra_hour1 = pa_m::ha_ra(
+        corrected_ha_hour,
+        0.0,
+        0.0,
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving_hours,
+        timezone_hours,
+        lcd_day,
+        lcd_month,
+        lcd_year,
+        geog_long_deg,
+    );
+    let corrected_dec_deg1 = pa_m::hor_dec(
+        azimuth_deg,
+        0.0,
+        0.0,
+        corrected_altitude_deg,
+        0.0,
+        0.0,
+        geog_lat_deg,
+    );
+
+    let corrected_ra_hour = pa_m::dh_hour(corrected_ra_hour1);
+    let corrected_ra_min = pa_m::dh_min(corrected_ra_hour1);
+    let corrected_ra_sec = pa_m::dh_sec(corrected_ra_hour1);
+    let corrected_dec_deg = pa_m::dd_deg(corrected_dec_deg1);
+    let corrected_dec_min = pa_m::dd_min(corrected_dec_deg1);
+    let corrected_dec_sec = pa_m::dd_sec(corrected_dec_deg1);
+
+    return (
+        corrected_ra_hour as f64,
+        corrected_ra_min as f64,
+        corrected_ra_sec,
+        corrected_dec_deg,
+        corrected_dec_min,
+        corrected_dec_sec,
+    );
+}
+
+/// Correct an altitude for atmospheric refraction, using the selected model.
+///
+/// A true altitude is converted to apparent, an apparent altitude to true. Models that are only
+/// defined in one direction (Bennett, the book formula and ray tracing take apparent altitude;
+/// Saemundsson takes true altitude) are inverted by bisection. The formulas are held constant
+/// below the altitudes where they break down, so the inverse always exists.
+///
+/// ## Arguments
+/// * `altitude_deg` -- Altitude to correct (degrees).
+/// * `coordinate_type` -- Whether `altitude_deg` is the true or the apparent altitude.
+/// * `atmospheric_pressure_mbar` -- Atmospheric pressure at the observer (millibars).
+/// * `atmospheric_temperature_celsius` -- Air temperature at the observer (degrees Celsius).
+/// * `wavelength_micrometres` -- Wavelength of the light (micrometres, 0.55 for visual).
+/// * `refraction_model` -- Refraction formula to use.
+///
+/// ## Returns
+/// corrected altitude (degrees)
+pub fn refracted_altitude(
+    altitude_deg: f64,
+    coordinate_type: pa_t::CoordinateType,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+    wavelength_micrometres: f64,
+    refraction_model: pa_t::RefractionModel,
+) -> f64 {
+    let is_true_input = coordinate_type == pa_t::CoordinateType::True;
+    let uses_true_altitude = matches!(refraction_model, pa_t::RefractionModel::Saemundsson);
+    let refraction_deg = |model_altitude_deg: f64| {
+        refraction_by_model(
+            model_altitude_deg,
+            atmospheric_pressure_mbar,
+            atmospheric_temperature_celsius,
+            wavelength_micrometres,
+            &refraction_model,
+        )
+    };
+
+    if is_true_input == uses_true_altitude {
+        return if is_true_input {
+            altitude_deg + refraction_deg(altitude_deg)
+        } else {
+            altitude_deg - refraction_deg(altitude_deg)
+        };
+    }
+
+    // Bisection on the other altitude: apparent - refraction(apparent) = true, or
+    // true + refraction(true) = apparent. Both sides increase monotonically with altitude.
+    let sign = if is_true_input { -1.0 } else { 1.0 };
+    let mut low_deg = altitude_deg - 5.0;
+    let mut high_deg = altitude_deg + 5.0;
+
+    for _ in 0..60 {
+        let mid_deg = (low_deg + high_deg) / 2.0;
+
+        if mid_deg + sign * refraction_deg(mid_deg) < altitude_deg {
+            low_deg = mid_deg;
+        } else {
+            high_deg = mid_deg;
+        }
+    }
+
+    return (low_deg + high_deg) / 2.0;
+}
+
+/// Refraction (degrees) for one model, at the altitude the model is defined for.
+fn refraction_by_model(
+    altitude_deg: f64,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+    wavelength_micrometres: f64,
+    refraction_model: &pa_t::RefractionModel,
+) -> f64 {
+    let weather_factor =
+        (atmospheric_pressure_mbar / 1010.0) * (283.0 / (273.0 + atmospheric_temperature_celsius));
+    let colour_factor = air_refractivity(wavelength_micrometres) / air_refractivity(0.55);
+
+    return match refraction_model {
+        pa_t::RefractionModel::Book => {
+            // Held constant below -0.087 radians, where the book's formula drops to zero.
+            -pa_m::degrees(pa_m::refract_l3035(
+                atmospheric_pressure_mbar,
+                atmospheric_temperature_celsius,
+                altitude_deg.to_radians().max(-0.087),
+                1.0,
+            )) * colour_factor
+        }
+        pa_t::RefractionModel::Bennett => {
+            // Held constant below -4 degrees: the formula is singular at -4.4 degrees, and the
+            // bisection needs the refraction to stay finite and monotonic.
+            let h = altitude_deg.max(-4.0);
+            let r_arcmin = 1.0 / (h + 7.31 / (h + 4.4)).to_radians().tan();
+            r_arcmin / 60.0 * weather_factor * colour_factor
+        }
+        pa_t::RefractionModel::Saemundsson => {
+            // Singular at -5.11 degrees.
+            let h = altitude_deg.max(-5.0);
+            let r_arcmin = 1.02 / (h + 10.3 / (h + 5.11)).to_radians().tan();
+            r_arcmin / 60.0 * weather_factor * colour_factor
+        }
+        pa_t::RefractionModel::RayTracing => ray_traced_refraction(
+            altitude_deg,
+            atmospheric_pressure_mbar,
+            atmospheric_temperature_celsius,
+            wavelength_micrometres,
+        ),
+    };
+}
+
+/// Refractivity (n - 1) of dry air at 15 degrees C and 1013.25 mbar (Edlen 1966).
+fn air_refractivity(wavelength_micrometres: f64) -> f64 {
+    let sigma2 = 1.0 / (wavelength_micrometres * wavelength_micrometres);
+
+    return (8342.54 + 2406147.0 / (130.0 - sigma2) + 15998.0 / (38.9 - sigma2)) * 1.0e-8;
+}
+
+/// Refraction (degrees) for an apparent altitude, found by integrating along the ray through a
+/// spherically stratified atmosphere.
+///
+/// The atmosphere has a 6.5 K/km lapse rate up to 11 km, is isothermal above that, and is
+/// extrapolated below the observer for rays leaving below the horizon.
+fn ray_traced_refraction(
+    apparent_altitude_deg: f64,
+    atmospheric_pressure_mbar: f64,
+    atmospheric_temperature_celsius: f64,
+    wavelength_micrometres: f64,
+) -> f64 {
+    let earth_radius_m = 6378120.0;
+    let top_m = 80000.0;
+    let tropopause_m = 11000.0;
+    let lapse_rate = 0.0065;
+    let gas_exponent = 9.80665 / 287.053;
+    let t0 = 273.15 + atmospheric_temperature_celsius;
+    let refractivity_std = air_refractivity(wavelength_micrometres);
+
+    // Temperature, refractivity and its height derivative at height h above the observer.
+    let atmosphere = |h: f64| -> (f64, f64) {
+        let (temperature, pressure, d_temperature) = if h < tropopause_m {
+            let temperature = t0 - lapse_rate * h;
+            let pressure =
+                atmospheric_pressure_mbar * (temperature / t0).powf(gas_exponent / lapse_rate);
+            (temperature, pressure, -lapse_rate)
+        } else {
+            let temperature = t0 - lapse_rate * tropopause_m;
+            let pressure = atmospheric_pressure_mbar
+                * (temperature / t0).powf(gas_exponent / lapse_rate)
+                * (-gas_exponent * (h - tropopause_m) / temperature).exp();
+            (temperature, pressure, 0.0)
+        };
+        let refractivity = refractivity_std * (pressure / 1013.25) * (288.15 / temperature);
+        let d_refractivity =
+            -refractivity * (gas_exponent / temperature + d_temperature / temperature);
+
+        (refractivity, d_refractivity)
+    };
+
+    let z0 = (90.0 - apparent_altitude_deg).to_radians();
+    let (refractivity0, _) = atmosphere(0.0);
+    let invariant = (1.0 + refractivity0) * earth_radius_m * z0.sin();
+
+    // Integral of -tan(z)/n dn from height a to the top, with h = a + u^2 removing the
+    // square-root singularity at a tangent point.
+    let integrate = |a: f64| -> f64 {
+        let steps = 4000;
+        let u_top = (top_m - a).sqrt();
+        let du = u_top / steps as f64;
+        let integrand = |u: f64| -> f64 {
+            let h = a + u * u;
+            let (refractivity, d_refractivity) = atmosphere(h);
+            let sin_z = (invariant / ((1.0 + refractivity) * (earth_radius_m + h))).min(1.0);
+            let cos_z = (1.0 - sin_z * sin_z).max(0.0).sqrt();
+
+            if cos_z == 0.0 {
+                // Limit at the tangent point, where cos z ~ u.
+                let f = (1.0 + refractivity) * (earth_radius_m + h);
+                let df = d_refractivity * (earth_radius_m + h) + (1.0 + refractivity);
+                return -d_refractivity / (1.0 + refractivity) * 2.0 / (2.0 * df / f).sqrt();
+            }
+
+            return -d_refractivity / (1.0 + refractivity) * (sin_z / cos_z) * 2.0 * u;
+        };
+
+        let mut sum = integrand(0.0) + integrand(u_top);
+        for i in 1..steps {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * integrand(i as f64 * du);
+        }
+
+        return sum * du / 3.0;
+    };
+
+    let refraction_rad = if z0 <= std::f64::consts::FRAC_PI_2 {
+        integrate(0.0)
+    } else {
+        // Ray dips below the observer: find the tangent height, count the lower part twice.
+        let mut low_m = -50000.0;
+        let mut high_m = 0.0;
+        for _ in 0..60 {
+            let mid_m = (low_m + high_m) / 2.0;
+            let (refractivity, _) = atmosphere(mid_m);
+            if (1.0 + refractivity) * (earth_radius_m + mid_m) < invariant {
+                low_m = mid_m;
+            } else {
+                high_m = mid_m;
+            }
+        }
+
+        2.0 * integrate(high_m) - integrate(0.0)
+    };
+
+    return refraction_rad.to_degrees();
+}
+
 /// Calculate corrected RA/Dec, accounting for geocentric parallax.
 ///
 /// NOTE: Valid values for coordinate_type are "TRUE" and "APPARENT".
@@ -969,6 +1674,94 @@ pub fn heliographic_coordinates(
     gwdate_month: u32,
     gwdate_year: u32,
 ) -> (f64, f64) {
+    let (p_deg, b0_deg, l0_deg) = solar_disk_orientation(gwdate_day, gwdate_month, gwdate_year);
+    let b0_rad = b0_deg.to_radians();
+    let rho1_deg = helio_displacement_arcmin / 60.0;
+    let rho_rad = (2.0 * rho1_deg
+        / pa_m::sun_dia(0.0, 0.0, 0.0, 0, 0, gwdate_day, gwdate_month, gwdate_year))
+    .asin()
+        - (rho1_deg).to_radians();
+    let b_rad = ((b0_rad).sin() * (rho_rad).cos()
+        + (b0_rad).cos()
+            * (rho_rad).sin()
+            * ((p_deg - helio_position_angle_deg).to_radians()).cos())
+    .asin();
+    let b_deg = pa_m::degrees(b_rad);
+    let l_deg1 = pa_m::degrees(
+        ((rho_rad).sin() * ((p_deg - helio_position_angle_deg).to_radians()).sin() / (b_rad).cos())
+            .asin(),
+    ) + l0_deg;
+    let l_deg2 = l_deg1 - 360.0 * (l_deg1 / 360.0).floor();
+
+    let helio_long_deg = pa_u::round_f64(l_deg2, 2);
+    let helio_lat_deg = pa_u::round_f64(b_deg, 2);
+
+    return (helio_long_deg, helio_lat_deg);
+}
+
+/// Calculate the position on the solar disk of a point with given heliographic coordinates, for a given Greenwich date. (The inverse of `heliographic_coordinates`.)
+///
+/// ## Returns
+/// * heliographic position angle, in degrees (-99.0 if the point is on the far side of the Sun)
+/// * heliographic displacement, in arc minutes (-99.0 if the point is on the far side of the Sun)
+pub fn heliographic_to_disk_position(
+    helio_long_deg: f64,
+    helio_lat_deg: f64,
+    gwdate_day: f64,
+    gwdate_month: u32,
+    gwdate_year: u32,
+) -> (f64, f64) {
+    let (p_deg, b0_deg, l0_deg) = solar_disk_orientation(gwdate_day, gwdate_month, gwdate_year);
+    let b0_rad = b0_deg.to_radians();
+    let b_rad = helio_lat_deg.to_radians();
+    let l_rad = (helio_long_deg - l0_deg).to_radians();
+
+    let cos_rho = b0_rad.sin() * b_rad.sin() + b0_rad.cos() * b_rad.cos() * l_rad.cos();
+    if cos_rho < 0.0 {
+        return (-99.0, -99.0);
+    }
+    let rho_rad = cos_rho.acos();
+
+    let p_minus_theta_rad = (b_rad.cos() * l_rad.sin())
+        .atan2(b0_rad.cos() * b_rad.sin() - b0_rad.sin() * b_rad.cos() * l_rad.cos());
+    let theta_deg = p_deg - pa_m::degrees(p_minus_theta_rad);
+
+    // Invert rho = asin(2 rho1 / S) - rho1 for the apparent displacement rho1.
+    let sun_dia_deg = pa_m::sun_dia(0.0, 0.0, 0.0, 0, 0, gwdate_day, gwdate_month, gwdate_year);
+    let mut rho1_deg = sun_dia_deg / 2.0 * rho_rad.sin();
+    for _ in 0..10 {
+        rho1_deg = sun_dia_deg / 2.0 * (rho_rad + rho1_deg.to_radians()).sin();
+    }
+
+    let helio_position_angle_deg =
+        pa_u::round_f64(theta_deg - 360.0 * (theta_deg / 360.0).floor(), 2);
+    let helio_displacement_arcmin = pa_u::round_f64(rho1_deg * 60.0, 2);
+
+    return (helio_position_angle_deg, helio_displacement_arcmin);
+}
+
+/// Calculate the solar physical ephemeris for a given Greenwich date.
+///
+/// ## Returns
+/// * position angle of the Sun's rotation axis (P), in degrees
+/// * heliographic latitude of the center of the disk (B0), in degrees
+/// * heliographic longitude of the center of the disk (L0), in degrees
+pub fn solar_physical_ephemeris(
+    gwdate_day: f64,
+    gwdate_month: u32,
+    gwdate_year: u32,
+) -> (f64, f64, f64) {
+    let (p_deg, b0_deg, l0_deg) = solar_disk_orientation(gwdate_day, gwdate_month, gwdate_year);
+
+    return (
+        pa_u::round_f64(p_deg, 2),
+        pa_u::round_f64(b0_deg, 2),
+        pa_u::round_f64(l0_deg, 2),
+    );
+}
+
+/// Orientation of the solar disk for a Greenwich date: P, B0 and L0, in degrees.
+fn solar_disk_orientation(gwdate_day: f64, gwdate_month: u32, gwdate_year: u32) -> (f64, f64, f64) {
     let julian_date_days = pa_m::cd_jd(gwdate_day, gwdate_month, gwdate_year);
     let t_centuries = (julian_date_days - 2415020.0) / 36525.0;
     let long_asc_node_deg = pa_m::dms_dd(74.0, 22.0, 0.0) + (84.0 * t_centuries / 60.0);
@@ -980,7 +1773,7 @@ pub fn heliographic_coordinates(
     let m_deg1 = 360.0 - (360.0 * (julian_date_days - 2398220.0) / 25.38);
     let m_deg2 = m_deg1 - 360.0 * (m_deg1 / 360.0).floor();
     let l0_deg1 = m_deg2 + a_deg;
-    let _l0_deg2 = l0_deg1 - 360.0 * (l0_deg1 / 360.0).floor();
+    let l0_deg2 = l0_deg1 - 360.0 * (l0_deg1 / 360.0).floor();
     let b0_rad = (((sun_long_deg - long_asc_node_deg).to_radians()).sin()
         * ((pa_m::dms_dd(7.0, 15.0, 0.0)).to_radians()).sin())
     .asin();
@@ -991,27 +1784,8 @@ pub fn heliographic_coordinates(
         * ((pa_m::dms_dd(7.0, 15.0, 0.0)).to_radians()).tan())
     .atan();
     let p_deg = pa_m::degrees(theta1_rad + theta2_rad);
-    let rho1_deg = helio_displacement_arcmin / 60.0;
-    let rho_rad = (2.0 * rho1_deg
-        / pa_m::sun_dia(0.0, 0.0, 0.0, 0, 0, gwdate_day, gwdate_month, gwdate_year))
-    .asin()
-        - (rho1_deg).to_radians();
-    let b_rad = ((b0_rad).sin() * (rho_rad).cos()
-        + (b0_rad).cos()
-            * (rho_rad).sin()
-            * ((p_deg - helio_position_angle_deg).to_radians()).cos())
-    .asin();
-    let b_deg = pa_m::degrees(b_rad);
-    let l_deg1 = pa_m::degrees(
-        ((rho_rad).sin() * ((p_deg - helio_position_angle_deg).to_radians()).sin() / (b_rad).cos())
-            .asin(),
-    ) + l0_deg1;
-    let l_deg2 = l_deg1 - 360.0 * (l_deg1 / 360.0).floor();
-
-    let helio_long_deg = pa_u::round_f64(l_deg2, 2);
-    let helio_lat_deg = pa_u::round_f64(b_deg, 2);
 
-    return (helio_long_deg, helio_lat_deg);
+    return (p_deg, pa_m::degrees(b0_rad), l0_deg2);
 }
 
 /// Calculate carrington rotation number for a Greenwich date.
@@ -1026,6 +1800,37 @@ pub fn carrington_rotation_number(gwdate_day: f64, gwdate_month: u32, gwdate_yea
     return crn;
 }
 
+/// Calculate the start and end of a Carrington rotation (when the Carrington longitude of the center of the disk passes 360 degrees).
+///
+/// Meeus's formula gives Terrestrial Time; the dates are converted to Universal Time with Delta T.
+///
+/// ## Returns
+/// * start of the rotation: Greenwich date day part (with fraction of day, UT), month part, year part
+/// * end of the rotation: Greenwich date day part (with fraction of day, UT), month part, year part
+pub fn carrington_rotation_start_and_end(
+    carrington_rotation_number: i32,
+) -> (f64, u32, u32, f64, u32, u32) {
+    let rotation_start = |rotation_number: f64| {
+        let m_rad = (281.96 + 26.882476 * rotation_number).to_radians();
+        2398140.2270 + 27.2752316 * rotation_number + 0.1454 * m_rad.sin()
+            - 0.0085 * (2.0 * m_rad).sin()
+            - 0.0141 * (2.0 * m_rad).cos()
+    };
+    let start_jde = rotation_start(carrington_rotation_number as f64);
+    let end_jde = rotation_start(carrington_rotation_number as f64 + 1.0);
+    let start_jd = start_jde - pa_dt::delta_t_days(start_jde);
+    let end_jd = end_jde - pa_dt::delta_t_days(end_jde);
+
+    return (
+        pa_m::jdc_day(start_jd),
+        pa_m::jdc_month(start_jd),
+        pa_m::jdc_year(start_jd),
+        pa_m::jdc_day(end_jd),
+        pa_m::jdc_month(end_jd),
+        pa_m::jdc_year(end_jd),
+    );
+}
+
 /// Calculate selenographic (lunar) coordinates (sub-Earth).
 ///
 /// ## Returns
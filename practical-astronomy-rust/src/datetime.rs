@@ -1,4 +1,7 @@
+use crate::calendar as pa_cal;
+use crate::coordinates as pa_c;
 use crate::macros as pa_m;
+use crate::types as pa_t;
 use crate::util as pa_u;
 
 /// Gets the date of Easter for the year specified.
@@ -34,6 +37,56 @@ pub fn get_date_of_easter(input_year: u32) -> (u32, u32, u32) {
     return (month as u32, day as u32, year as u32);
 }
 
+/// Gets the date of Orthodox Easter for the year specified, as a Gregorian calendar date.
+///
+/// ## Arguments
+///
+/// input_year -- Year for which you'd like the date of Orthodox Easter.
+///
+/// ## Returns
+///
+/// month, day, year
+pub fn get_date_of_orthodox_easter(input_year: u32) -> (u32, u32, u32) {
+    let year = input_year as f64;
+
+    let a = year % 4.0;
+    let b = year % 7.0;
+    let c = year % 19.0;
+    let d = ((19.0 * c) + 15.0) % 30.0;
+    let e = ((2.0 * a) + (4.0 * b) - d + 34.0) % 7.0;
+    let f = ((d + e + 114.0) / 31.0).floor();
+    let g = (d + e + 114.0) % 31.0;
+
+    let julian_day = g + 1.0;
+    let julian_month = f;
+    let (day, month, year) =
+        pa_cal::julian_day_number_to_gregorian(pa_cal::julian_to_julian_day_number(
+            julian_day as u32,
+            julian_month as u32,
+            input_year as i64,
+        ));
+
+    return (month, day, year as u32);
+}
+
+/// Gets the date of Passover (15 Nisan) for the year specified, as a Gregorian calendar date.
+/// The festival begins at sunset on the previous day.
+///
+/// ## Arguments
+///
+/// input_year -- Year for which you'd like the date of Passover.
+///
+/// ## Returns
+///
+/// month, day, year
+pub fn get_date_of_passover(input_year: u32) -> (u32, u32, u32) {
+    let (day, month, year) = pa_cal::julian_day_number_to_gregorian(
+        pa_cal::hebrew_to_julian_day_number(15, 1, input_year as i64 + 3760),
+    );
+
+    return (month, day, year as u32);
+}
+
 /// Calculate day number for a date.
 ///
 /// ## Arguments
@@ -189,6 +242,35 @@ pub fn universal_time_to_greenwich_sidereal_time(
     return (gst_hours, gst_minutes, gst_seconds);
 }
 
+/// Convert Universal Time to Greenwich Apparent Sidereal Time.
+///
+/// Apparent sidereal time is mean sidereal time corrected by the equation of the equinoxes,
+/// using the selected nutation series. Mean sidereal time is the IAU 1982 expression; see
+/// `greenwich_apparent_sidereal_time` to select the sidereal time model.
+///
+/// ## Returns
+/// GAST hours, GAST minutes, GAST seconds
+pub fn universal_time_to_greenwich_apparent_sidereal_time(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+    nutation_model: pa_t::NutationModel,
+) -> (u32, u32, f64) {
+    return greenwich_apparent_sidereal_time(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        pa_t::SiderealTimeModel::Iau1982,
+        nutation_model,
+    );
+}
+
 /// Convert Greenwich Sidereal Time to Universal Time.
 ///
 /// ## Returns
@@ -220,6 +302,184 @@ pub fn greenwich_sidereal_time_to_universal_time(
     return (ut_hours, ut_minutes, ut_seconds, warning_flag.to_string());
 }
 
+/// Convert Greenwich Sidereal Time to Universal Time, returning every matching instant.
+///
+/// A sidereal day is about 3m56s shorter than a solar day, so a sidereal time that falls
+/// in the first 0.065574 hours after 0h UT occurs twice during the Greenwich date.
+///
+/// ## Returns
+/// Candidate UT times (hours, minutes, seconds), in chronological order. There are two
+/// entries when the sidereal time occurs twice on the Greenwich date, otherwise one.
+pub fn greenwich_sidereal_time_to_universal_times(
+    gst_hours: f64,
+    gst_minutes: f64,
+    gst_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) -> Vec<(u32, u32, f64)> {
+    let sidereal_day_hours = 24.0 * 0.9972695663;
+    let ut_first = pa_m::gst_ut(
+        gst_hours,
+        gst_minutes,
+        gst_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+    );
+    let ut_second = ut_first + sidereal_day_hours;
+
+    let mut ut_candidates: Vec<(u32, u32, f64)> = Vec::new();
+    ut_candidates.push((
+        pa_m::dh_hour(ut_first),
+        pa_m::dh_min(ut_first),
+        pa_m::dh_sec(ut_first),
+    ));
+    if ut_second < 24.0 {
+        ut_candidates.push((
+            pa_m::dh_hour(ut_second),
+            pa_m::dh_min(ut_second),
+            pa_m::dh_sec(ut_second),
+        ));
+    }
+
+    return ut_candidates;
+}
+
+/// Calculate the Earth Rotation Angle (IAU 2000) for a Universal Time and Greenwich date.
+///
+/// ## Returns
+/// Earth Rotation Angle, in degrees (0 to 360)
+pub fn earth_rotation_angle(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+) -> f64 {
+    let ut = pa_m::hms_dh(ut_hours, ut_minutes, ut_seconds);
+    let jd_0h = pa_m::cd_jd(gw_day, gw_month, gw_year);
+    let du_days = (jd_0h - 2451545.0) + (ut / 24.0);
+    let fraction_of_day = (jd_0h - jd_0h.floor()) + (ut / 24.0);
+    let turns = fraction_of_day + 0.7790572732640 + 0.00273781191135448 * du_days;
+    let era_deg1 = 360.0 * turns;
+
+    return era_deg1 - 360.0 * (era_deg1 / 360.0).floor();
+}
+
+/// Convert Universal Time to Greenwich Mean Sidereal Time, using the selected IAU model.
+///
+/// * IAU 1982 -- The expression of Aoki et al., as used with the IAU 1976/1980 precession-nutation.
+/// * IAU 2006 -- Earth Rotation Angle plus the IAU 2006 precession polynomial (Capitaine et al.).
+///   Terrestrial Time is taken to be equal to Universal Time, which affects the result by well
+///   under a millisecond.
+///
+/// ## Returns
+/// GMST hours, GMST minutes, GMST seconds
+pub fn greenwich_mean_sidereal_time(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+    sidereal_time_model: pa_t::SiderealTimeModel,
+) -> (u32, u32, f64) {
+    let gmst_hours1 = greenwich_mean_sidereal_time_hours(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        sidereal_time_model,
+    );
+
+    return (
+        pa_m::dh_hour(gmst_hours1),
+        pa_m::dh_min(gmst_hours1),
+        pa_m::dh_sec(gmst_hours1),
+    );
+}
+
+/// Convert Universal Time to Greenwich Apparent Sidereal Time, using the selected IAU models.
+///
+/// Apparent sidereal time is the mean sidereal time of the selected model, corrected by the
+/// equation of the equinoxes from the selected nutation series.
+///
+/// ## Returns
+/// GAST hours, GAST minutes, GAST seconds
+pub fn greenwich_apparent_sidereal_time(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+    sidereal_time_model: pa_t::SiderealTimeModel,
+    nutation_model: pa_t::NutationModel,
+) -> (u32, u32, f64) {
+    let gmst_hours = greenwich_mean_sidereal_time_hours(
+        ut_hours,
+        ut_minutes,
+        ut_seconds,
+        gw_day,
+        gw_month,
+        gw_year,
+        sidereal_time_model,
+    );
+    let ut = pa_m::hms_dh(ut_hours, ut_minutes, ut_seconds);
+    let eq_eq_seconds =
+        pa_c::equation_of_the_equinoxes(gw_day + (ut / 24.0), gw_month, gw_year, nutation_model);
+    let gast1 = gmst_hours + (eq_eq_seconds / 3600.0);
+    let gast2 = gast1 - (24.0 * (gast1 / 24.0).floor());
+
+    return (
+        pa_m::dh_hour(gast2),
+        pa_m::dh_min(gast2),
+        pa_m::dh_sec(gast2),
+    );
+}
+
+/// Greenwich Mean Sidereal Time in decimal hours, for the selected IAU model.
+pub(crate) fn greenwich_mean_sidereal_time_hours(
+    ut_hours: f64,
+    ut_minutes: f64,
+    ut_seconds: f64,
+    gw_day: f64,
+    gw_month: u32,
+    gw_year: u32,
+    sidereal_time_model: pa_t::SiderealTimeModel,
+) -> f64 {
+    let ut = pa_m::hms_dh(ut_hours, ut_minutes, ut_seconds);
+    let jd = pa_m::cd_jd(gw_day, gw_month, gw_year) + (ut / 24.0);
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let gmst_hours1 = match sidereal_time_model {
+        pa_t::SiderealTimeModel::Iau1982 => {
+            let jd_0h = (jd - 0.5).floor() + 0.5;
+            let t_0h = (jd_0h - 2451545.0) / 36525.0;
+            let gmst_0h_seconds =
+                24110.54841 + t_0h * (8640184.812866 + t_0h * (0.093104 - t_0h * 0.0000062));
+            let ut_since_0h_seconds = (jd - jd_0h) * 86400.0;
+
+            (gmst_0h_seconds + 1.00273790935 * ut_since_0h_seconds) / 3600.0
+        }
+        pa_t::SiderealTimeModel::Iau2006 => {
+            let era_deg =
+                earth_rotation_angle(ut_hours, ut_minutes, ut_seconds, gw_day, gw_month, gw_year);
+            let precession_arcsec = 0.014506
+                + t * (4612.156534
+                    + t * (1.3915817 + t * (-0.00000044 + t * (-0.000029956 + t * -0.0000000368))));
+
+            (era_deg + precession_arcsec / 3600.0) / 15.0
+        }
+    };
+
+    return gmst_hours1 - (24.0 * (gmst_hours1 / 24.0).floor());
+}
+
 /// Convert Greenwich Sidereal Time to Local Sidereal Time.
 ///
 /// ## Returns
@@ -263,3 +523,100 @@ pub fn local_sidereal_time_to_greenwich_sidereal_time(
 
     return (gst_hours, gst_minutes, gst_seconds);
 }
+
+/// Estimate Delta T (Terrestrial Time minus Universal Time) for a Greenwich date.
+///
+/// Uses the polynomial expressions of Espenak & Meeus (2006), which are fitted to historical
+/// observations and extrapolated outside them.
+///
+/// ## Returns
+/// Delta T, in seconds
+pub fn delta_t(gw_day: f64, gw_month: u32, gw_year: u32) -> f64 {
+    let y = gw_year as f64 + (gw_month as f64 - 1.0 + (gw_day - 1.0) / 31.0) / 12.0;
+
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    return if y < -500.0 {
+        long_term(y)
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        10583.6
+            + u * (-1014.41
+                + u * (33.78311
+                    + u * (-5.952053 + u * (-0.1798452 + u * (0.022174192 + u * 0.0090316521)))))
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        1574.2
+            + u * (-556.01
+                + u * (71.23472
+                    + u * (0.319781 + u * (-0.8503463 + u * (-0.005050998 + u * 0.0083572073)))))
+    } else if y < 1700.0 {
+        let t = y - 1600.0;
+        120.0 + t * (-0.9808 + t * (-0.01532 + t / 7129.0))
+    } else if y < 1800.0 {
+        let t = y - 1700.0;
+        8.83 + t * (0.1603 + t * (-0.0059285 + t * (0.00013336 - t / 1174000.0)))
+    } else if y < 1860.0 {
+        let t = y - 1800.0;
+        13.72
+            + t * (-0.332447
+                + t * (0.0068612
+                    + t * (0.0041116
+                        + t * (-0.00037436
+                            + t * (0.0000121272 + t * (-0.0000001699 + t * 0.000000000875))))))
+    } else if y < 1900.0 {
+        let t = y - 1860.0;
+        7.62 + t
+            * (0.5737 + t * (-0.251754 + t * (0.01680668 + t * (-0.0004473624 + t / 233174.0))))
+    } else if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + t * (1.494119 + t * (-0.0598939 + t * (0.0061966 - t * 0.000197)))
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.20 + t * (0.84493 + t * (-0.076100 + t * 0.0020936))
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + t * (0.407 + t * (-1.0 / 233.0 + t / 2547.0))
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + t * (1.067 + t * (-1.0 / 260.0 - t / 718.0))
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86
+            + t * (0.3345
+                + t * (-0.060374 + t * (0.0017275 + t * (0.000651814 + t * 0.00002373599))))
+    } else if y < 2050.0 {
+        let t = y - 2000.0;
+        62.92 + t * (0.32217 + t * 0.005589)
+    } else if y < 2150.0 {
+        long_term(y) - 0.5628 * (2150.0 - y)
+    } else {
+        long_term(y)
+    };
+}
+
+/// Offset of local civil time from Universal Time, in days.
+pub(crate) fn local_offset_days(is_daylight_saving: bool, zone_correction_hours: i32) -> f64 {
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+
+    return (daylight_saving + zone_correction_hours) as f64 / 24.0;
+}
+
+/// Julian date (UT) of the start of a local civil date.
+pub(crate) fn local_date_start_jd_ut(
+    local_date_day: u32,
+    local_date_month: u32,
+    local_date_year: u32,
+    local_offset_days: f64,
+) -> f64 {
+    return pa_m::cd_jd(local_date_day as f64, local_date_month, local_date_year)
+        - local_offset_days;
+}
+
+/// Delta T, in days, at a Julian date.
+pub(crate) fn delta_t_days(jd: f64) -> f64 {
+    return delta_t(pa_m::jdc_day(jd), pa_m::jdc_month(jd), pa_m::jdc_year(jd)) / 86400.0;
+}
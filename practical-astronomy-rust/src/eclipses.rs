@@ -1,4 +1,8 @@
+use crate::coordinates as pa_c;
+use crate::datetime as pa_dt;
+use crate::frames as pa_f;
 use crate::macros as pa_m;
+use crate::types as pa_t;
 use crate::util as pa_u;
 
 /// Determine if a lunar eclipse is likely to occur.
@@ -637,3 +641,1351 @@ pub fn solar_eclipse_circumstances(
         eclipse_magnitude,
     );
 }
+
+/// Info about one eclipse found by an eclipse search:
+/// * `eclipse_kind` -- Solar or lunar.
+/// * `eclipse_type` -- Total, annular, hybrid, partial, or penumbral.
+/// * `greatest_eclipse_jd_tt` -- Julian date of greatest eclipse (Terrestrial Time).
+/// * `greatest_eclipse_date_day` -- Date of greatest eclipse (day, Universal Time).
+/// * `greatest_eclipse_date_month` -- Date of greatest eclipse (month, Universal Time).
+/// * `greatest_eclipse_date_year` -- Date of greatest eclipse (year, Universal Time).
+/// * `greatest_eclipse_ut_hour` -- Time of greatest eclipse (hour, Universal Time).
+/// * `greatest_eclipse_ut_minutes` -- Time of greatest eclipse (minutes, Universal Time).
+/// * `gamma` -- Least distance from the shadow axis to the center of the Earth (solar), or from the Moon's center to the shadow axis (lunar), in Earth radii.
+/// * `magnitude` -- Eclipse magnitude (umbral for partial and total lunar eclipses).
+/// * `saros_number` -- Saros series number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EclipseEvent {
+    pub eclipse_kind: pa_t::EclipseKind,
+    pub eclipse_type: pa_t::EclipseType,
+    pub greatest_eclipse_jd_tt: f64,
+    pub greatest_eclipse_date_day: f64,
+    pub greatest_eclipse_date_month: u32,
+    pub greatest_eclipse_date_year: u32,
+    pub greatest_eclipse_ut_hour: f64,
+    pub greatest_eclipse_ut_minutes: f64,
+    pub gamma: f64,
+    pub magnitude: f64,
+    pub saros_number: i32,
+}
+
+/// Iterator over all solar and lunar eclipses in a range of years, in date order.
+///
+/// Created by `eclipse_search`.
+pub struct EclipseSearch {
+    lunation: f64,
+    end_year: u32,
+    start_year: u32,
+}
+
+impl Iterator for EclipseSearch {
+    type Item = EclipseEvent;
+
+    fn next(&mut self) -> Option<EclipseEvent> {
+        loop {
+            let lunation = self.lunation;
+            self.lunation += 0.5;
+
+            if let Some(eclipse_event) = eclipse_at_lunation(lunation) {
+                if eclipse_event.greatest_eclipse_date_year > self.end_year {
+                    return None;
+                }
+                if eclipse_event.greatest_eclipse_date_year >= self.start_year {
+                    return Some(eclipse_event);
+                }
+            }
+        }
+    }
+}
+
+/// Search for every solar and lunar eclipse from the start of one year to the end of another.
+///
+/// Eclipse circumstances are computed with the method of Meeus, "Astronomical Algorithms",
+/// chapter 54. Times are accurate to a few minutes, gamma and magnitude to about 0.001.
+///
+/// ## Arguments
+/// * `start_year` -- First year to search.
+/// * `end_year` -- Last year to search (inclusive).
+///
+/// ## Returns
+/// iterator of `EclipseEvent`
+pub fn eclipse_search(start_year: u32, end_year: u32) -> EclipseSearch {
+    let lunation = ((start_year as f64 - 2000.0) * 12.3685).floor() - 1.0;
+
+    return EclipseSearch {
+        lunation,
+        end_year,
+        start_year,
+    };
+}
+
+/// Eclipse circumstances at a Meeus lunation (integer for new moon, half-integer for full
+/// moon, 0 at the new moon of 2000 January 6), if there is an eclipse.
+fn eclipse_at_lunation(k: f64) -> Option<EclipseEvent> {
+    let is_solar = k.fract() == 0.0;
+    let t = k / 1236.85;
+
+    let f_deg = 160.7108 + 390.67050284 * k - 0.0016118 * t * t - 0.00000227 * t * t * t
+        + 0.000000011 * t * t * t * t;
+    if f_deg.to_radians().sin().abs() > 0.36 {
+        return None;
+    }
+
+    let jde = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.00000015 * t * t * t
+        + 0.00000000073 * t * t * t * t;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.1053567 * k - 0.0000014 * t * t - 0.00000011 * t * t * t).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t * t + 0.00001238 * t * t * t
+        - 0.000000058 * t * t * t * t)
+        .to_radians();
+    let omega =
+        (124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t * t * t).to_radians();
+    let f1 = f_deg.to_radians() - 0.02665_f64.to_radians() * omega.sin();
+    let a1 = (299.77 + 0.107408 * k - 0.009173 * t * t).to_radians();
+
+    let (sin_mp_coefficient, sin_m_coefficient) = if is_solar {
+        (-0.4075, 0.1721)
+    } else {
+        (-0.4065, 0.1727)
+    };
+    let greatest_eclipse_jd_tt = jde
+        + sin_mp_coefficient * mp.sin()
+        + sin_m_coefficient * e * m.sin()
+        + 0.0161 * (2.0 * mp).sin()
+        - 0.0097 * (2.0 * f1).sin()
+        + 0.0073 * e * (mp - m).sin()
+        - 0.0050 * e * (mp + m).sin()
+        - 0.0023 * (mp - 2.0 * f1).sin()
+        + 0.0021 * e * (2.0 * m).sin()
+        + 0.0012 * (mp + 2.0 * f1).sin()
+        + 0.0006 * e * (2.0 * mp + m).sin()
+        - 0.0004 * (3.0 * mp).sin()
+        - 0.0003 * e * (m + 2.0 * f1).sin()
+        + 0.0003 * a1.sin()
+        - 0.0002 * e * (m - 2.0 * f1).sin()
+        - 0.0002 * e * (2.0 * mp - m).sin()
+        - 0.0002 * omega.sin();
+
+    let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin()
+        + 0.0116 * (2.0 * mp).sin()
+        - 0.0073 * e * (mp + m).sin()
+        + 0.0067 * e * (mp - m).sin()
+        + 0.0118 * (2.0 * f1).sin();
+    let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos()
+        - 0.3299 * mp.cos()
+        - 0.0060 * e * (mp + m).cos()
+        + 0.0041 * e * (mp - m).cos();
+    let w = f1.cos().abs();
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+    let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos()
+        - 0.0005 * (m + mp).cos();
+
+    let (eclipse_type, magnitude) = if is_solar {
+        if gamma.abs() > 1.5433 + u {
+            return None;
+        }
+
+        if gamma.abs() < 0.9972 + u.abs() {
+            // Umbral or antumbral shadow reaches the Earth. The shadow radius is reduced to the
+            // Earth's surface under the axis before comparing with the penumbra.
+            let z = (1.0 - gamma * gamma).max(0.0).sqrt();
+            let u_surface = u - 0.0046 * z;
+            let central_type = if u < 0.0 {
+                pa_t::EclipseType::Total
+            } else if u > 0.0047 || u >= 0.00464 * z {
+                pa_t::EclipseType::Annular
+            } else {
+                pa_t::EclipseType::Hybrid
+            };
+
+            (central_type, 0.5461 / (0.5461 + 2.0 * u_surface))
+        } else {
+            (
+                pa_t::EclipseType::Partial,
+                (1.5433 + u - gamma.abs()) / (0.5461 + 2.0 * u),
+            )
+        }
+    } else {
+        let penumbral_magnitude = (1.5573 + u - gamma.abs()) / 0.5450;
+        let umbral_magnitude = (1.0128 - u - gamma.abs()) / 0.5450;
+
+        if penumbral_magnitude <= 0.0 {
+            return None;
+        }
+
+        if umbral_magnitude <= 0.0 {
+            (pa_t::EclipseType::Penumbral, penumbral_magnitude)
+        } else if umbral_magnitude < 1.0 {
+            (pa_t::EclipseType::Partial, umbral_magnitude)
+        } else {
+            (pa_t::EclipseType::Total, umbral_magnitude)
+        }
+    };
+
+    // Saros series: one inex (358 lunations) later is the next series, one saros (223
+    // lunations) later is the same series. 38 is the inverse of 358 modulo 223.
+    let (reference_lunation, reference_saros) = if is_solar { (218.0, 145) } else { (235.5, 134) };
+    let inex_steps = (38 * (k - reference_lunation) as i64).rem_euclid(223) as i32;
+    let saros_number = (reference_saros + inex_steps + 20).rem_euclid(223) - 20;
+
+    let approx_day = pa_m::jdc_day(greatest_eclipse_jd_tt);
+    let approx_month = pa_m::jdc_month(greatest_eclipse_jd_tt);
+    let approx_year = pa_m::jdc_year(greatest_eclipse_jd_tt);
+    let greatest_eclipse_jd_ut =
+        greatest_eclipse_jd_tt - pa_dt::delta_t(approx_day, approx_month, approx_year) / 86400.0;
+
+    let ut_day = pa_m::jdc_day(greatest_eclipse_jd_ut);
+    let ut_hours = (ut_day - ut_day.floor()) * 24.0;
+
+    return Some(EclipseEvent {
+        eclipse_kind: if is_solar {
+            pa_t::EclipseKind::Solar
+        } else {
+            pa_t::EclipseKind::Lunar
+        },
+        eclipse_type,
+        greatest_eclipse_jd_tt,
+        greatest_eclipse_date_day: ut_day.floor(),
+        greatest_eclipse_date_month: pa_m::jdc_month(greatest_eclipse_jd_ut),
+        greatest_eclipse_date_year: pa_m::jdc_year(greatest_eclipse_jd_ut),
+        greatest_eclipse_ut_hour: pa_m::dh_hour(ut_hours) as f64,
+        greatest_eclipse_ut_minutes: pa_m::dh_min(ut_hours) as f64,
+        gamma: pa_u::round_f64(gamma, 4),
+        magnitude: pa_u::round_f64(magnitude, 4),
+        saros_number,
+    });
+}
+
+/// Besselian elements of a solar eclipse, as cubic polynomials in `t` (hours of Terrestrial
+/// Time from `t0_jd_tt`), each stored as `[c0, c1, c2, c3]`:
+/// * `t0_jd_tt` -- Reference time, the hour of Terrestrial Time nearest greatest eclipse.
+/// * `delta_t_seconds` -- Delta T used to convert to Universal Time.
+/// * `x` -- Shadow axis x coordinate in the fundamental plane (Earth radii).
+/// * `y` -- Shadow axis y coordinate in the fundamental plane (Earth radii).
+/// * `d` -- Declination of the shadow axis (degrees).
+/// * `mu` -- Greenwich hour angle of the shadow axis (degrees).
+/// * `l1` -- Radius of the penumbral cone in the fundamental plane (Earth radii).
+/// * `l2` -- Radius of the umbral cone in the fundamental plane (Earth radii, negative for a total eclipse).
+/// * `tan_f1` -- Tangent of the penumbral cone half-angle.
+/// * `tan_f2` -- Tangent of the umbral cone half-angle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BesselianElements {
+    pub t0_jd_tt: f64,
+    pub delta_t_seconds: f64,
+    pub x: [f64; 4],
+    pub y: [f64; 4],
+    pub d: [f64; 4],
+    pub mu: [f64; 4],
+    pub l1: [f64; 4],
+    pub l2: [f64; 4],
+    pub tan_f1: f64,
+    pub tan_f2: f64,
+}
+
+/// Ground track of a solar eclipse. Lines are lists of (longitude, latitude) in degrees, with
+/// longitude positive east:
+/// * `central_line` -- Path of the shadow axis.
+/// * `northern_umbral_limit` -- Northern limit of the umbral (or antumbral) shadow.
+/// * `southern_umbral_limit` -- Southern limit of the umbral (or antumbral) shadow.
+/// * `northern_penumbral_limit` -- Northern limit of the penumbral shadow.
+/// * `southern_penumbral_limit` -- Southern limit of the penumbral shadow.
+/// * `greatest_eclipse_point` -- Point of greatest eclipse (longitude, latitude).
+/// * `greatest_eclipse_jd_tt` -- Julian date of greatest eclipse (Terrestrial Time).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolarEclipsePath {
+    pub central_line: Vec<(f64, f64)>,
+    pub northern_umbral_limit: Vec<(f64, f64)>,
+    pub southern_umbral_limit: Vec<(f64, f64)>,
+    pub northern_penumbral_limit: Vec<(f64, f64)>,
+    pub southern_penumbral_limit: Vec<(f64, f64)>,
+    pub greatest_eclipse_point: (f64, f64),
+    pub greatest_eclipse_jd_tt: f64,
+}
+
+/// Squared eccentricity of the Earth's meridian ellipse.
+const EARTH_E2: f64 = 0.00669438;
+
+/// Calculate the Besselian elements of a solar eclipse.
+///
+/// The elements are fitted to positions of the Sun and Moon over six hours centred on the hour
+/// nearest greatest eclipse, and are valid over that interval.
+///
+/// ## Arguments
+/// * `greatest_eclipse_jd_tt` -- Approximate Julian date of greatest eclipse (Terrestrial Time), e.g. from `eclipse_search`.
+///
+/// ## Returns
+/// BesselianElements structure.
+pub fn besselian_elements(greatest_eclipse_jd_tt: f64) -> BesselianElements {
+    let t0_jd_tt = ((greatest_eclipse_jd_tt - 0.5) * 24.0).round() / 24.0 + 0.5;
+    let sample_hours: Vec<f64> = (-3..=3).map(|hour| hour as f64).collect();
+    let samples: Vec<[f64; 8]> = sample_hours
+        .iter()
+        .map(|hours| besselian_elements_at_instant(t0_jd_tt + hours / 24.0))
+        .collect();
+
+    // Unwrap the hour angle so that it can be fitted by a polynomial.
+    let mut mu_samples: Vec<f64> = samples.iter().map(|sample| sample[3]).collect();
+    for i in 1..mu_samples.len() {
+        while mu_samples[i] < mu_samples[i - 1] {
+            mu_samples[i] += 360.0;
+        }
+    }
+
+    let column = |index: usize| -> Vec<f64> { samples.iter().map(|s| s[index]).collect() };
+
+    let gd = pa_m::jdc_day(t0_jd_tt);
+    let gm = pa_m::jdc_month(t0_jd_tt);
+    let gy = pa_m::jdc_year(t0_jd_tt);
+
+    return BesselianElements {
+        t0_jd_tt,
+        delta_t_seconds: pa_dt::delta_t(gd, gm, gy),
+        x: fit_cubic(&sample_hours, &column(0)),
+        y: fit_cubic(&sample_hours, &column(1)),
+        d: fit_cubic(&sample_hours, &column(2)),
+        mu: fit_cubic(&sample_hours, &mu_samples),
+        l1: fit_cubic(&sample_hours, &column(4)),
+        l2: fit_cubic(&sample_hours, &column(5)),
+        tan_f1: samples[3][6],
+        tan_f2: samples[3][7],
+    };
+}
+
+/// Evaluate Besselian elements at a time.
+///
+/// ## Arguments
+/// * `besselian_elements` -- Elements from `besselian_elements`.
+/// * `t_hours` -- Hours of Terrestrial Time from `t0_jd_tt`.
+///
+/// ## Returns
+/// * x, y (Earth radii)
+/// * d (degrees)
+/// * mu (degrees, 0 to 360)
+/// * l1, l2 (Earth radii)
+pub fn evaluate_besselian_elements(
+    besselian_elements: &BesselianElements,
+    t_hours: f64,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let poly = |c: &[f64; 4]| c[0] + t_hours * (c[1] + t_hours * (c[2] + t_hours * c[3]));
+    let mu = poly(&besselian_elements.mu);
+
+    return (
+        poly(&besselian_elements.x),
+        poly(&besselian_elements.y),
+        poly(&besselian_elements.d),
+        mu - 360.0 * (mu / 360.0).floor(),
+        poly(&besselian_elements.l1),
+        poly(&besselian_elements.l2),
+    );
+}
+
+/// Calculate the central line, umbral and penumbral limits, and point of greatest eclipse.
+///
+/// ## Arguments
+/// * `besselian_elements` -- Elements from `besselian_elements`.
+/// * `step_minutes` -- Time between points along each line.
+///
+/// ## Returns
+/// SolarEclipsePath structure.
+pub fn solar_eclipse_path(
+    besselian_elements: &BesselianElements,
+    step_minutes: f64,
+) -> SolarEclipsePath {
+    let mut central_line: Vec<(f64, f64)> = Vec::new();
+    let mut northern_umbral_limit: Vec<(f64, f64)> = Vec::new();
+    let mut southern_umbral_limit: Vec<(f64, f64)> = Vec::new();
+    let mut northern_penumbral_limit: Vec<(f64, f64)> = Vec::new();
+    let mut southern_penumbral_limit: Vec<(f64, f64)> = Vec::new();
+
+    let steps = (360.0 / step_minutes).floor() as i32;
+    for step in 0..=steps {
+        let t = -3.0 + step as f64 * step_minutes / 60.0;
+        let (x, y, _d, _mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+
+        if let Some(point) = shadow_ground_point(besselian_elements, t, x, y) {
+            central_line.push(geographic_position(&point));
+        }
+
+        let (north, south) = shadow_limits(besselian_elements, t, true);
+        if let Some(point) = north {
+            northern_umbral_limit.push(point);
+        }
+        if let Some(point) = south {
+            southern_umbral_limit.push(point);
+        }
+
+        let (north, south) = shadow_limits(besselian_elements, t, false);
+        if let Some(point) = north {
+            northern_penumbral_limit.push(point);
+        }
+        if let Some(point) = south {
+            southern_penumbral_limit.push(point);
+        }
+    }
+
+    // Greatest eclipse: the instant the shadow axis passes closest to the Earth's center.
+    let axis_distance = |t: f64| {
+        let (x, y, _d, _mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+        (x * x + y * y).sqrt()
+    };
+    let mut low = -3.0;
+    let mut high = 3.0;
+    for _ in 0..100 {
+        let m1 = low + (high - low) / 3.0;
+        let m2 = high - (high - low) / 3.0;
+        if axis_distance(m1) < axis_distance(m2) {
+            high = m2;
+        } else {
+            low = m1;
+        }
+    }
+    let t_greatest = (low + high) / 2.0;
+    let (x, y, _d, _mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t_greatest);
+    let greatest_point = match shadow_ground_point(besselian_elements, t_greatest, x, y) {
+        Some(point) => point,
+        None => {
+            // Axis misses the Earth: use the point on the limb nearest to it.
+            let r = (x * x + y * y).sqrt();
+            shadow_ground_point(
+                besselian_elements,
+                t_greatest,
+                x / r * 0.99999,
+                y / r * 0.99999,
+            )
+            .unwrap()
+        }
+    };
+
+    return SolarEclipsePath {
+        central_line,
+        northern_umbral_limit,
+        southern_umbral_limit,
+        northern_penumbral_limit,
+        southern_penumbral_limit,
+        greatest_eclipse_point: geographic_position(&greatest_point),
+        greatest_eclipse_jd_tt: besselian_elements.t0_jd_tt + t_greatest / 24.0,
+    };
+}
+
+/// Format a solar eclipse path as a GeoJSON FeatureCollection.
+///
+/// Each line becomes a LineString feature and the point of greatest eclipse a Point feature,
+/// with a `name` property identifying it. Empty lines are left out.
+pub fn solar_eclipse_path_to_geojson(solar_eclipse_path: &SolarEclipsePath) -> String {
+    let mut features: Vec<String> = Vec::new();
+
+    let lines = [
+        ("Central line", &solar_eclipse_path.central_line),
+        (
+            "Northern umbral limit",
+            &solar_eclipse_path.northern_umbral_limit,
+        ),
+        (
+            "Southern umbral limit",
+            &solar_eclipse_path.southern_umbral_limit,
+        ),
+        (
+            "Northern penumbral limit",
+            &solar_eclipse_path.northern_penumbral_limit,
+        ),
+        (
+            "Southern penumbral limit",
+            &solar_eclipse_path.southern_penumbral_limit,
+        ),
+    ];
+
+    for (name, line) in lines.iter() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let coordinates: Vec<String> = line
+            .iter()
+            .map(|(longitude, latitude)| format!("[{:.4},{:.4}]", longitude, latitude))
+            .collect();
+
+        features.push(format!(
+            "{{\"type\":\"Feature\",\"properties\":{{\"name\":\"{}\"}},\"geometry\":{{\"type\":\"LineString\",\"coordinates\":[{}]}}}}",
+            name,
+            coordinates.join(",")
+        ));
+    }
+
+    let (longitude, latitude) = solar_eclipse_path.greatest_eclipse_point;
+    features.push(format!(
+        "{{\"type\":\"Feature\",\"properties\":{{\"name\":\"Greatest eclipse\"}},\"geometry\":{{\"type\":\"Point\",\"coordinates\":[{:.4},{:.4}]}}}}",
+        longitude, latitude
+    ));
+
+    return format!(
+        "{{\"type\":\"FeatureCollection\",\"features\":[{}]}}",
+        features.join(",")
+    );
+}
+
+/// Raw Besselian elements at one instant: x, y, d, mu, l1, l2, tan f1, tan f2.
+fn besselian_elements_at_instant(jd_tt: f64) -> [f64; 8] {
+    let earth_radius_km = 6378.137;
+    let sun_radius = 696000.0 / earth_radius_km;
+    let moon_radius_penumbra = 0.2725076;
+    let moon_radius_umbra = 0.272281;
+
+    let (sun, moon) = sun_and_moon_position(jd_tt);
+    let g = pa_f::Vector3 {
+        x: sun.x - moon.x,
+        y: sun.y - moon.y,
+        z: sun.z - moon.z,
+    };
+    let g_length = (g.x * g.x + g.y * g.y + g.z * g.z).sqrt();
+    let a = g.y.atan2(g.x);
+    let d = (g.z / g_length).asin();
+
+    let x = -moon.x * a.sin() + moon.y * a.cos();
+    let y = -moon.x * d.sin() * a.cos() - moon.y * d.sin() * a.sin() + moon.z * d.cos();
+    let z = moon.x * d.cos() * a.cos() + moon.y * d.cos() * a.sin() + moon.z * d.sin();
+
+    let f1 = ((sun_radius + moon_radius_penumbra) / g_length).asin();
+    let f2 = ((sun_radius - moon_radius_umbra) / g_length).asin();
+    let l1 = z * f1.tan() + moon_radius_penumbra / f1.cos();
+    let l2 = z * f2.tan() - moon_radius_umbra / f2.cos();
+
+    let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+    let gast_hours = apparent_sidereal_time_hours(jd_ut);
+    let mu = gast_hours * 15.0 - a.to_degrees();
+
+    return [
+        x,
+        y,
+        d.to_degrees(),
+        mu - 360.0 * (mu / 360.0).floor(),
+        l1,
+        l2,
+        f1.tan(),
+        f2.tan(),
+    ];
+}
+
+/// Greenwich apparent sidereal time (hours) at a Julian date (UT).
+pub(crate) fn apparent_sidereal_time_hours(jd_ut: f64) -> f64 {
+    let gd = pa_m::jdc_day(jd_ut);
+    let gm = pa_m::jdc_month(jd_ut);
+    let gy = pa_m::jdc_year(jd_ut);
+
+    return pa_dt::greenwich_mean_sidereal_time_hours(
+        0.0,
+        0.0,
+        0.0,
+        gd,
+        gm,
+        gy,
+        pa_t::SiderealTimeModel::Iau2006,
+    ) + pa_c::equation_of_the_equinoxes(gd, gm, gy, pa_t::NutationModel::Iau2000B) / 3600.0;
+}
+
+/// Geocentric apparent equatorial positions of the Sun and Moon (Earth radii).
+pub(crate) fn sun_and_moon_position(jd_tt: f64) -> (pa_f::Vector3, pa_f::Vector3) {
+    let earth_radius_km = 6378.137;
+    let au_km = 149597870.7;
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    let obliquity_deg = pa_m::obliq(gd, gm, gy);
+    // Apparent longitude of the Sun, reduced like the Moon's: nutation, and aberration.
+    let sun_long_deg =
+        pa_m::sun_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy) + pa_m::nutat_long(gd, gm, gy) - 0.005694;
+    let sun_dist = pa_m::sun_dist(hours, 0.0, 0.0, 0, 0, gd, gm, gy) * au_km / earth_radius_km;
+    let moon_long_deg =
+        pa_m::moon_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy) + pa_m::nutat_long(gd, gm, gy);
+    let moon_lat_deg = pa_m::moon_lat(hours, 0.0, 0.0, 0, 0, gd, gm, gy);
+    let moon_dist = pa_m::moon_dist(hours, 0.0, 0.0, 0, 0, gd, gm, gy) / earth_radius_km;
+
+    let to_equatorial = |long_deg: f64, lat_deg: f64, distance: f64| {
+        let ecliptic = pa_f::spherical_to_vector(long_deg, lat_deg);
+        let equatorial = pa_f::rotate_vector(&pa_f::rotation_matrix_x(-obliquity_deg), &ecliptic);
+        pa_f::Vector3 {
+            x: equatorial.x * distance,
+            y: equatorial.y * distance,
+            z: equatorial.z * distance,
+        }
+    };
+
+    return (
+        to_equatorial(sun_long_deg, 0.0, sun_dist),
+        to_equatorial(moon_long_deg, moon_lat_deg, moon_dist),
+    );
+}
+
+/// Least squares cubic through a set of samples.
+fn fit_cubic(ts: &[f64], values: &[f64]) -> [f64; 4] {
+    let mut matrix = [[0.0; 5]; 4];
+
+    for (t, value) in ts.iter().zip(values.iter()) {
+        let powers = [1.0, *t, t * t, t * t * t];
+        for (row, row_power) in powers.iter().enumerate() {
+            for (column, column_power) in powers.iter().enumerate() {
+                matrix[row][column] += row_power * column_power;
+            }
+            matrix[row][4] += row_power * value;
+        }
+    }
+
+    // Gaussian elimination (the normal equations are positive definite).
+    for pivot in 0..4 {
+        for row in (pivot + 1)..4 {
+            let pivot_row = matrix[pivot];
+            let factor = matrix[row][pivot] / pivot_row[pivot];
+            for (element, pivot_element) in matrix[row].iter_mut().zip(pivot_row.iter()).skip(pivot)
+            {
+                *element -= factor * pivot_element;
+            }
+        }
+    }
+
+    let mut coefficients = [0.0; 4];
+    for row in (0..4).rev() {
+        let known: f64 = ((row + 1)..4)
+            .map(|column| matrix[row][column] * coefficients[column])
+            .sum();
+        coefficients[row] = (matrix[row][4] - known) / matrix[row][row];
+    }
+
+    return coefficients;
+}
+
+/// Fundamental plane unit vectors (i, j, k) in Earth-fixed coordinates, for axis declination
+/// `d` and Greenwich hour angle `mu` (degrees).
+fn fundamental_plane_axes(d_deg: f64, mu_deg: f64) -> [pa_f::Vector3; 3] {
+    let (sin_d, cos_d) = d_deg.to_radians().sin_cos();
+    let (sin_mu, cos_mu) = mu_deg.to_radians().sin_cos();
+
+    return [
+        pa_f::Vector3 {
+            x: sin_mu,
+            y: cos_mu,
+            z: 0.0,
+        },
+        pa_f::Vector3 {
+            x: -sin_d * cos_mu,
+            y: sin_d * sin_mu,
+            z: cos_d,
+        },
+        pa_f::Vector3 {
+            x: cos_d * cos_mu,
+            y: -cos_d * sin_mu,
+            z: sin_d,
+        },
+    ];
+}
+
+/// Earth-fixed point (Earth radii) where the line through (xi, eta) parallel to the shadow axis
+/// meets the sunward side of the Earth's surface, if it does.
+fn shadow_ground_point(
+    besselian_elements: &BesselianElements,
+    t: f64,
+    xi: f64,
+    eta: f64,
+) -> Option<pa_f::Vector3> {
+    let (_x, _y, d, mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+
+    return fundamental_plane_ground_point(d, mu, xi, eta);
+}
+
+/// Earth-fixed point (Earth radii) where the line through (xi, eta) in the fundamental plane of
+/// an axis with declination `d_deg` and Greenwich hour angle `mu_deg` meets the side of the
+/// Earth's surface facing along the axis, if it does.
+pub(crate) fn fundamental_plane_ground_point(
+    d_deg: f64,
+    mu_deg: f64,
+    xi: f64,
+    eta: f64,
+) -> Option<pa_f::Vector3> {
+    let [i, j, k] = fundamental_plane_axes(d_deg, mu_deg);
+
+    let base = pa_f::Vector3 {
+        x: xi * i.x + eta * j.x,
+        y: xi * i.y + eta * j.y,
+        z: xi * i.z + eta * j.z,
+    };
+    let polar_factor = 1.0 / (1.0 - EARTH_E2);
+    let qa = k.x * k.x + k.y * k.y + k.z * k.z * polar_factor;
+    let qb = 2.0 * (base.x * k.x + base.y * k.y + base.z * k.z * polar_factor);
+    let qc = base.x * base.x + base.y * base.y + base.z * base.z * polar_factor - 1.0;
+    let discriminant = qb * qb - 4.0 * qa * qc;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let s = (-qb + discriminant.sqrt()) / (2.0 * qa);
+
+    return Some(pa_f::Vector3 {
+        x: base.x + s * k.x,
+        y: base.y + s * k.y,
+        z: base.z + s * k.z,
+    });
+}
+
+/// Geodetic longitude (east positive) and latitude (degrees) of an Earth-fixed point.
+pub(crate) fn geographic_position(point: &pa_f::Vector3) -> (f64, f64) {
+    let longitude_deg = point.y.atan2(point.x).to_degrees();
+    let latitude_deg = (point.z
+        / ((1.0 - EARTH_E2) * (point.x * point.x + point.y * point.y).sqrt()))
+    .atan()
+    .to_degrees();
+
+    return (longitude_deg, latitude_deg);
+}
+
+/// Northern and southern limits of the umbral or penumbral shadow at a time.
+///
+/// A limit point lies on the shadow's edge, at the place where a fixed observer is just grazed
+/// by the edge: its distance from the edge has a stationary value in time.
+fn shadow_limits(
+    besselian_elements: &BesselianElements,
+    t: f64,
+    is_umbra: bool,
+) -> (Option<(f64, f64)>, Option<(f64, f64)>) {
+    let tan_f = if is_umbra {
+        besselian_elements.tan_f2
+    } else {
+        besselian_elements.tan_f1
+    };
+
+    // Shadow edge point at position angle q (radians) on the edge.
+    let edge_point = |q: f64| -> Option<pa_f::Vector3> {
+        let (x, y, _d, _mu, l1, l2) = evaluate_besselian_elements(besselian_elements, t);
+        let l = if is_umbra { l2 } else { l1 };
+        let mut zeta = 0.0;
+        let mut point = None;
+        for _ in 0..4 {
+            let radius = l - zeta * tan_f;
+            point = shadow_ground_point(
+                besselian_elements,
+                t,
+                x - radius * q.sin(),
+                y - radius * q.cos(),
+            );
+            match point {
+                Some(p) => {
+                    let (_x, _y, d, mu, _l1, _l2) =
+                        evaluate_besselian_elements(besselian_elements, t);
+                    let [_i, _j, k] = fundamental_plane_axes(d, mu);
+                    zeta = p.x * k.x + p.y * k.y + p.z * k.z;
+                }
+                None => return None,
+            }
+        }
+        return point;
+    };
+
+    // Signed distance of a fixed ground point inside the shadow edge at time t.
+    let depth = |point: &pa_f::Vector3, t: f64| -> f64 {
+        let (x, y, d, mu, l1, l2) = evaluate_besselian_elements(besselian_elements, t);
+        let [i, j, k] = fundamental_plane_axes(d, mu);
+        let xi = point.x * i.x + point.y * i.y + point.z * i.z;
+        let eta = point.x * j.x + point.y * j.y + point.z * j.z;
+        let zeta = point.x * k.x + point.y * k.y + point.z * k.z;
+        let l = if is_umbra { l2 } else { l1 };
+        (l - zeta * tan_f).abs() - ((xi - x) * (xi - x) + (eta - y) * (eta - y)).sqrt()
+    };
+
+    let rate = |q: f64| -> Option<f64> {
+        let point = edge_point(q)?;
+        Some(depth(&point, t + 0.01) - depth(&point, t - 0.01))
+    };
+
+    let (_x, y, _d, _mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+    let mut north = None;
+    let mut south = None;
+    let samples = 72;
+
+    for sample in 0..samples {
+        let mut q_low = (sample as f64) * 360.0 / samples as f64;
+        let mut q_high = q_low + 360.0 / samples as f64;
+        let (rate_low, rate_high) = match (rate(q_low.to_radians()), rate(q_high.to_radians())) {
+            (Some(r1), Some(r2)) => (r1, r2),
+            _ => continue,
+        };
+        if rate_low.signum() == rate_high.signum() {
+            continue;
+        }
+
+        let mut sign_low = rate_low.signum();
+        for _ in 0..30 {
+            let q_mid = (q_low + q_high) / 2.0;
+            match rate(q_mid.to_radians()) {
+                Some(r) if r.signum() == sign_low => {
+                    q_low = q_mid;
+                    sign_low = r.signum();
+                }
+                Some(_) => q_high = q_mid,
+                None => break,
+            }
+        }
+
+        // A grazing point is one the shadow edge reaches and then recedes from, so the depth
+        // of the point reaches a maximum (zero) at this instant.
+        let q = ((q_low + q_high) / 2.0).to_radians();
+        if let Some(point) = edge_point(q) {
+            let before = depth(&point, t - 0.05);
+            let after = depth(&point, t + 0.05);
+            if before > 0.0 || after > 0.0 {
+                continue;
+            }
+
+            let (_x2, _y2, d, mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+            let [_i, j, _k] = fundamental_plane_axes(d, mu);
+            let eta = point.x * j.x + point.y * j.y + point.z * j.z;
+            if eta > y {
+                north = Some(geographic_position(&point));
+            } else {
+                south = Some(geographic_position(&point));
+            }
+        }
+    }
+
+    return (north, south);
+}
+
+/// Info about one contact (or maximum) of a solar eclipse seen from a location:
+/// * `jd_ut` -- Julian date of the contact (Universal Time).
+/// * `ut_hour` -- Time of the contact (hour, Universal Time).
+/// * `ut_minutes` -- Time of the contact (minutes, Universal Time).
+/// * `ut_seconds` -- Time of the contact (seconds, Universal Time).
+/// * `sun_altitude_deg` -- Geometric altitude of the Sun.
+/// * `sun_azimuth_deg` -- Azimuth of the Sun, measured from north through east.
+/// * `position_angle_deg` -- Position angle of the contact point on the Sun's limb, measured from north through east.
+/// * `vertex_angle_deg` -- Position angle of the contact point measured from the zenith.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarEclipseContact {
+    pub jd_ut: f64,
+    pub ut_hour: u32,
+    pub ut_minutes: u32,
+    pub ut_seconds: f64,
+    pub sun_altitude_deg: f64,
+    pub sun_azimuth_deg: f64,
+    pub position_angle_deg: f64,
+    pub vertex_angle_deg: f64,
+}
+
+/// Info about a solar eclipse seen from a location:
+/// * `local_eclipse_type` -- Partial, total, or annular, as seen from the location.
+/// * `first_contact` -- Start of the partial phase.
+/// * `second_contact` -- Start of totality or annularity (None for a partial eclipse).
+/// * `maximum_eclipse` -- Maximum eclipse.
+/// * `third_contact` -- End of totality or annularity (None for a partial eclipse).
+/// * `fourth_contact` -- End of the partial phase.
+/// * `magnitude` -- Fraction of the Sun's diameter covered at maximum eclipse.
+/// * `obscuration` -- Fraction of the Sun's disk area covered at maximum eclipse.
+/// * `central_duration_seconds` -- Duration of totality or annularity (0 for a partial eclipse).
+/// * `begins_below_horizon` -- The eclipse is already in progress at sunrise.
+/// * `ends_below_horizon` -- The eclipse is still in progress at sunset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalSolarEclipseCircumstances {
+    pub local_eclipse_type: pa_t::EclipseType,
+    pub first_contact: SolarEclipseContact,
+    pub second_contact: Option<SolarEclipseContact>,
+    pub maximum_eclipse: SolarEclipseContact,
+    pub third_contact: Option<SolarEclipseContact>,
+    pub fourth_contact: SolarEclipseContact,
+    pub magnitude: f64,
+    pub obscuration: f64,
+    pub central_duration_seconds: f64,
+    pub begins_below_horizon: bool,
+    pub ends_below_horizon: bool,
+}
+
+/// Calculate the circumstances of a solar eclipse for an observer, from its Besselian elements.
+///
+/// Sunrise and sunset are taken as the Sun's upper limb on the horizon, with standard refraction.
+///
+/// ## Arguments
+/// * `besselian_elements` -- Elements from `besselian_elements`.
+/// * `geog_longitude_deg` -- Geographical longitude of observer (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of observer.
+/// * `height_m` -- Height of observer above sea level, in metres.
+///
+/// ## Returns
+/// LocalSolarEclipseCircumstances structure, or None if the eclipse is not seen from the location
+/// (ignoring whether the Sun is above the horizon).
+pub fn local_solar_eclipse_circumstances(
+    besselian_elements: &BesselianElements,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) -> Option<LocalSolarEclipseCircumstances> {
+    let observer = (geog_longitude_deg, geog_latitude_deg, height_m);
+
+    // Distance of the observer inside the penumbral (index 0) or umbral (index 1) edge.
+    let penumbral_depth = |t: f64| {
+        let (_xi, _eta, _zeta, m, l1, _l2) =
+            observer_shadow_coordinates(besselian_elements, &observer, t);
+        l1 - m
+    };
+    let umbral_depth = |t: f64| {
+        let (_xi, _eta, _zeta, m, _l1, l2) =
+            observer_shadow_coordinates(besselian_elements, &observer, t);
+        l2.abs() - m
+    };
+    let distance = |t: f64| {
+        let (_xi, _eta, _zeta, m, _l1, _l2) =
+            observer_shadow_coordinates(besselian_elements, &observer, t);
+        m
+    };
+
+    let (first_t, fourth_t) = crossing_times(&penumbral_depth, -3.0, 3.0)?;
+
+    let mut low = first_t;
+    let mut high = fourth_t;
+    for _ in 0..100 {
+        let m1 = low + (high - low) / 3.0;
+        let m2 = high - (high - low) / 3.0;
+        if distance(m1) < distance(m2) {
+            high = m2;
+        } else {
+            low = m1;
+        }
+    }
+    let maximum_t = (low + high) / 2.0;
+
+    let (_xi, _eta, _zeta, m, l1, l2) =
+        observer_shadow_coordinates(besselian_elements, &observer, maximum_t);
+    let central = if umbral_depth(maximum_t) > 0.0 {
+        crossing_times(&umbral_depth, first_t, fourth_t)
+    } else {
+        None
+    };
+
+    let local_eclipse_type = match central {
+        Some(_) if l2 < 0.0 => pa_t::EclipseType::Total,
+        Some(_) => pa_t::EclipseType::Annular,
+        None => pa_t::EclipseType::Partial,
+    };
+
+    // Sun and Moon radii in the observer's plane, and their separation.
+    let sun_radius = (l1 + l2) / 2.0;
+    let moon_radius = (l1 - l2) / 2.0;
+    let magnitude = (l1 - m) / (l1 + l2);
+    let obscuration = disk_overlap_fraction(sun_radius, moon_radius, m);
+
+    let contact = |t: f64, is_internal: bool| {
+        solar_eclipse_contact(besselian_elements, &observer, t, is_internal && l2 < 0.0)
+    };
+
+    let first_contact = contact(first_t, false);
+    let fourth_contact = contact(fourth_t, false);
+    let horizon_altitude_deg = -0.8333333;
+
+    return Some(LocalSolarEclipseCircumstances {
+        local_eclipse_type,
+        first_contact,
+        second_contact: central.map(|(second_t, _third_t)| contact(second_t, true)),
+        maximum_eclipse: contact(maximum_t, false),
+        third_contact: central.map(|(_second_t, third_t)| contact(third_t, true)),
+        fourth_contact,
+        magnitude,
+        obscuration,
+        central_duration_seconds: match central {
+            Some((second_t, third_t)) => (third_t - second_t) * 3600.0,
+            None => 0.0,
+        },
+        begins_below_horizon: first_contact.sun_altitude_deg < horizon_altitude_deg,
+        ends_below_horizon: fourth_contact.sun_altitude_deg < horizon_altitude_deg,
+    });
+}
+
+/// Observer's coordinates in the fundamental plane at a time, with the distance from the shadow
+/// axis and the shadow radii at the observer: xi, eta, zeta, m, L1', L2'.
+fn observer_shadow_coordinates(
+    besselian_elements: &BesselianElements,
+    observer: &(f64, f64, f64),
+    t: f64,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let (x, y, d, mu, l1, l2) = evaluate_besselian_elements(besselian_elements, t);
+    let (xi, eta, zeta) = observer_fundamental_plane_coordinates(observer, d, mu);
+
+    let m = ((x - xi) * (x - xi) + (y - eta) * (y - eta)).sqrt();
+
+    return (
+        xi,
+        eta,
+        zeta,
+        m,
+        l1 - zeta * besselian_elements.tan_f1,
+        l2 - zeta * besselian_elements.tan_f2,
+    );
+}
+
+/// Observer's coordinates (xi, eta, zeta) in the fundamental plane of an axis with declination
+/// `d_deg` and Greenwich hour angle `mu_deg`. The observer is (longitude east, latitude, height in
+/// metres).
+pub(crate) fn observer_fundamental_plane_coordinates(
+    observer: &(f64, f64, f64),
+    d_deg: f64,
+    mu_deg: f64,
+) -> (f64, f64, f64) {
+    let (longitude_deg, latitude_deg, height_m) = *observer;
+
+    let latitude = latitude_deg.to_radians();
+    let u = ((1.0 - EARTH_E2).sqrt() * latitude.tan()).atan();
+    let rho_sin_phi = (1.0 - EARTH_E2).sqrt() * u.sin() + height_m / 6378137.0 * latitude.sin();
+    let rho_cos_phi = u.cos() + height_m / 6378137.0 * latitude.cos();
+
+    let hour_angle = (mu_deg + longitude_deg).to_radians();
+    let d = d_deg.to_radians();
+    let xi = rho_cos_phi * hour_angle.sin();
+    let eta = rho_sin_phi * d.cos() - rho_cos_phi * hour_angle.cos() * d.sin();
+    let zeta = rho_sin_phi * d.sin() + rho_cos_phi * hour_angle.cos() * d.cos();
+
+    return (xi, eta, zeta);
+}
+
+/// First and last times in an interval where a function rises through and falls back through
+/// zero, if it is positive anywhere in the interval.
+pub(crate) fn crossing_times(
+    function: &dyn Fn(f64) -> f64,
+    start: f64,
+    end: f64,
+) -> Option<(f64, f64)> {
+    let step = 1.0 / 60.0;
+    let steps = ((end - start) / step).ceil() as i32;
+    let mut first: Option<f64> = None;
+    let mut last: Option<f64> = None;
+    let mut previous_t = start;
+    let mut previous_value = function(start);
+
+    for i in 1..=steps {
+        let t = (start + i as f64 * step).min(end);
+        let value = function(t);
+
+        if (previous_value <= 0.0) != (value <= 0.0) {
+            let mut low = previous_t;
+            let mut high = t;
+            for _ in 0..50 {
+                let middle = (low + high) / 2.0;
+                if (function(middle) <= 0.0) == (previous_value <= 0.0) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            if value > 0.0 {
+                if first.is_none() {
+                    first = Some((low + high) / 2.0);
+                }
+            } else {
+                last = Some((low + high) / 2.0);
+            }
+        }
+
+        previous_t = t;
+        previous_value = value;
+    }
+
+    return match (first, last) {
+        (Some(first), Some(last)) => Some((first, last)),
+        _ => None,
+    };
+}
+
+/// Fraction of a disk of radius `r1` covered by a disk of radius `r2`, with centers `separation` apart.
+fn disk_overlap_fraction(r1: f64, r2: f64, separation: f64) -> f64 {
+    if separation >= r1 + r2 {
+        return 0.0;
+    }
+    if separation <= (r2 - r1).abs() {
+        return if r2 >= r1 { 1.0 } else { (r2 * r2) / (r1 * r1) };
+    }
+
+    let a1 = ((separation * separation + r1 * r1 - r2 * r2) / (2.0 * separation * r1)).acos();
+    let a2 = ((separation * separation + r2 * r2 - r1 * r1) / (2.0 * separation * r2)).acos();
+    let area = r1 * r1 * (a1 - a1.sin() * a1.cos()) + r2 * r2 * (a2 - a2.sin() * a2.cos());
+
+    return area / (std::f64::consts::PI * r1 * r1);
+}
+
+/// Circumstances at one contact: time, Sun position and contact position angles.
+///
+/// `is_far_side` puts the contact point on the side of the Sun away from the Moon's center, as at
+/// the internal contacts of a total eclipse.
+fn solar_eclipse_contact(
+    besselian_elements: &BesselianElements,
+    observer: &(f64, f64, f64),
+    t: f64,
+    is_far_side: bool,
+) -> SolarEclipseContact {
+    let (longitude_deg, latitude_deg, _height_m) = *observer;
+    let (x, y, d, mu, _l1, _l2) = evaluate_besselian_elements(besselian_elements, t);
+    let (xi, eta, _zeta, _m, _l1, _l2) =
+        observer_shadow_coordinates(besselian_elements, observer, t);
+
+    let jd_ut =
+        besselian_elements.t0_jd_tt + t / 24.0 - besselian_elements.delta_t_seconds / 86400.0;
+    let day = pa_m::jdc_day(jd_ut);
+    let ut_hours = (day - day.floor()) * 24.0;
+
+    let hour_angle = (mu + longitude_deg).to_radians();
+    let d = d.to_radians();
+    let latitude = latitude_deg.to_radians();
+    let sun_altitude_deg = (latitude.sin() * d.sin() + latitude.cos() * d.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees();
+    let sun_azimuth_deg = pa_m::unwind_deg(
+        (-d.cos() * hour_angle.sin())
+            .atan2(d.sin() * latitude.cos() - d.cos() * hour_angle.cos() * latitude.sin())
+            .to_degrees(),
+    );
+
+    let moon_position_angle_deg = (x - xi).atan2(y - eta).to_degrees();
+    let position_angle_deg = pa_m::unwind_deg(if is_far_side {
+        moon_position_angle_deg + 180.0
+    } else {
+        moon_position_angle_deg
+    });
+    let zenith_angle_deg = xi.atan2(eta).to_degrees();
+
+    return SolarEclipseContact {
+        jd_ut,
+        ut_hour: pa_m::dh_hour(ut_hours),
+        ut_minutes: pa_m::dh_min(ut_hours),
+        ut_seconds: pa_m::dh_sec(ut_hours),
+        sun_altitude_deg,
+        sun_azimuth_deg,
+        position_angle_deg,
+        vertex_angle_deg: pa_m::unwind_deg(position_angle_deg - zenith_angle_deg),
+    };
+}
+
+/// Info about one contact (or greatest eclipse) of a lunar eclipse seen from a location:
+/// * `jd_ut` -- Julian date of the contact (Universal Time).
+/// * `ut_hour` -- Time of the contact (hour, Universal Time).
+/// * `ut_minutes` -- Time of the contact (minutes, Universal Time).
+/// * `ut_seconds` -- Time of the contact (seconds, Universal Time).
+/// * `moon_altitude_deg` -- Topocentric altitude of the Moon's center, without refraction.
+/// * `moon_azimuth_deg` -- Azimuth of the Moon, measured from north through east.
+/// * `is_visible` -- The Moon is above the horizon (allowing for standard refraction).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LunarEclipseContact {
+    pub jd_ut: f64,
+    pub ut_hour: u32,
+    pub ut_minutes: u32,
+    pub ut_seconds: f64,
+    pub moon_altitude_deg: f64,
+    pub moon_azimuth_deg: f64,
+    pub is_visible: bool,
+}
+
+/// Info about a lunar eclipse seen from a location:
+/// * `eclipse_type` -- Total, partial, or penumbral.
+/// * `umbral_magnitude` -- Fraction of the Moon's diameter inside the umbra at greatest eclipse (negative if outside).
+/// * `penumbral_magnitude` -- Fraction of the Moon's diameter inside the penumbra at greatest eclipse.
+/// * `penumbral_start` -- P1, Moon enters the penumbra.
+/// * `partial_start` -- U1, Moon enters the umbra (None for a penumbral eclipse).
+/// * `total_start` -- U2, Moon entirely inside the umbra (None unless total).
+/// * `greatest_eclipse` -- Greatest eclipse.
+/// * `total_end` -- U3, Moon starts to leave the umbra (None unless total).
+/// * `partial_end` -- U4, Moon leaves the umbra (None for a penumbral eclipse).
+/// * `penumbral_end` -- P4, Moon leaves the penumbra.
+/// * `is_penumbral_phase_visible` -- The Moon is above the horizon at some time between P1 and P4.
+/// * `is_partial_phase_visible` -- The Moon is above the horizon at some time between U1 and U4.
+/// * `is_total_phase_visible` -- The Moon is above the horizon at some time between U2 and U3.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalLunarEclipseCircumstances {
+    pub eclipse_type: pa_t::EclipseType,
+    pub umbral_magnitude: f64,
+    pub penumbral_magnitude: f64,
+    pub penumbral_start: LunarEclipseContact,
+    pub partial_start: Option<LunarEclipseContact>,
+    pub total_start: Option<LunarEclipseContact>,
+    pub greatest_eclipse: LunarEclipseContact,
+    pub total_end: Option<LunarEclipseContact>,
+    pub partial_end: Option<LunarEclipseContact>,
+    pub penumbral_end: LunarEclipseContact,
+    pub is_penumbral_phase_visible: bool,
+    pub is_partial_phase_visible: bool,
+    pub is_total_phase_visible: bool,
+}
+
+/// Calculate the magnitudes, contact times and visibility of a lunar eclipse for an observer.
+///
+/// The Earth's shadow is enlarged for the atmosphere either by Chauvenet's rule (shadow radii
+/// increased by 1/50) or Danjon's (Earth's radius increased by 1/85, as used in modern canons; the
+/// factor 1.01 already includes the flattening of the Earth).
+///
+/// ## Arguments
+/// * `greatest_eclipse_jd_tt` -- Approximate Julian date of greatest eclipse (Terrestrial Time), e.g. from `eclipse_search`.
+/// * `geog_longitude_deg` -- Geographical longitude of observer (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of observer.
+/// * `shadow_enlargement` -- Chauvenet or Danjon.
+///
+/// ## Returns
+/// LocalLunarEclipseCircumstances structure, or None if the Moon misses the penumbra.
+pub fn local_lunar_eclipse_circumstances(
+    greatest_eclipse_jd_tt: f64,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    shadow_enlargement: pa_t::ShadowEnlargement,
+) -> Option<LocalLunarEclipseCircumstances> {
+    let start = -6.0;
+    let end = 6.0;
+    let jd_tt = |t: f64| greatest_eclipse_jd_tt + t / 24.0;
+
+    let depth = |t: f64, phase: usize| {
+        let (separation, umbra, penumbra, moon_semidiameter) =
+            lunar_shadow_geometry(jd_tt(t), shadow_enlargement);
+        match phase {
+            0 => penumbra + moon_semidiameter - separation,
+            1 => umbra + moon_semidiameter - separation,
+            _ => umbra - moon_semidiameter - separation,
+        }
+    };
+    let separation = |t: f64| lunar_shadow_geometry(jd_tt(t), shadow_enlargement).0;
+
+    let penumbral = crossing_times(&|t| depth(t, 0), start, end)?;
+    let partial = crossing_times(&|t| depth(t, 1), penumbral.0, penumbral.1);
+    let total = match partial {
+        Some((u1, u4)) => crossing_times(&|t| depth(t, 2), u1, u4),
+        None => None,
+    };
+
+    let mut low = penumbral.0;
+    let mut high = penumbral.1;
+    for _ in 0..100 {
+        let m1 = low + (high - low) / 3.0;
+        let m2 = high - (high - low) / 3.0;
+        if separation(m1) < separation(m2) {
+            high = m2;
+        } else {
+            low = m1;
+        }
+    }
+    let greatest_t = (low + high) / 2.0;
+
+    let (greatest_separation, umbra, penumbra, moon_semidiameter) =
+        lunar_shadow_geometry(jd_tt(greatest_t), shadow_enlargement);
+    let umbral_magnitude =
+        (umbra - greatest_separation + moon_semidiameter) / (2.0 * moon_semidiameter);
+    let penumbral_magnitude =
+        (penumbra - greatest_separation + moon_semidiameter) / (2.0 * moon_semidiameter);
+
+    let eclipse_type = if total.is_some() {
+        pa_t::EclipseType::Total
+    } else if partial.is_some() {
+        pa_t::EclipseType::Partial
+    } else {
+        pa_t::EclipseType::Penumbral
+    };
+
+    let observer = (geog_longitude_deg, geog_latitude_deg);
+    let contact = |t: f64| lunar_eclipse_contact(jd_tt(t), &observer);
+
+    // A phase is visible if the Moon is up at either end, or rises and sets within it.
+    let is_phase_visible = |phase: Option<(f64, f64)>| match phase {
+        Some((phase_start, phase_end)) => {
+            let samples = ((phase_end - phase_start) * 60.0).ceil().max(1.0) as i32;
+            (0..=samples).any(|i| {
+                let t = phase_start + (phase_end - phase_start) * i as f64 / samples as f64;
+                contact(t).is_visible
+            })
+        }
+        None => false,
+    };
+
+    return Some(LocalLunarEclipseCircumstances {
+        eclipse_type,
+        umbral_magnitude,
+        penumbral_magnitude,
+        penumbral_start: contact(penumbral.0),
+        partial_start: partial.map(|(u1, _u4)| contact(u1)),
+        total_start: total.map(|(u2, _u3)| contact(u2)),
+        greatest_eclipse: contact(greatest_t),
+        total_end: total.map(|(_u2, u3)| contact(u3)),
+        partial_end: partial.map(|(_u1, u4)| contact(u4)),
+        penumbral_end: contact(penumbral.1),
+        is_penumbral_phase_visible: is_phase_visible(Some(penumbral)),
+        is_partial_phase_visible: is_phase_visible(partial),
+        is_total_phase_visible: is_phase_visible(total),
+    });
+}
+
+/// Angular separation of the Moon from the shadow axis, umbral and penumbral radii, and the
+/// Moon's semidiameter (all degrees) at a Julian date (Terrestrial Time).
+fn lunar_shadow_geometry(
+    jd_tt: f64,
+    shadow_enlargement: pa_t::ShadowEnlargement,
+) -> (f64, f64, f64, f64) {
+    let (sun, moon) = sun_and_moon_position(jd_tt);
+    let sun_distance = (sun.x * sun.x + sun.y * sun.y + sun.z * sun.z).sqrt();
+    let moon_distance = (moon.x * moon.x + moon.y * moon.y + moon.z * moon.z).sqrt();
+
+    let cos_separation =
+        -(sun.x * moon.x + sun.y * moon.y + sun.z * moon.z) / (sun_distance * moon_distance);
+    let separation = cos_separation.min(1.0).acos().to_degrees();
+
+    let moon_parallax = (1.0 / moon_distance).asin().to_degrees();
+    let sun_parallax = (1.0 / sun_distance).asin().to_degrees();
+    let sun_semidiameter = (696000.0 / 6378.137 / sun_distance).asin().to_degrees();
+    let moon_semidiameter = (0.2725076 / moon_distance).asin().to_degrees();
+
+    let (umbra, penumbra) = match shadow_enlargement {
+        pa_t::ShadowEnlargement::Chauvenet => (
+            1.02 * (0.99834 * moon_parallax - sun_semidiameter + sun_parallax),
+            1.02 * (0.99834 * moon_parallax + sun_semidiameter + sun_parallax),
+        ),
+        pa_t::ShadowEnlargement::Danjon => {
+            let earth_parallax = 1.01 * moon_parallax + sun_parallax;
+            (
+                earth_parallax - sun_semidiameter,
+                earth_parallax + sun_semidiameter,
+            )
+        }
+    };
+
+    return (separation, umbra, penumbra, moon_semidiameter);
+}
+
+/// Time and position of the Moon for an observer at a Julian date (Terrestrial Time).
+fn lunar_eclipse_contact(jd_tt: f64, observer: &(f64, f64)) -> LunarEclipseContact {
+    let (longitude_deg, latitude_deg) = *observer;
+    let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+    let day = pa_m::jdc_day(jd_ut);
+    let ut_hours = (day - day.floor()) * 24.0;
+
+    let (_sun, moon) = sun_and_moon_position(jd_tt);
+    let moon_distance = (moon.x * moon.x + moon.y * moon.y + moon.z * moon.z).sqrt();
+    let (right_ascension_deg, declination_deg) = pa_f::vector_to_spherical(&moon);
+
+    let hour_angle = (apparent_sidereal_time_hours(jd_ut) * 15.0 + longitude_deg
+        - right_ascension_deg)
+        .to_radians();
+    let declination = declination_deg.to_radians();
+    let latitude = latitude_deg.to_radians();
+
+    let geocentric_altitude = (latitude.sin() * declination.sin()
+        + latitude.cos() * declination.cos() * hour_angle.cos())
+    .asin();
+    let moon_altitude_deg = (geocentric_altitude
+        - ((1.0 / moon_distance) * geocentric_altitude.cos()).asin())
+    .to_degrees();
+    let moon_azimuth_deg = pa_m::unwind_deg(
+        (-declination.cos() * hour_angle.sin())
+            .atan2(
+                declination.sin() * latitude.cos()
+                    - declination.cos() * hour_angle.cos() * latitude.sin(),
+            )
+            .to_degrees(),
+    );
+
+    return LunarEclipseContact {
+        jd_ut,
+        ut_hour: pa_m::dh_hour(ut_hours),
+        ut_minutes: pa_m::dh_min(ut_hours),
+        ut_seconds: pa_m::dh_sec(ut_hours),
+        moon_altitude_deg,
+        moon_azimuth_deg,
+        is_visible: moon_altitude_deg > -0.5666667,
+    };
+}
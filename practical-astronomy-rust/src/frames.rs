@@ -0,0 +1,385 @@
+use crate::coordinates as pa_c;
+use crate::datetime as pa_dt;
+use crate::macros as pa_m;
+use crate::types as pa_t;
+
+/// Cartesian vector, usually a unit direction in some reference frame:
+/// * `x` -- Component towards longitude 0, latitude 0.
+/// * `y` -- Component towards longitude 90, latitude 0.
+/// * `z` -- Component towards the pole (latitude 90).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// 3x3 matrix, stored by rows:
+/// * `m` -- Matrix elements, `m[row][column]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix3 {
+    pub m: [[f64; 3]; 3],
+}
+
+/// Location of the observer, needed for the Horizon frame:
+/// * `geographical_longitude_deg` -- Geographical longitude (degrees, east positive).
+/// * `geographical_latitude_deg` -- Geographical latitude (degrees).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Observer {
+    pub geographical_longitude_deg: f64,
+    pub geographical_latitude_deg: f64,
+}
+
+/// Directly connected pairs of reference frames.
+///
+/// Each pair has a rotation from the first frame into the second (see `edge_matrix`); the
+/// opposite direction uses the transpose. A new frame only needs an entry here and in
+/// `edge_matrix` to become reachable from every other frame.
+fn frame_edges() -> Vec<(pa_t::ReferenceFrame, pa_t::ReferenceFrame)> {
+    return vec![
+        (pa_t::ReferenceFrame::Icrs, pa_t::ReferenceFrame::MeanOfDate),
+        (
+            pa_t::ReferenceFrame::MeanOfDate,
+            pa_t::ReferenceFrame::TrueOfDate,
+        ),
+        (
+            pa_t::ReferenceFrame::MeanOfDate,
+            pa_t::ReferenceFrame::EclipticOfDate,
+        ),
+        (
+            pa_t::ReferenceFrame::Icrs,
+            pa_t::ReferenceFrame::EclipticJ2000,
+        ),
+        (pa_t::ReferenceFrame::Icrs, pa_t::ReferenceFrame::Galactic),
+        (
+            pa_t::ReferenceFrame::Galactic,
+            pa_t::ReferenceFrame::Supergalactic,
+        ),
+        (
+            pa_t::ReferenceFrame::TrueOfDate,
+            pa_t::ReferenceFrame::Horizon,
+        ),
+    ];
+}
+
+/// Rotation from the first frame of an edge into the second.
+fn edge_matrix(
+    from_frame: pa_t::ReferenceFrame,
+    to_frame: pa_t::ReferenceFrame,
+    julian_date: f64,
+    observer: &Observer,
+) -> Matrix3 {
+    let gw_day = pa_m::jdc_day(julian_date);
+    let gw_month = pa_m::jdc_month(julian_date);
+    let gw_year = pa_m::jdc_year(julian_date);
+
+    return match (from_frame, to_frame) {
+        (pa_t::ReferenceFrame::Icrs, pa_t::ReferenceFrame::MeanOfDate) => {
+            precession_matrix(julian_date)
+        }
+        (pa_t::ReferenceFrame::MeanOfDate, pa_t::ReferenceFrame::TrueOfDate) => {
+            let mean_obliquity_deg =
+                pa_c::mean_obliquity_of_the_ecliptic(gw_day, gw_month, gw_year);
+            let (nut_in_long_deg, nut_in_obl_deg) =
+                pa_c::nutation_in_ecliptic_longitude_and_obliquity_by_model(
+                    gw_day,
+                    gw_month,
+                    gw_year,
+                    pa_t::NutationModel::Iau2000B,
+                );
+
+            multiply_matrices(
+                &rotation_matrix_x(-(mean_obliquity_deg + nut_in_obl_deg)),
+                &multiply_matrices(
+                    &rotation_matrix_z(-nut_in_long_deg),
+                    &rotation_matrix_x(mean_obliquity_deg),
+                ),
+            )
+        }
+        (pa_t::ReferenceFrame::MeanOfDate, pa_t::ReferenceFrame::EclipticOfDate) => {
+            rotation_matrix_x(pa_c::mean_obliquity_of_the_ecliptic(
+                gw_day, gw_month, gw_year,
+            ))
+        }
+        (pa_t::ReferenceFrame::Icrs, pa_t::ReferenceFrame::EclipticJ2000) => {
+            rotation_matrix_x(23.4392911)
+        }
+        (pa_t::ReferenceFrame::Icrs, pa_t::ReferenceFrame::Galactic) => Matrix3 {
+            m: [
+                [-0.0548755604, -0.8734370902, -0.4838350155],
+                [0.4941094279, -0.4448296300, 0.7469822445],
+                [-0.8676661490, -0.1980763734, 0.4559837762],
+            ],
+        },
+        (pa_t::ReferenceFrame::Galactic, pa_t::ReferenceFrame::Supergalactic) => {
+            // Supergalactic pole at l = 47.37, b = 6.32; zero point at l = 137.37, b = 0.
+            let x_axis = spherical_to_vector(137.37, 0.0);
+            let z_axis = spherical_to_vector(47.37, 6.32);
+            let y_axis = cross_product(&z_axis, &x_axis);
+
+            Matrix3 {
+                m: [
+                    [x_axis.x, x_axis.y, x_axis.z],
+                    [y_axis.x, y_axis.y, y_axis.z],
+                    [z_axis.x, z_axis.y, z_axis.z],
+                ],
+            }
+        }
+        (pa_t::ReferenceFrame::TrueOfDate, pa_t::ReferenceFrame::Horizon) => {
+            let gast_hours = pa_dt::greenwich_mean_sidereal_time_hours(
+                0.0,
+                0.0,
+                0.0,
+                gw_day,
+                gw_month,
+                gw_year,
+                pa_t::SiderealTimeModel::Iau2006,
+            ) + pa_c::equation_of_the_equinoxes(
+                gw_day,
+                gw_month,
+                gw_year,
+                pa_t::NutationModel::Iau2000B,
+            ) / 3600.0;
+            let last_deg = gast_hours * 15.0 + observer.geographical_longitude_deg;
+
+            // Hour angle frame tilted onto the zenith, then flipped so that x points north.
+            let south_east_zenith = multiply_matrices(
+                &rotation_matrix_y(90.0 - observer.geographical_latitude_deg),
+                &rotation_matrix_z(last_deg),
+            );
+
+            multiply_matrices(
+                &Matrix3 {
+                    m: [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                },
+                &south_east_zenith,
+            )
+        }
+        _ => transpose_matrix(&edge_matrix(to_frame, from_frame, julian_date, observer)),
+    };
+}
+
+/// Precession matrix from J2000.0 to the mean equator and equinox of date (IAU 1976 angles).
+///
+/// The small frame bias between ICRS and the J2000.0 mean equator is ignored.
+fn precession_matrix(julian_date: f64) -> Matrix3 {
+    let t = (julian_date - 2451545.0) / 36525.0;
+    let zeta_arcsec = t * (2306.2181 + t * (0.30188 + t * 0.017998));
+    let z_arcsec = t * (2306.2181 + t * (1.09468 + t * 0.018203));
+    let theta_arcsec = t * (2004.3109 + t * (-0.42665 - t * 0.041833));
+
+    return multiply_matrices(
+        &rotation_matrix_z(-z_arcsec / 3600.0),
+        &multiply_matrices(
+            &rotation_matrix_y(theta_arcsec / 3600.0),
+            &rotation_matrix_z(-zeta_arcsec / 3600.0),
+        ),
+    );
+}
+
+/// Find the chain of frames linking two reference frames.
+///
+/// ## Returns
+/// frames visited, starting with `from_frame` and ending with `to_frame` (empty if not connected)
+pub fn frame_path(
+    from_frame: pa_t::ReferenceFrame,
+    to_frame: pa_t::ReferenceFrame,
+) -> Vec<pa_t::ReferenceFrame> {
+    let edges = frame_edges();
+    let mut paths: Vec<Vec<pa_t::ReferenceFrame>> = vec![vec![from_frame]];
+    let mut visited: Vec<pa_t::ReferenceFrame> = vec![from_frame];
+
+    while !paths.is_empty() {
+        let path = paths.remove(0);
+        let last_frame = path[path.len() - 1];
+
+        if last_frame == to_frame {
+            return path;
+        }
+
+        for (edge_a, edge_b) in edges.iter() {
+            let next_frame = if *edge_a == last_frame {
+                *edge_b
+            } else if *edge_b == last_frame {
+                *edge_a
+            } else {
+                continue;
+            };
+
+            if !visited.contains(&next_frame) {
+                visited.push(next_frame);
+                let mut next_path = path.clone();
+                next_path.push(next_frame);
+                paths.push(next_path);
+            }
+        }
+    }
+
+    return Vec::new();
+}
+
+/// Build the rotation matrix taking vectors from one reference frame into another.
+///
+/// ## Arguments
+/// * `from_frame` -- Frame the input vectors are expressed in.
+/// * `to_frame` -- Frame the output vectors should be expressed in.
+/// * `julian_date` -- Instant of the transformation (UT, also used as TT).
+/// * `observer` -- Observer location (only used by the Horizon frame).
+///
+/// ## Returns
+/// rotation matrix (identity when the frames are the same)
+pub fn transform(
+    from_frame: pa_t::ReferenceFrame,
+    to_frame: pa_t::ReferenceFrame,
+    julian_date: f64,
+    observer: &Observer,
+) -> Matrix3 {
+    let path = frame_path(from_frame, to_frame);
+    let mut result = identity_matrix();
+
+    for step in path.windows(2) {
+        result = multiply_matrices(
+            &edge_matrix(step[0], step[1], julian_date, observer),
+            &result,
+        );
+    }
+
+    return result;
+}
+
+/// Transform spherical coordinates from one reference frame into another.
+///
+/// Longitude is right ascension, ecliptic/galactic/supergalactic longitude, or azimuth (from
+/// north through east) as appropriate to the frame; latitude is declination, ecliptic/galactic/
+/// supergalactic latitude, or altitude.
+///
+/// ## Arguments
+/// * `from_frame` -- Frame of the input coordinates.
+/// * `to_frame` -- Frame of the output coordinates.
+/// * `longitude_deg` -- Input longitude (degrees).
+/// * `latitude_deg` -- Input latitude (degrees).
+/// * `julian_date` -- Instant of the transformation (UT, also used as TT).
+/// * `observer` -- Observer location (only used by the Horizon frame).
+///
+/// ## Returns
+/// * output longitude (degrees, 0 to 360)
+/// * output latitude (degrees)
+pub fn transform_coordinates(
+    from_frame: pa_t::ReferenceFrame,
+    to_frame: pa_t::ReferenceFrame,
+    longitude_deg: f64,
+    latitude_deg: f64,
+    julian_date: f64,
+    observer: &Observer,
+) -> (f64, f64) {
+    let rotation = transform(from_frame, to_frame, julian_date, observer);
+    let vector = rotate_vector(&rotation, &spherical_to_vector(longitude_deg, latitude_deg));
+
+    return vector_to_spherical(&vector);
+}
+
+/// Unit vector for a direction given as longitude and latitude (degrees).
+pub fn spherical_to_vector(longitude_deg: f64, latitude_deg: f64) -> Vector3 {
+    let longitude_rad = longitude_deg.to_radians();
+    let latitude_rad = latitude_deg.to_radians();
+
+    return Vector3 {
+        x: latitude_rad.cos() * longitude_rad.cos(),
+        y: latitude_rad.cos() * longitude_rad.sin(),
+        z: latitude_rad.sin(),
+    };
+}
+
+/// Longitude (degrees, 0 to 360) and latitude (degrees) of a vector.
+pub fn vector_to_spherical(vector: &Vector3) -> (f64, f64) {
+    let longitude_deg = vector.y.atan2(vector.x).to_degrees();
+    let latitude_deg = vector
+        .z
+        .atan2((vector.x * vector.x + vector.y * vector.y).sqrt())
+        .to_degrees();
+
+    return (
+        longitude_deg - 360.0 * (longitude_deg / 360.0).floor(),
+        latitude_deg,
+    );
+}
+
+/// Cross product of two vectors.
+pub fn cross_product(a: &Vector3, b: &Vector3) -> Vector3 {
+    return Vector3 {
+        x: a.y * b.z - a.z * b.y,
+        y: a.z * b.x - a.x * b.z,
+        z: a.x * b.y - a.y * b.x,
+    };
+}
+
+/// Apply a matrix to a vector.
+pub fn rotate_vector(matrix: &Matrix3, vector: &Vector3) -> Vector3 {
+    let m = &matrix.m;
+
+    return Vector3 {
+        x: m[0][0] * vector.x + m[0][1] * vector.y + m[0][2] * vector.z,
+        y: m[1][0] * vector.x + m[1][1] * vector.y + m[1][2] * vector.z,
+        z: m[2][0] * vector.x + m[2][1] * vector.y + m[2][2] * vector.z,
+    };
+}
+
+/// Matrix product `a * b` (apply `b` first, then `a`).
+pub fn multiply_matrices(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut product = [[0.0; 3]; 3];
+
+    for (row, product_row) in product.iter_mut().enumerate() {
+        for (column, element) in product_row.iter_mut().enumerate() {
+            *element = (0..3).map(|k| a.m[row][k] * b.m[k][column]).sum();
+        }
+    }
+
+    return Matrix3 { m: product };
+}
+
+/// Transpose of a matrix (the inverse, for a rotation).
+pub fn transpose_matrix(matrix: &Matrix3) -> Matrix3 {
+    let m = &matrix.m;
+
+    return Matrix3 {
+        m: [
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ],
+    };
+}
+
+/// Identity matrix.
+pub fn identity_matrix() -> Matrix3 {
+    return Matrix3 {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+}
+
+/// Rotation of the coordinate frame about the x axis by an angle (degrees).
+pub fn rotation_matrix_x(angle_deg: f64) -> Matrix3 {
+    let (s, c) = angle_deg.to_radians().sin_cos();
+
+    return Matrix3 {
+        m: [[1.0, 0.0, 0.0], [0.0, c, s], [0.0, -s, c]],
+    };
+}
+
+/// Rotation of the coordinate frame about the y axis by an angle (degrees).
+pub fn rotation_matrix_y(angle_deg: f64) -> Matrix3 {
+    let (s, c) = angle_deg.to_radians().sin_cos();
+
+    return Matrix3 {
+        m: [[c, 0.0, -s], [0.0, 1.0, 0.0], [s, 0.0, c]],
+    };
+}
+
+/// Rotation of the coordinate frame about the z axis by an angle (degrees).
+pub fn rotation_matrix_z(angle_deg: f64) -> Matrix3 {
+    let (s, c) = angle_deg.to_radians().sin_cos();
+
+    return Matrix3 {
+        m: [[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, 1.0]],
+    };
+}
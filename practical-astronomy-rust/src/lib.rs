@@ -1,14 +1,22 @@
 pub mod binary;
 mod binarydata;
+pub mod calendar;
 pub mod comet;
 mod cometdata;
 pub mod coordinates;
 pub mod datetime;
 pub mod eclipses;
+pub mod frames;
+mod lunarfeaturedata;
 pub mod macros;
 pub mod moon;
+mod nutationdata;
+pub mod occultations;
 pub mod planet;
 mod planetdata;
+pub mod riseset;
+mod spadata;
 pub mod sun;
+pub mod transits;
 pub mod types;
 pub mod util;
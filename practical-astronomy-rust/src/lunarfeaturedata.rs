@@ -0,0 +1,71 @@
+/// Info about a lunar feature:
+/// * `name` -- Name of the feature.
+/// * `latitude_deg` -- Selenographic latitude (degrees, north positive).
+/// * `longitude_deg` -- Selenographic longitude (degrees, east positive).
+pub struct LunarFeatureInfo {
+    pub name: String,
+    pub latitude_deg: f64,
+    pub longitude_deg: f64,
+}
+
+/// Name, selenographic latitude and selenographic longitude (east positive) of the centre of
+/// well-known craters, from the IAU Gazetteer of Planetary Nomenclature.
+const LUNAR_FEATURES: [(&str, f64, f64); 32] = [
+    ("Albategnius", -11.24, 4.01),
+    ("Alphonsus", -13.39, -2.85),
+    ("Archimedes", 29.72, -3.99),
+    ("Aristarchus", 23.73, -47.49),
+    ("Aristoteles", 50.24, 17.32),
+    ("Arzachel", -18.26, -1.93),
+    ("Bullialdus", -20.74, -22.46),
+    ("Catharina", -17.98, 23.55),
+    ("Clavius", -58.62, -14.73),
+    ("Copernicus", 9.62, -20.08),
+    ("Cyrillus", -13.2, 24.0),
+    ("Endymion", 53.61, 56.5),
+    ("Eratosthenes", 14.47, -11.32),
+    ("Eudoxus", 44.27, 16.23),
+    ("Gassendi", -17.55, -39.96),
+    ("Grimaldi", -5.51, -68.31),
+    ("Hipparchus", -5.11, 4.82),
+    ("Kepler", 8.12, -38.01),
+    ("Langrenus", -8.86, 60.9),
+    ("Longomontanus", -49.55, -21.97),
+    ("Maginus", -50.0, -6.2),
+    ("Manilius", 14.45, 9.06),
+    ("Petavius", -25.3, 60.4),
+    ("Plato", 51.62, -9.38),
+    ("Posidonius", 31.88, 29.99),
+    ("Ptolemaeus", -9.16, -1.84),
+    ("Schickard", -44.38, -54.62),
+    ("Stevinus", -32.53, 54.14),
+    ("Theophilus", -11.45, 26.28),
+    ("Tycho", -43.31, -11.36),
+    ("Walter", -33.1, 0.7),
+    ("Werner", -28.03, 3.26),
+];
+
+/// Retrieve info about a lunar feature.
+pub fn get_lunar_feature_info(feature_name: String) -> (LunarFeatureInfo, String) {
+    for (name, latitude_deg, longitude_deg) in LUNAR_FEATURES.iter() {
+        if *name == feature_name {
+            return (
+                LunarFeatureInfo {
+                    name: name.to_string(),
+                    latitude_deg: *latitude_deg,
+                    longitude_deg: *longitude_deg,
+                },
+                "OK".to_string(),
+            );
+        }
+    }
+
+    return (
+        LunarFeatureInfo {
+            name: feature_name,
+            latitude_deg: 0.0,
+            longitude_deg: 0.0,
+        },
+        "NotFound".to_string(),
+    );
+}
@@ -1,4 +1,8 @@
+use crate::datetime as pa_dt;
+use crate::lunarfeaturedata as pa_lf;
 use crate::macros as pa_m;
+use crate::riseset as pa_rs;
+use crate::types as pa_t;
 use crate::util as pa_u;
 
 /// Calculate approximate position of the Moon.
@@ -827,3 +831,1131 @@ pub fn moonrise_and_moonset(
         ms_azimuth_deg,
     );
 }
+
+/// Info about the physical ephemeris of the Moon:
+/// * `optical_libration_longitude_deg` -- Optical libration in longitude (degrees).
+/// * `optical_libration_latitude_deg` -- Optical libration in latitude (degrees).
+/// * `physical_libration_longitude_deg` -- Physical libration in longitude (degrees).
+/// * `physical_libration_latitude_deg` -- Physical libration in latitude (degrees).
+/// * `total_libration_longitude_deg` -- Total libration in longitude, i.e. selenographic longitude of the Earth (degrees).
+/// * `total_libration_latitude_deg` -- Total libration in latitude, i.e. selenographic latitude of the Earth (degrees).
+/// * `position_angle_of_axis_deg` -- Position angle of the Moon's axis of rotation (degrees).
+/// * `subsolar_latitude_deg` -- Selenographic latitude of the Sun (degrees).
+/// * `selenographic_colongitude_deg` -- Selenographic colongitude of the Sun (degrees).
+#[derive(Clone, Copy, Debug)]
+pub struct LunarPhysicalEphemeris {
+    pub optical_libration_longitude_deg: f64,
+    pub optical_libration_latitude_deg: f64,
+    pub physical_libration_longitude_deg: f64,
+    pub physical_libration_latitude_deg: f64,
+    pub total_libration_longitude_deg: f64,
+    pub total_libration_latitude_deg: f64,
+    pub position_angle_of_axis_deg: f64,
+    pub subsolar_latitude_deg: f64,
+    pub selenographic_colongitude_deg: f64,
+}
+
+/// Info about the lighting of a lunar feature, as seen by an observer:
+/// * `feature_name` -- Name of the feature.
+/// * `status` -- "OK", or "NotFound" if the feature is not in the bundled list.
+/// * `lighting` -- Illuminated, on the terminator, or dark.
+/// * `solar_altitude_deg` -- Altitude of the Sun above the feature's local horizon (degrees).
+/// * `faces_observer` -- Is the feature on the hemisphere turned towards the observer?
+/// * `moon_altitude_deg` -- Topocentric altitude of the Moon for the observer (degrees, without refraction).
+/// * `is_visible` -- Is the feature illuminated or on the terminator, facing the observer, with the Moon above the horizon?
+pub struct LunarFeatureVisibility {
+    pub feature_name: String,
+    pub status: String,
+    pub lighting: pa_t::LunarFeatureLighting,
+    pub solar_altitude_deg: f64,
+    pub faces_observer: bool,
+    pub moon_altitude_deg: f64,
+    pub is_visible: bool,
+}
+
+/// Calculate the physical ephemeris of the Moon: optical and physical librations, position
+/// angle of the axis, and selenographic position of the Sun (Meeus, Astronomical Algorithms, ch. 53).
+///
+/// ## Arguments
+/// * `lct_hour` -- Local civil time, in hours.
+/// * `lct_min` -- Local civil time, in minutes.
+/// * `lct_sec` -- Local civil time, in seconds.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+///
+/// ## Returns
+/// * `LunarPhysicalEphemeris` -- geocentric librations, position angle and colongitude, rounded to 2 decimal places.
+pub fn lunar_physical_ephemeris(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) -> LunarPhysicalEphemeris {
+    let (ephemeris, _moon_ra_deg, _moon_dec_deg, _moon_hp_deg) = lunar_orientation(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    return LunarPhysicalEphemeris {
+        optical_libration_longitude_deg: pa_u::round_f64(
+            ephemeris.optical_libration_longitude_deg,
+            2,
+        ),
+        optical_libration_latitude_deg: pa_u::round_f64(
+            ephemeris.optical_libration_latitude_deg,
+            2,
+        ),
+        physical_libration_longitude_deg: pa_u::round_f64(
+            ephemeris.physical_libration_longitude_deg,
+            2,
+        ),
+        physical_libration_latitude_deg: pa_u::round_f64(
+            ephemeris.physical_libration_latitude_deg,
+            2,
+        ),
+        total_libration_longitude_deg: pa_u::round_f64(ephemeris.total_libration_longitude_deg, 2),
+        total_libration_latitude_deg: pa_u::round_f64(ephemeris.total_libration_latitude_deg, 2),
+        position_angle_of_axis_deg: pa_u::round_f64(ephemeris.position_angle_of_axis_deg, 2),
+        subsolar_latitude_deg: pa_u::round_f64(ephemeris.subsolar_latitude_deg, 2),
+        selenographic_colongitude_deg: pa_u::round_f64(ephemeris.selenographic_colongitude_deg, 2),
+    };
+}
+
+/// Decide whether a named lunar feature is illuminated, on the terminator, or dark, and whether
+/// an observer can see it.
+///
+/// The feature is on the terminator when the Sun is within 1 degree of its local horizon. The
+/// librations are corrected to the observer's topocentric view of the Moon.
+///
+/// ## Arguments
+/// * `feature_name` -- Name of the feature (e.g. "Copernicus").
+/// * `lct_hour` -- Local civil time, in hours.
+/// * `lct_min` -- Local civil time, in minutes.
+/// * `lct_sec` -- Local civil time, in seconds.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `geog_long_deg` -- Geographical longitude, in degrees.
+/// * `geog_lat_deg` -- Geographical latitude, in degrees.
+///
+/// ## Returns
+/// * `LunarFeatureVisibility` -- lighting of the feature and its visibility for the observer.
+pub fn lunar_feature_visibility(
+    feature_name: String,
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+) -> LunarFeatureVisibility {
+    let (feature, status) = pa_lf::get_lunar_feature_info(feature_name);
+
+    if status != "OK" {
+        return LunarFeatureVisibility {
+            feature_name: feature.name,
+            status,
+            lighting: pa_t::LunarFeatureLighting::Dark,
+            solar_altitude_deg: -99.0,
+            faces_observer: false,
+            moon_altitude_deg: -99.0,
+            is_visible: false,
+        };
+    }
+
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+    let (ephemeris, moon_ra_deg, moon_dec_deg, moon_hp_deg) = lunar_orientation(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    // Topocentric correction of the librations (Meeus 53).
+    let ut = pa_m::lct_ut(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gd = pa_m::lct_gday(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gm = pa_m::lct_gmonth(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gy = pa_m::lct_gyear(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gst = pa_m::ut_gst(ut, 0.0, 0.0, gd, gm, gy);
+    let lst = pa_m::gst_lst(gst, 0.0, 0.0, geog_long_deg);
+
+    let latitude = geog_lat_deg.to_radians();
+    let hour_angle = (lst * 15.0 - moon_ra_deg).to_radians();
+    let declination = moon_dec_deg.to_radians();
+    let q = (latitude.cos() * hour_angle.sin()).atan2(
+        declination.cos() * latitude.sin() - declination.sin() * latitude.cos() * hour_angle.cos(),
+    );
+    let z = (declination.sin() * latitude.sin()
+        + declination.cos() * latitude.cos() * hour_angle.cos())
+    .acos();
+    let parallax = moon_hp_deg * (z.sin() + 0.0084 * (2.0 * z).sin());
+    let p = ephemeris.position_angle_of_axis_deg.to_radians();
+    let libration_latitude_deg = ephemeris.total_libration_latitude_deg + parallax * (q - p).cos();
+    let libration_longitude_deg = ephemeris.total_libration_longitude_deg
+        - parallax * (q - p).sin() / ephemeris.total_libration_latitude_deg.to_radians().cos();
+
+    let feature_latitude = feature.latitude_deg.to_radians();
+    let feature_longitude = feature.longitude_deg.to_radians();
+
+    let subsolar_latitude = ephemeris.subsolar_latitude_deg.to_radians();
+    let colongitude = ephemeris.selenographic_colongitude_deg.to_radians();
+    let solar_altitude_deg = pa_m::degrees(
+        (subsolar_latitude.sin() * feature_latitude.sin()
+            + subsolar_latitude.cos()
+                * feature_latitude.cos()
+                * (colongitude + feature_longitude).sin())
+        .asin(),
+    );
+
+    let sub_observer_latitude = libration_latitude_deg.to_radians();
+    let sub_observer_longitude = libration_longitude_deg.to_radians();
+    let faces_observer = sub_observer_latitude.sin() * feature_latitude.sin()
+        + sub_observer_latitude.cos()
+            * feature_latitude.cos()
+            * (feature_longitude - sub_observer_longitude).cos()
+        > 0.0;
+
+    let lighting = if solar_altitude_deg.abs() < 1.0 {
+        pa_t::LunarFeatureLighting::Terminator
+    } else if solar_altitude_deg > 0.0 {
+        pa_t::LunarFeatureLighting::Illuminated
+    } else {
+        pa_t::LunarFeatureLighting::Dark
+    };
+
+    let jd_ut = pa_m::cd_jd(gd, gm, gy) + ut / 24.0;
+    let (moon_altitude_deg, _moon_azimuth_deg, _moon_hour_angle) = pa_rs::horizontal_position(
+        &pa_rs::RiseSetBody {
+            kind: pa_t::RiseSetBodyKind::Moon,
+            name: "Moon".to_string(),
+            right_ascension_hours: 0.0,
+            declination_deg: 0.0,
+        },
+        jd_ut,
+        &(geog_long_deg, geog_lat_deg, 0.0),
+    );
+
+    let is_visible =
+        lighting != pa_t::LunarFeatureLighting::Dark && faces_observer && moon_altitude_deg > 0.0;
+
+    return LunarFeatureVisibility {
+        feature_name: feature.name,
+        status,
+        lighting,
+        solar_altitude_deg: pa_u::round_f64(solar_altitude_deg, 2),
+        faces_observer,
+        moon_altitude_deg: pa_u::round_f64(moon_altitude_deg, 2),
+        is_visible,
+    };
+}
+
+/// Info about a lunar event:
+/// * `kind` -- Kind of event.
+/// * `jd_ut` -- Julian date (UT) of the event.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `lct_hour` -- Local civil time, hour part.
+/// * `lct_minutes` -- Local civil time, minutes part.
+/// * `lct_seconds` -- Local civil time, seconds part.
+/// * `distance_km` -- Earth-Moon distance at the event (km).
+/// * `declination_deg` -- Apparent declination of the Moon at the event (degrees).
+#[derive(Clone, Copy, Debug)]
+pub struct LunarEvent {
+    pub kind: pa_t::LunarEventKind,
+    pub jd_ut: f64,
+    pub local_date_day: u32,
+    pub local_date_month: u32,
+    pub local_date_year: u32,
+    pub lct_hour: u32,
+    pub lct_minutes: u32,
+    pub lct_seconds: f64,
+    pub distance_km: f64,
+    pub declination_deg: f64,
+}
+
+/// Iterate over the lunar events in a range of local dates, in time order: principal phases,
+/// perigees and apogees, node crossings, maximum declinations and lunar standstills.
+///
+/// A full Moon closer than 360,000 km is followed by a Supermoon event, and the second full
+/// Moon in a calendar month (local time) by a BlueMoon event. Major and minor standstills are
+/// the instants when the mean ascending node passes 0 and 180 degrees of longitude.
+///
+/// ## Arguments
+/// * `start_date_day` -- First local date of the range, day part.
+/// * `start_date_month` -- First local date of the range, month part.
+/// * `start_date_year` -- First local date of the range, year part.
+/// * `end_date_day` -- Last local date of the range (inclusive), day part.
+/// * `end_date_month` -- Last local date of the range (inclusive), month part.
+/// * `end_date_year` -- Last local date of the range (inclusive), year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+///
+/// ## Returns
+/// * Iterator over `LunarEvent`s.
+pub fn lunar_events(
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) -> std::vec::IntoIter<LunarEvent> {
+    let local_offset_days = pa_dt::local_offset_days(is_daylight_saving, zone_correction_hours);
+    let start_jd_ut = pa_dt::local_date_start_jd_ut(
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        local_offset_days,
+    );
+    let end_jd_ut = pa_dt::local_date_start_jd_ut(
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        local_offset_days,
+    ) + 1.0;
+    let step = 0.25;
+    let delta = 0.01;
+
+    let mut events: Vec<LunarEvent> = Vec::new();
+    let mut push_event = |kind: pa_t::LunarEventKind, jd_ut: f64| {
+        events.push(lunar_event(kind, jd_ut, local_offset_days));
+    };
+
+    // Principal phases, from a month before the range so that blue moons can be recognised.
+    let phases = [
+        pa_t::LunarEventKind::NewMoon,
+        pa_t::LunarEventKind::FirstQuarter,
+        pa_t::LunarEventKind::FullMoon,
+        pa_t::LunarEventKind::LastQuarter,
+    ];
+    let mut previous_full_moon: Option<(u32, u32)> = None;
+    let mut phase_events: Vec<(f64, pa_t::LunarEventKind)> = Vec::new();
+    let elongation = |jd_ut: f64| {
+        let (moon_long_deg, _, _, sun_long_deg) = moon_and_sun_at(jd_ut);
+        moon_long_deg - sun_long_deg
+    };
+    let quarter = |jd_ut: f64| {
+        let elongation_deg = elongation(jd_ut);
+        elongation_deg - 90.0 * ((elongation_deg + 45.0) / 90.0).floor()
+    };
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&quarter, start_jd_ut - 31.0, end_jd_ut, step)
+    {
+        if is_rising {
+            let elongation_deg = elongation(jd_ut);
+            let index = (elongation_deg - 360.0 * (elongation_deg / 360.0).floor()) / 90.0;
+            phase_events.push((jd_ut, phases[index.round() as usize % 4]));
+        }
+    }
+    for (jd_ut, kind) in phase_events {
+        let event = lunar_event(kind, jd_ut, local_offset_days);
+        let is_full_moon = kind == pa_t::LunarEventKind::FullMoon;
+        let is_blue_moon = is_full_moon
+            && previous_full_moon == Some((event.local_date_month, event.local_date_year));
+        if is_full_moon {
+            previous_full_moon = Some((event.local_date_month, event.local_date_year));
+        }
+        if jd_ut < start_jd_ut {
+            continue;
+        }
+
+        push_event(kind, jd_ut);
+        if is_full_moon && event.distance_km < 360000.0 {
+            push_event(pa_t::LunarEventKind::Supermoon, jd_ut);
+        }
+        if is_blue_moon {
+            push_event(pa_t::LunarEventKind::BlueMoon, jd_ut);
+        }
+    }
+
+    // Apsides: extremes of the distance.
+    let distance_rate =
+        |jd_ut: f64| moon_and_sun_at(jd_ut + delta).2 - moon_and_sun_at(jd_ut - delta).2;
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&distance_rate, start_jd_ut, end_jd_ut, step)
+    {
+        push_event(
+            if is_rising {
+                pa_t::LunarEventKind::Perigee
+            } else {
+                pa_t::LunarEventKind::Apogee
+            },
+            jd_ut,
+        );
+    }
+
+    // Nodes: the Moon crosses the ecliptic.
+    let latitude = |jd_ut: f64| moon_and_sun_at(jd_ut).1;
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&latitude, start_jd_ut, end_jd_ut, step)
+    {
+        push_event(
+            if is_rising {
+                pa_t::LunarEventKind::AscendingNode
+            } else {
+                pa_t::LunarEventKind::DescendingNode
+            },
+            jd_ut,
+        );
+    }
+
+    // Maximum declinations: extremes of the declination.
+    let declination_rate =
+        |jd_ut: f64| moon_declination_at(jd_ut + delta) - moon_declination_at(jd_ut - delta);
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&declination_rate, start_jd_ut, end_jd_ut, step)
+    {
+        push_event(
+            if is_rising {
+                pa_t::LunarEventKind::MaximumSouthDeclination
+            } else {
+                pa_t::LunarEventKind::MaximumNorthDeclination
+            },
+            jd_ut,
+        );
+    }
+
+    // Standstills: the mean ascending node regresses through 0 (major) and 180 (minor) degrees.
+    for (kind, node_longitude_deg) in [
+        (pa_t::LunarEventKind::MajorStandstill, 0.0),
+        (pa_t::LunarEventKind::MinorStandstill, 180.0),
+    ] {
+        let node = |jd_ut: f64| {
+            let t = (jd_ut - 2451545.0) / 36525.0;
+            pa_u::wrap_deg(125.0445479 - 1934.1362891 * t + 0.0020754 * t * t - node_longitude_deg)
+        };
+        for (jd_ut, is_rising) in
+            pa_rs::zero_crossings_with_step(&node, start_jd_ut, end_jd_ut, step)
+        {
+            if !is_rising {
+                push_event(kind, jd_ut);
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.jd_ut.partial_cmp(&b.jd_ut).unwrap());
+
+    return events.into_iter();
+}
+
+/// Info about the lunation numbers of a new Moon:
+/// * `new_moon_jd_ut` -- Julian date (UT) of the new Moon.
+/// * `meeus` -- Meeus lunation number (0 = new Moon of 6 January 2000).
+/// * `brown` -- Brown lunation number (1 = new Moon of 17 January 1923).
+/// * `islamic` -- Islamic lunation number (1 = Muharram, 1 AH).
+/// * `islamic_month` -- Estimated Islamic month (1 = Muharram).
+/// * `islamic_year` -- Estimated Islamic year (AH).
+/// * `hebrew` -- Hebrew lunation number (1 = Tishri, AM 1).
+/// * `hebrew_month` -- Estimated Hebrew month, counted from Nisan as in `calendar` (1 = Nisan, 7 = Tishri, 12 = Adar or Adar I, 13 = Adar II).
+/// * `hebrew_year` -- Estimated Hebrew year (AM).
+#[derive(Clone, Copy, Debug)]
+pub struct LunationNumbers {
+    pub new_moon_jd_ut: f64,
+    pub meeus: i64,
+    pub brown: i64,
+    pub islamic: i64,
+    pub islamic_month: u32,
+    pub islamic_year: i64,
+    pub hebrew: i64,
+    pub hebrew_month: u32,
+    pub hebrew_year: i64,
+}
+
+/// Info about the visibility of the young crescent Moon at sunset:
+/// * `status` -- "OK", "Sun does not set", "Moon does not set" or "Moon sets before Sun".
+/// * `sunset_hour` / `sunset_minutes` -- Local civil time of sunset.
+/// * `moonset_hour` / `moonset_minutes` -- Local civil time of moonset.
+/// * `best_time_hour` / `best_time_minutes` -- Local civil time of best visibility (sunset + 4/9 of the lag).
+/// * `lag_time_minutes` -- Moonset minus sunset (minutes).
+/// * `moon_age_hours` -- Age of the Moon at the best time (hours since the nearest new Moon; negative before it).
+/// * `arcl_deg` -- Geocentric elongation of the Moon from the Sun (degrees).
+/// * `arcv_deg` -- Geocentric arc of vision: Moon altitude minus Sun altitude (degrees).
+/// * `daz_deg` -- Relative azimuth: Sun azimuth minus Moon azimuth (degrees).
+/// * `crescent_width_arcmin` -- Topocentric width of the crescent (arcminutes).
+/// * `yallop_q` -- Yallop's q test value.
+/// * `yallop_visibility` -- Yallop's visibility category.
+/// * `odeh_v` -- Odeh's V test value.
+/// * `odeh_visibility` -- Odeh's visibility zone.
+pub struct CrescentVisibility {
+    pub status: String,
+    pub sunset_hour: u32,
+    pub sunset_minutes: u32,
+    pub moonset_hour: u32,
+    pub moonset_minutes: u32,
+    pub best_time_hour: u32,
+    pub best_time_minutes: u32,
+    pub lag_time_minutes: f64,
+    pub moon_age_hours: f64,
+    pub arcl_deg: f64,
+    pub arcv_deg: f64,
+    pub daz_deg: f64,
+    pub crescent_width_arcmin: f64,
+    pub yallop_q: f64,
+    pub yallop_visibility: pa_t::YallopVisibility,
+    pub odeh_v: f64,
+    pub odeh_visibility: pa_t::OdehVisibility,
+}
+
+/// Calculate the Brown, Meeus, Islamic and Hebrew lunation numbers of the new Moon nearest a
+/// date, with the Islamic and Hebrew months that it begins.
+///
+/// The calendar months are arithmetical estimates; the actual start of a month depends on the
+/// sighting of the crescent (see `crescent_visibility`) or on the calendar rules.
+///
+/// ## Arguments
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+///
+/// ## Returns
+/// * `LunationNumbers`
+pub fn lunation_numbers(
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) -> LunationNumbers {
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+
+    let new_moon_jd_ut = pa_m::new_moon(
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    let meeus = ((new_moon_jd_ut - 2451550.09766) / 29.530588861).round() as i64;
+    let brown = meeus + 953;
+    let islamic = meeus + 17038;
+    let hebrew = meeus + 71234;
+
+    // Hebrew years have 12 or 13 months in a 19-year cycle of 235 months.
+    let months_before_hebrew_year = |year: i64| (235 * year - 234).div_euclid(19);
+    let mut hebrew_year = (hebrew * 19).div_euclid(235) + 1;
+    while months_before_hebrew_year(hebrew_year) >= hebrew {
+        hebrew_year -= 1;
+    }
+    while months_before_hebrew_year(hebrew_year + 1) < hebrew {
+        hebrew_year += 1;
+    }
+
+    // Months are counted from Tishri within the year, but numbered from Nisan.
+    let hebrew_month_from_tishri = hebrew - months_before_hebrew_year(hebrew_year);
+    let months_from_tishri_to_adar =
+        months_before_hebrew_year(hebrew_year + 1) - months_before_hebrew_year(hebrew_year) - 6;
+    let hebrew_month = if hebrew_month_from_tishri <= months_from_tishri_to_adar {
+        hebrew_month_from_tishri + 6
+    } else {
+        hebrew_month_from_tishri - months_from_tishri_to_adar
+    };
+
+    return LunationNumbers {
+        new_moon_jd_ut,
+        meeus,
+        brown,
+        islamic,
+        islamic_month: ((islamic - 1).rem_euclid(12) + 1) as u32,
+        islamic_year: (islamic - 1).div_euclid(12) + 1,
+        hebrew,
+        hebrew_month: hebrew_month as u32,
+        hebrew_year,
+    };
+}
+
+/// Evaluate the Yallop and Odeh criteria for the visibility of the young crescent Moon after
+/// sunset on a local date.
+///
+/// The Moon and Sun are taken at the best time, sunset plus 4/9 of the lag time (Yallop).
+/// Yallop's q uses the geocentric arc of light and arc of vision; Odeh's V uses topocentric
+/// ones. Both use the topocentric crescent width.
+///
+/// ## Arguments
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `geog_long_deg` -- Geographical longitude, in degrees.
+/// * `geog_lat_deg` -- Geographical latitude, in degrees.
+///
+/// ## Returns
+/// * `CrescentVisibility`
+pub fn crescent_visibility(
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+) -> CrescentVisibility {
+    let body = |kind: pa_t::RiseSetBodyKind, name: &str| pa_rs::RiseSetBody {
+        kind,
+        name: name.to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+    let sun = body(pa_t::RiseSetBodyKind::Sun, "Sun");
+    let moon = body(pa_t::RiseSetBodyKind::Moon, "Moon");
+    let observer = (geog_long_deg, geog_lat_deg, 0.0);
+
+    let sets = |body: &pa_rs::RiseSetBody, day: f64| {
+        pa_rs::rise_set_events(
+            body,
+            day,
+            local_date_month,
+            local_date_year,
+            is_daylight_saving,
+            zone_correction_hours,
+            geog_long_deg,
+            geog_lat_deg,
+            0.0,
+            pa_rs::standard_horizon_altitude(body.kind),
+            false,
+        )
+        .into_iter()
+        .filter(|event| event.kind == pa_t::RiseSetEventKind::Set)
+        .collect::<Vec<pa_rs::RiseSetEvent>>()
+    };
+
+    let mut visibility = CrescentVisibility {
+        status: "OK".to_string(),
+        sunset_hour: 0,
+        sunset_minutes: 0,
+        moonset_hour: 0,
+        moonset_minutes: 0,
+        best_time_hour: 0,
+        best_time_minutes: 0,
+        lag_time_minutes: -99.0,
+        moon_age_hours: -99.0,
+        arcl_deg: -99.0,
+        arcv_deg: -99.0,
+        daz_deg: -99.0,
+        crescent_width_arcmin: -99.0,
+        yallop_q: -99.0,
+        yallop_visibility: pa_t::YallopVisibility::F,
+        odeh_v: -99.0,
+        odeh_visibility: pa_t::OdehVisibility::NotVisible,
+    };
+
+    let sunset = match sets(&sun, local_date_day).into_iter().next() {
+        Some(event) => event,
+        None => {
+            visibility.status = "Sun does not set".to_string();
+            return visibility;
+        }
+    };
+    visibility.sunset_hour = sunset.lct_hour;
+    visibility.sunset_minutes = sunset.lct_minutes;
+
+    let moonset = match sets(&moon, local_date_day)
+        .into_iter()
+        .chain(sets(&moon, local_date_day + 1.0))
+        .find(|event| event.jd_ut > sunset.jd_ut - 0.5)
+    {
+        Some(event) => event,
+        None => {
+            visibility.status = "Moon does not set".to_string();
+            return visibility;
+        }
+    };
+    let moonset_lct_hours = sunset.lct_hour as f64
+        + sunset.lct_minutes as f64 / 60.0
+        + sunset.lct_seconds / 3600.0
+        + (moonset.jd_ut - sunset.jd_ut) * 24.0;
+    visibility.moonset_hour = pa_m::dh_hour(moonset_lct_hours);
+    visibility.moonset_minutes = pa_m::dh_min(moonset_lct_hours);
+    visibility.lag_time_minutes = pa_u::round_f64((moonset.jd_ut - sunset.jd_ut) * 1440.0, 0);
+    if moonset.jd_ut <= sunset.jd_ut {
+        visibility.status = "Moon sets before Sun".to_string();
+        return visibility;
+    }
+
+    let best_time_jd_ut = sunset.jd_ut + 4.0 / 9.0 * (moonset.jd_ut - sunset.jd_ut);
+    let best_time_lct_hours = sunset.lct_hour as f64
+        + sunset.lct_minutes as f64 / 60.0
+        + sunset.lct_seconds / 3600.0
+        + (best_time_jd_ut - sunset.jd_ut) * 24.0;
+    visibility.best_time_hour = pa_m::dh_hour(best_time_lct_hours);
+    visibility.best_time_minutes = pa_m::dh_min(best_time_lct_hours);
+
+    let new_moon_jd_ut = pa_m::new_moon(
+        0,
+        0,
+        pa_m::jdc_day(best_time_jd_ut),
+        pa_m::jdc_month(best_time_jd_ut),
+        pa_m::jdc_year(best_time_jd_ut),
+    );
+    visibility.moon_age_hours = pa_u::round_f64((best_time_jd_ut - new_moon_jd_ut) * 24.0, 2);
+
+    let arc = |altitude1_deg: f64, azimuth1_deg: f64, altitude2_deg: f64, azimuth2_deg: f64| {
+        let altitude1 = altitude1_deg.to_radians();
+        let altitude2 = altitude2_deg.to_radians();
+        pa_m::degrees(
+            (altitude1.sin() * altitude2.sin()
+                + altitude1.cos()
+                    * altitude2.cos()
+                    * (azimuth1_deg - azimuth2_deg).to_radians().cos())
+            .acos(),
+        )
+    };
+
+    let (sun_altitude_deg, sun_azimuth_deg, _) =
+        pa_rs::geocentric_horizontal_position(&sun, best_time_jd_ut, &observer);
+    let (moon_altitude_deg, moon_azimuth_deg, _) =
+        pa_rs::geocentric_horizontal_position(&moon, best_time_jd_ut, &observer);
+    let (topocentric_sun_altitude_deg, topocentric_sun_azimuth_deg, _) =
+        pa_rs::horizontal_position(&sun, best_time_jd_ut, &observer);
+    let (topocentric_moon_altitude_deg, topocentric_moon_azimuth_deg, _) =
+        pa_rs::horizontal_position(&moon, best_time_jd_ut, &observer);
+
+    let arcl_deg = arc(
+        sun_altitude_deg,
+        sun_azimuth_deg,
+        moon_altitude_deg,
+        moon_azimuth_deg,
+    );
+    let arcv_deg = moon_altitude_deg - sun_altitude_deg;
+    let topocentric_arcl_deg = arc(
+        topocentric_sun_altitude_deg,
+        topocentric_sun_azimuth_deg,
+        topocentric_moon_altitude_deg,
+        topocentric_moon_azimuth_deg,
+    );
+    let topocentric_arcv_deg = topocentric_moon_altitude_deg - topocentric_sun_altitude_deg;
+
+    let moon_position = pa_rs::body_position(&moon, best_time_jd_ut);
+    let moon_distance = (moon_position.x * moon_position.x
+        + moon_position.y * moon_position.y
+        + moon_position.z * moon_position.z)
+        .sqrt();
+    let parallax = (1.0 / moon_distance).asin();
+    let semi_diameter_arcmin = 0.27245 * parallax.to_degrees() * 60.0;
+    let topocentric_semi_diameter_arcmin =
+        semi_diameter_arcmin * (1.0 + moon_altitude_deg.to_radians().sin() * parallax.sin());
+
+    let width =
+        |arcl_deg: f64| topocentric_semi_diameter_arcmin * (1.0 - arcl_deg.to_radians().cos());
+    let yallop_width_arcmin = width(arcl_deg);
+    let odeh_width_arcmin = width(topocentric_arcl_deg);
+    let polynomial = |w: f64| -6.3226 * w + 0.7319 * w * w - 0.1018 * w * w * w;
+
+    let yallop_q = (arcv_deg - (11.8371 + polynomial(yallop_width_arcmin))) / 10.0;
+    let odeh_v = topocentric_arcv_deg - (7.1651 + polynomial(odeh_width_arcmin));
+
+    visibility.arcl_deg = pa_u::round_f64(arcl_deg, 2);
+    visibility.arcv_deg = pa_u::round_f64(arcv_deg, 2);
+    visibility.daz_deg = pa_u::round_f64(sun_azimuth_deg - moon_azimuth_deg, 2);
+    visibility.crescent_width_arcmin = pa_u::round_f64(odeh_width_arcmin, 2);
+    visibility.yallop_q = pa_u::round_f64(yallop_q, 3);
+    visibility.yallop_visibility = if yallop_q > 0.216 {
+        pa_t::YallopVisibility::A
+    } else if yallop_q > -0.014 {
+        pa_t::YallopVisibility::B
+    } else if yallop_q > -0.160 {
+        pa_t::YallopVisibility::C
+    } else if yallop_q > -0.232 {
+        pa_t::YallopVisibility::D
+    } else if yallop_q > -0.293 {
+        pa_t::YallopVisibility::E
+    } else {
+        pa_t::YallopVisibility::F
+    };
+    visibility.odeh_v = pa_u::round_f64(odeh_v, 2);
+    visibility.odeh_visibility = if odeh_v >= 5.65 {
+        pa_t::OdehVisibility::NakedEye
+    } else if odeh_v >= 2.0 {
+        pa_t::OdehVisibility::OpticalAidOrNakedEye
+    } else if odeh_v >= -0.96 {
+        pa_t::OdehVisibility::OpticalAidOnly
+    } else {
+        pa_t::OdehVisibility::NotVisible
+    };
+
+    return visibility;
+}
+
+/// Lunar event of a kind at a Julian date (UT), with local date and time.
+fn lunar_event(kind: pa_t::LunarEventKind, jd_ut: f64, local_offset_days: f64) -> LunarEvent {
+    let local_jd = jd_ut + local_offset_days;
+    let local_day = pa_m::jdc_day(local_jd);
+    let lct_hours = (local_day - local_day.floor()) * 24.0;
+
+    return LunarEvent {
+        kind,
+        jd_ut,
+        local_date_day: local_day.floor() as u32,
+        local_date_month: pa_m::jdc_month(local_jd),
+        local_date_year: pa_m::jdc_year(local_jd),
+        lct_hour: pa_m::dh_hour(lct_hours),
+        lct_minutes: pa_m::dh_min(lct_hours),
+        lct_seconds: pa_m::dh_sec(lct_hours),
+        distance_km: pa_u::round_f64(moon_and_sun_at(jd_ut).2, 0),
+        declination_deg: pa_u::round_f64(moon_declination_at(jd_ut), 2),
+    };
+}
+
+/// Apparent longitude (degrees), latitude (degrees) and distance (km) of the Moon, and apparent
+/// longitude of the Sun (degrees), at a Julian date (UT).
+pub(crate) fn moon_and_sun_at(jd_ut: f64) -> (f64, f64, f64, f64) {
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    return (
+        pa_m::moon_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy) + pa_m::nutat_long(gd, gm, gy),
+        pa_m::moon_lat(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::moon_dist(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::sun_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy) + pa_m::nutat_long(gd, gm, gy) - 0.005694,
+    );
+}
+
+/// Apparent declination of the Moon (degrees) at a Julian date (UT).
+fn moon_declination_at(jd_ut: f64) -> f64 {
+    let (moon_long_deg, moon_lat_deg, _, _) = moon_and_sun_at(jd_ut);
+    let day = pa_m::jdc_day(jd_ut);
+
+    return pa_m::ec_dec(
+        moon_long_deg,
+        0.0,
+        0.0,
+        moon_lat_deg,
+        0.0,
+        0.0,
+        day.floor(),
+        pa_m::jdc_month(jd_ut),
+        pa_m::jdc_year(jd_ut),
+    );
+}
+
+/// Unrounded geocentric physical ephemeris of the Moon, with the Moon's apparent right ascension
+/// (degrees), declination (degrees) and horizontal parallax (degrees).
+fn lunar_orientation(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+) -> (LunarPhysicalEphemeris, f64, f64, f64) {
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+
+    let ut = pa_m::lct_ut(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gd = pa_m::lct_gday(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gm = pa_m::lct_gmonth(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gy = pa_m::lct_gyear(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+
+    let nutation_in_longitude_deg = pa_m::nutat_long(gd, gm, gy);
+    let obliquity_deg = pa_m::obliq(gd, gm, gy);
+    let moon_long_deg = pa_m::moon_long(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    ) + nutation_in_longitude_deg;
+    let moon_lat_deg = pa_m::moon_lat(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let moon_dist_km = pa_m::moon_dist(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let moon_hp_deg = pa_m::moon_hp(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let sun_long_deg = pa_m::sun_long(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let sun_dist_km = pa_m::sun_dist(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    ) * 149597870.7;
+
+    let moon_ra_deg = pa_m::ec_ra(moon_long_deg, 0.0, 0.0, moon_lat_deg, 0.0, 0.0, gd, gm, gy);
+    let moon_dec_deg = pa_m::ec_dec(moon_long_deg, 0.0, 0.0, moon_lat_deg, 0.0, 0.0, gd, gm, gy);
+
+    // Fundamental arguments of the lunar theory (Meeus 47).
+    let t = (pa_m::cd_jd(gd, gm, gy) + ut / 24.0 - 2451545.0) / 36525.0;
+    let d = (297.8501921 + 445267.1114034 * t - 0.0018819 * t * t + t * t * t / 545868.0
+        - t * t * t * t / 113065000.0)
+        .to_radians();
+    let m =
+        (357.5291092 + 35999.0502909 * t - 0.0001536 * t * t + t * t * t / 24490000.0).to_radians();
+    let m1 = (134.9633964 + 477198.8675055 * t + 0.0087414 * t * t + t * t * t / 69699.0
+        - t * t * t * t / 14712000.0)
+        .to_radians();
+    let f = (93.2720950 + 483202.0175233 * t - 0.0036539 * t * t - t * t * t / 3526000.0
+        + t * t * t * t / 863310000.0)
+        .to_radians();
+    let omega_deg = 125.0445479 - 1934.1362891 * t + 0.0020754 * t * t + t * t * t / 467441.0
+        - t * t * t * t / 60616000.0;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let k1 = (119.75 + 131.849 * t).to_radians();
+    let k2 = (72.56 + 20.186 * t).to_radians();
+
+    let inclination = 1.54242_f64.to_radians();
+
+    // Physical librations (degrees).
+    let rho = -0.02752 * m1.cos() - 0.02245 * f.sin() + 0.00684 * (m1 - 2.0 * f).cos()
+        - 0.00293 * (2.0 * f).cos()
+        - 0.00085 * (2.0 * f - 2.0 * d).cos()
+        - 0.00054 * (m1 - 2.0 * d).cos()
+        - 0.00020 * (m1 + f).sin()
+        - 0.00020 * (m1 + 2.0 * f).cos()
+        - 0.00020 * (m1 - f).cos()
+        + 0.00014 * (m1 + 2.0 * f - 2.0 * d).cos();
+    let sigma = -0.02816 * m1.sin() + 0.02244 * f.cos()
+        - 0.00682 * (m1 - 2.0 * f).sin()
+        - 0.00279 * (2.0 * f).sin()
+        - 0.00083 * (2.0 * f - 2.0 * d).sin()
+        + 0.00069 * (m1 - 2.0 * d).sin()
+        + 0.00040 * (m1 + f).cos()
+        - 0.00025 * (2.0 * m1).sin()
+        - 0.00023 * (m1 + 2.0 * f).sin()
+        + 0.00020 * (m1 - f).cos()
+        + 0.00019 * (m1 - f).sin()
+        + 0.00013 * (m1 + 2.0 * f - 2.0 * d).sin()
+        - 0.00010 * (m1 - 3.0 * f).cos();
+    let tau = 0.02520 * e * m.sin() + 0.00473 * (2.0 * m1 - 2.0 * f).sin() - 0.00467 * m1.sin()
+        + 0.00396 * k1.sin()
+        + 0.00276 * (2.0 * m1 - 2.0 * d).sin()
+        + 0.00196 * omega_deg.to_radians().sin()
+        - 0.00183 * (m1 - f).cos()
+        + 0.00115 * (m1 - 2.0 * d).sin()
+        - 0.00096 * (m1 - d).sin()
+        + 0.00046 * (2.0 * f - 2.0 * d).sin()
+        - 0.00039 * (m1 - f).sin()
+        - 0.00032 * (m1 - m - d).sin()
+        + 0.00027 * (2.0 * m1 - m - 2.0 * d).sin()
+        + 0.00023 * k2.sin()
+        - 0.00014 * (2.0 * d).sin()
+        + 0.00014 * (2.0 * m1 - 2.0 * f).cos()
+        - 0.00012 * (m1 - 2.0 * f).sin()
+        - 0.00012 * (2.0 * m1).sin()
+        + 0.00011 * (2.0 * m1 - 2.0 * m - 2.0 * d).sin();
+
+    // Optical and physical librations for a direction (longitude, latitude) from the Moon.
+    let librations = |long_deg: f64, lat_deg: f64| {
+        let w = (long_deg - nutation_in_longitude_deg - omega_deg).to_radians();
+        let beta = lat_deg.to_radians();
+        let a = (w.sin() * beta.cos() * inclination.cos() - beta.sin() * inclination.sin())
+            .atan2(w.cos() * beta.cos());
+        let optical_longitude_deg = pa_u::wrap_deg(a.to_degrees() - f.to_degrees());
+        let optical_latitude =
+            (-w.sin() * beta.cos() * inclination.sin() - beta.sin() * inclination.cos()).asin();
+        let physical_longitude_deg =
+            -tau + (rho * a.cos() + sigma * a.sin()) * optical_latitude.tan();
+        let physical_latitude_deg = sigma * a.cos() - rho * a.sin();
+
+        (
+            optical_longitude_deg,
+            optical_latitude.to_degrees(),
+            physical_longitude_deg,
+            physical_latitude_deg,
+        )
+    };
+
+    let (
+        optical_longitude_deg,
+        optical_latitude_deg,
+        physical_longitude_deg,
+        physical_latitude_deg,
+    ) = librations(moon_long_deg, moon_lat_deg);
+    let total_longitude_deg = optical_longitude_deg + physical_longitude_deg;
+    let total_latitude_deg = optical_latitude_deg + physical_latitude_deg;
+
+    // Position angle of the axis.
+    let v = (omega_deg + nutation_in_longitude_deg + sigma / inclination.sin()).to_radians();
+    let obliquity = obliquity_deg.to_radians();
+    let x = (inclination + rho.to_radians()).sin() * v.sin();
+    let y = (inclination + rho.to_radians()).sin() * v.cos() * obliquity.cos()
+        - (inclination + rho.to_radians()).cos() * obliquity.sin();
+    let omega = x.atan2(y);
+    let position_angle_deg = pa_m::degrees(
+        ((x * x + y * y).sqrt() * (moon_ra_deg.to_radians() - omega).cos()
+            / total_latitude_deg.to_radians().cos())
+        .asin(),
+    );
+
+    // Selenographic position of the Sun.
+    let moon_long = moon_long_deg.to_radians();
+    let moon_lat = moon_lat_deg.to_radians();
+    let heliocentric_long_deg = sun_long_deg
+        + 180.0
+        + (moon_dist_km / sun_dist_km)
+            * 57.296
+            * moon_lat.cos()
+            * (sun_long_deg.to_radians() - moon_long).sin();
+    let heliocentric_lat_deg = (moon_dist_km / sun_dist_km) * moon_lat_deg;
+    let (
+        sun_optical_longitude_deg,
+        sun_optical_latitude_deg,
+        sun_physical_longitude_deg,
+        sun_physical_latitude_deg,
+    ) = librations(heliocentric_long_deg, heliocentric_lat_deg);
+    let subsolar_longitude_deg = sun_optical_longitude_deg + sun_physical_longitude_deg;
+    let subsolar_latitude_deg = sun_optical_latitude_deg + sun_physical_latitude_deg;
+    let colongitude_deg =
+        90.0 - subsolar_longitude_deg - 360.0 * ((90.0 - subsolar_longitude_deg) / 360.0).floor();
+
+    return (
+        LunarPhysicalEphemeris {
+            optical_libration_longitude_deg: optical_longitude_deg,
+            optical_libration_latitude_deg: optical_latitude_deg,
+            physical_libration_longitude_deg: physical_longitude_deg,
+            physical_libration_latitude_deg: physical_latitude_deg,
+            total_libration_longitude_deg: total_longitude_deg,
+            total_libration_latitude_deg: total_latitude_deg,
+            position_angle_of_axis_deg: position_angle_deg,
+            subsolar_latitude_deg,
+            selenographic_colongitude_deg: colongitude_deg,
+        },
+        moon_ra_deg,
+        moon_dec_deg,
+        moon_hp_deg,
+    );
+}
@@ -0,0 +1,429 @@
+use crate::datetime as pa_dt;
+use crate::eclipses as pa_e;
+use crate::frames as pa_f;
+use crate::macros as pa_m;
+use crate::types as pa_t;
+
+/// Info about a body that may be occulted by the Moon:
+/// * `kind` -- Star or planet.
+/// * `name` -- Name of the star, or of the planet (e.g. "Venus").
+/// * `right_ascension_hours` -- Right ascension (J2000, decimal hours). Not used for planets.
+/// * `declination_deg` -- Declination (J2000, decimal degrees). Not used for planets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OccultationTarget {
+    pub kind: pa_t::OccultationTargetKind,
+    pub name: String,
+    pub right_ascension_hours: f64,
+    pub declination_deg: f64,
+}
+
+/// Info about the disappearance or reappearance of an occulted body:
+/// * `jd_ut` -- Julian date of the event (Universal Time).
+/// * `date_day` -- Date of the event (day, Universal Time).
+/// * `date_month` -- Date of the event (month, Universal Time).
+/// * `date_year` -- Date of the event (year, Universal Time).
+/// * `ut_hour` -- Time of the event (hour, Universal Time).
+/// * `ut_minutes` -- Time of the event (minutes, Universal Time).
+/// * `ut_seconds` -- Time of the event (seconds, Universal Time).
+/// * `position_angle_deg` -- Position angle of the event on the Moon's limb, measured from north through east.
+/// * `cusp_angle_deg` -- Angle of the event from the nearer cusp of the Moon, measured along the limb.
+/// * `is_north_cusp` -- The nearer cusp is the northern one.
+/// * `is_dark_limb` -- The event is on the Moon's dark limb.
+/// * `moon_altitude_deg` -- Topocentric altitude of the Moon, without refraction.
+/// * `sun_altitude_deg` -- Altitude of the Sun, without refraction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OccultationContact {
+    pub jd_ut: f64,
+    pub date_day: f64,
+    pub date_month: u32,
+    pub date_year: u32,
+    pub ut_hour: u32,
+    pub ut_minutes: u32,
+    pub ut_seconds: f64,
+    pub position_angle_deg: f64,
+    pub cusp_angle_deg: f64,
+    pub is_north_cusp: bool,
+    pub is_dark_limb: bool,
+    pub moon_altitude_deg: f64,
+    pub sun_altitude_deg: f64,
+}
+
+/// Info about a lunar occultation seen from a location:
+/// * `target` -- The occulted body.
+/// * `conjunction_jd_tt` -- Julian date (Terrestrial Time) of closest geocentric approach of the Moon and the body.
+/// * `disappearance` -- The body disappears behind the Moon.
+/// * `reappearance` -- The body reappears from behind the Moon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Occultation {
+    pub target: OccultationTarget,
+    pub conjunction_jd_tt: f64,
+    pub disappearance: OccultationContact,
+    pub reappearance: OccultationContact,
+}
+
+/// Radius of the Moon, in Earth radii.
+const MOON_RADIUS: f64 = 0.2725076;
+
+/// Predict lunar occultations of stars and planets for an observer.
+///
+/// Planets are treated as points at their centers. Only occultations with the Moon above the
+/// horizon at disappearance or reappearance are returned, in order of time.
+///
+/// ## Arguments
+/// * `targets` -- Stars and planets to search for.
+/// * `start_date_day` -- Start of search (day, Universal Time).
+/// * `start_date_month` -- Start of search (month, Universal Time).
+/// * `start_date_year` -- Start of search (year, Universal Time).
+/// * `number_of_days` -- Length of search, in days.
+/// * `geog_longitude_deg` -- Geographical longitude of observer (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of observer.
+/// * `height_m` -- Height of observer above sea level, in metres.
+///
+/// ## Returns
+/// list of Occultation structures
+pub fn lunar_occultations(
+    targets: &[OccultationTarget],
+    start_date_day: f64,
+    start_date_month: u32,
+    start_date_year: u32,
+    number_of_days: u32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) -> Vec<Occultation> {
+    let observer = (geog_longitude_deg, geog_latitude_deg, height_m);
+    let start_jd_tt = pa_m::cd_jd(start_date_day, start_date_month, start_date_year);
+    let mut occultations: Vec<Occultation> = Vec::new();
+
+    for target in targets.iter() {
+        for conjunction_jd_tt in conjunctions(target, start_jd_tt, number_of_days) {
+            let depth = |t: f64| {
+                let (x, y, d, mu) = occultation_elements(target, conjunction_jd_tt + t / 24.0);
+                let (xi, eta, _zeta) =
+                    pa_e::observer_fundamental_plane_coordinates(&observer, d, mu);
+                MOON_RADIUS - ((x - xi) * (x - xi) + (y - eta) * (y - eta)).sqrt()
+            };
+
+            if let Some((disappearance_t, reappearance_t)) = pa_e::crossing_times(&depth, -3.0, 3.0)
+            {
+                let disappearance = occultation_contact(
+                    target,
+                    conjunction_jd_tt + disappearance_t / 24.0,
+                    &observer,
+                );
+                let reappearance = occultation_contact(
+                    target,
+                    conjunction_jd_tt + reappearance_t / 24.0,
+                    &observer,
+                );
+
+                if disappearance.moon_altitude_deg > 0.0 || reappearance.moon_altitude_deg > 0.0 {
+                    occultations.push(Occultation {
+                        target: target.clone(),
+                        conjunction_jd_tt,
+                        disappearance,
+                        reappearance,
+                    });
+                }
+            }
+        }
+    }
+
+    occultations.sort_by(|a, b| {
+        a.disappearance
+            .jd_ut
+            .partial_cmp(&b.disappearance.jd_ut)
+            .unwrap()
+    });
+
+    return occultations;
+}
+
+/// Calculate the northern and southern graze lines of an occultation.
+///
+/// Along a graze line the body just touches the Moon's limb. Points are (longitude east,
+/// latitude) in degrees, ignoring the Moon's limb profile.
+///
+/// ## Arguments
+/// * `occultation` -- Occultation from `lunar_occultations`.
+/// * `step_minutes` -- Time between points along each line.
+///
+/// ## Returns
+/// * northern graze line
+/// * southern graze line
+pub fn occultation_graze_lines(
+    occultation: &Occultation,
+    step_minutes: f64,
+) -> (Vec<(f64, f64)>, Vec<(f64, f64)>) {
+    let mut northern_limit: Vec<(f64, f64)> = Vec::new();
+    let mut southern_limit: Vec<(f64, f64)> = Vec::new();
+
+    let steps = (360.0 / step_minutes).floor() as i32;
+    for step in 0..=steps {
+        let t = -3.0 + step as f64 * step_minutes / 60.0;
+        let jd_tt = occultation.conjunction_jd_tt + t / 24.0;
+        let (x, y, d, mu) = occultation_elements(&occultation.target, jd_tt);
+
+        // Offset the shadow center perpendicular to its motion by the Moon's radius.
+        let (x_before, y_before, _d, _mu) =
+            occultation_elements(&occultation.target, jd_tt - 0.5 / 1440.0);
+        let (x_after, y_after, _d, _mu) =
+            occultation_elements(&occultation.target, jd_tt + 0.5 / 1440.0);
+        let x_rate = x_after - x_before;
+        let y_rate = y_after - y_before;
+        let rate = (x_rate * x_rate + y_rate * y_rate).sqrt();
+        let (mut normal_x, mut normal_y) = (-y_rate / rate, x_rate / rate);
+        if normal_y < 0.0 {
+            normal_x = -normal_x;
+            normal_y = -normal_y;
+        }
+
+        if let Some(point) = pa_e::fundamental_plane_ground_point(
+            d,
+            mu,
+            x + MOON_RADIUS * normal_x,
+            y + MOON_RADIUS * normal_y,
+        ) {
+            northern_limit.push(pa_e::geographic_position(&point));
+        }
+        if let Some(point) = pa_e::fundamental_plane_ground_point(
+            d,
+            mu,
+            x - MOON_RADIUS * normal_x,
+            y - MOON_RADIUS * normal_y,
+        ) {
+            southern_limit.push(pa_e::geographic_position(&point));
+        }
+    }
+
+    return (northern_limit, southern_limit);
+}
+
+/// Geocentric apparent equatorial position of a target (Earth radii) at a Julian date (TT).
+///
+/// Stars are placed at a great distance, after precession, nutation and annual aberration.
+fn target_position(target: &OccultationTarget, jd_tt: f64) -> pa_f::Vector3 {
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    match target.kind {
+        pa_t::OccultationTargetKind::Planet => {
+            let (longitude_deg, latitude_deg, distance_au, _, _, _, _) = pa_m::planet_coordinates(
+                hours,
+                0.0,
+                0.0,
+                0,
+                0,
+                gd,
+                gm,
+                gy,
+                target.name.to_string(),
+            );
+            let ecliptic = pa_f::spherical_to_vector(longitude_deg, latitude_deg);
+            let equatorial = pa_f::rotate_vector(
+                &pa_f::rotation_matrix_x(-pa_m::obliq(gd, gm, gy)),
+                &ecliptic,
+            );
+            let distance = distance_au * 149597870.7 / 6378.137;
+
+            return pa_f::Vector3 {
+                x: equatorial.x * distance,
+                y: equatorial.y * distance,
+                z: equatorial.z * distance,
+            };
+        }
+        pa_t::OccultationTargetKind::Star => {
+            let catalogue = pa_f::spherical_to_vector(
+                target.right_ascension_hours * 15.0,
+                target.declination_deg,
+            );
+            let of_date = pa_f::rotate_vector(
+                &pa_f::transform(
+                    pa_t::ReferenceFrame::Icrs,
+                    pa_t::ReferenceFrame::TrueOfDate,
+                    jd_tt,
+                    &pa_f::Observer {
+                        geographical_longitude_deg: 0.0,
+                        geographical_latitude_deg: 0.0,
+                    },
+                ),
+                &catalogue,
+            );
+
+            // Earth's velocity (Earth radii per day) from the Sun's apparent motion.
+            let (sun_before, _moon) = pa_e::sun_and_moon_position(jd_tt - 0.5);
+            let (sun_after, _moon) = pa_e::sun_and_moon_position(jd_tt + 0.5);
+            let speed_of_light = 173.1446327 * 149597870.7 / 6378.137;
+            let apparent = pa_f::Vector3 {
+                x: of_date.x - (sun_after.x - sun_before.x) / speed_of_light,
+                y: of_date.y - (sun_after.y - sun_before.y) / speed_of_light,
+                z: of_date.z - (sun_after.z - sun_before.z) / speed_of_light,
+            };
+            let length =
+                (apparent.x * apparent.x + apparent.y * apparent.y + apparent.z * apparent.z)
+                    .sqrt();
+            let distance = 1.0e10;
+
+            return pa_f::Vector3 {
+                x: apparent.x / length * distance,
+                y: apparent.y / length * distance,
+                z: apparent.z / length * distance,
+            };
+        }
+    }
+}
+
+/// Occultation elements at an instant: x, y (Earth radii), and declination d and Greenwich hour
+/// angle mu (degrees) of the axis through the Moon from the target.
+fn occultation_elements(target: &OccultationTarget, jd_tt: f64) -> (f64, f64, f64, f64) {
+    let (_sun, moon) = pa_e::sun_and_moon_position(jd_tt);
+    let body = target_position(target, jd_tt);
+
+    let g = pa_f::Vector3 {
+        x: body.x - moon.x,
+        y: body.y - moon.y,
+        z: body.z - moon.z,
+    };
+    let (a_deg, d_deg) = pa_f::vector_to_spherical(&g);
+    let a = a_deg.to_radians();
+    let d = d_deg.to_radians();
+
+    let x = -moon.x * a.sin() + moon.y * a.cos();
+    let y = -moon.x * d.sin() * a.cos() - moon.y * d.sin() * a.sin() + moon.z * d.cos();
+
+    let mu = pa_e::apparent_sidereal_time_hours(jd_tt - pa_dt::delta_t_days(jd_tt)) * 15.0 - a_deg;
+
+    return (x, y, d_deg, pa_m::unwind_deg(mu));
+}
+
+/// Times (Julian date, TT) of geocentric conjunctions of the Moon with a target close enough
+/// for an occultation to be seen somewhere on the Earth.
+fn conjunctions(target: &OccultationTarget, start_jd_tt: f64, number_of_days: u32) -> Vec<f64> {
+    let separation = |jd_tt: f64| {
+        let (_sun, moon) = pa_e::sun_and_moon_position(jd_tt);
+        angle_between(&moon, &target_position(target, jd_tt))
+    };
+
+    let mut conjunctions: Vec<f64> = Vec::new();
+    let hours = number_of_days * 24;
+    let mut previous = separation(start_jd_tt - 1.0 / 24.0);
+    let mut current = separation(start_jd_tt);
+
+    for hour in 1..=hours {
+        let jd_tt = start_jd_tt + hour as f64 / 24.0;
+        let next = separation(jd_tt);
+
+        if current <= previous && current < next {
+            let mut low = jd_tt - 2.0 / 24.0;
+            let mut high = jd_tt;
+            for _ in 0..60 {
+                let m1 = low + (high - low) / 3.0;
+                let m2 = high - (high - low) / 3.0;
+                if separation(m1) < separation(m2) {
+                    high = m2;
+                } else {
+                    low = m1;
+                }
+            }
+            let conjunction_jd_tt = (low + high) / 2.0;
+
+            // Horizontal parallax plus semidiameter of the Moon, with a margin.
+            if separation(conjunction_jd_tt) < 1.5 {
+                conjunctions.push(conjunction_jd_tt);
+            }
+        }
+
+        previous = current;
+        current = next;
+    }
+
+    return conjunctions;
+}
+
+/// Disappearance or reappearance circumstances at a Julian date (TT).
+fn occultation_contact(
+    target: &OccultationTarget,
+    jd_tt: f64,
+    observer: &(f64, f64, f64),
+) -> OccultationContact {
+    let (longitude_deg, latitude_deg, _height_m) = *observer;
+    let (x, y, d, mu) = occultation_elements(target, jd_tt);
+    let (xi, eta, _zeta) = pa_e::observer_fundamental_plane_coordinates(observer, d, mu);
+
+    let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+    let day = pa_m::jdc_day(jd_ut);
+    let ut_hours = (day - day.floor()) * 24.0;
+
+    let position_angle_deg = pa_m::unwind_deg((xi - x).atan2(eta - y).to_degrees());
+
+    // The bright limb is centred on the position angle of the Sun seen from the Moon, and the
+    // cusps lie 90 degrees either side of it.
+    let (sun, moon) = pa_e::sun_and_moon_position(jd_tt);
+    let (sun_ra_deg, sun_dec_deg) = pa_f::vector_to_spherical(&sun);
+    let (moon_ra_deg, moon_dec_deg) = pa_f::vector_to_spherical(&moon);
+    let sun_dec = sun_dec_deg.to_radians();
+    let moon_dec = moon_dec_deg.to_radians();
+    let ra_difference = (sun_ra_deg - moon_ra_deg).to_radians();
+    let bright_limb_deg = (sun_dec.cos() * ra_difference.sin())
+        .atan2(
+            sun_dec.sin() * moon_dec.cos() - sun_dec.cos() * moon_dec.sin() * ra_difference.cos(),
+        )
+        .to_degrees();
+
+    let from_bright_limb_deg =
+        pa_m::unwind_deg(position_angle_deg - bright_limb_deg + 180.0) - 180.0;
+    let cusp_deg = bright_limb_deg + 90.0_f64.copysign(from_bright_limb_deg);
+
+    return OccultationContact {
+        jd_ut,
+        date_day: day.floor(),
+        date_month: pa_m::jdc_month(jd_ut),
+        date_year: pa_m::jdc_year(jd_ut),
+        ut_hour: pa_m::dh_hour(ut_hours),
+        ut_minutes: pa_m::dh_min(ut_hours),
+        ut_seconds: pa_m::dh_sec(ut_hours),
+        position_angle_deg,
+        cusp_angle_deg: (from_bright_limb_deg.abs() - 90.0).abs(),
+        is_north_cusp: cusp_deg.to_radians().cos() > 0.0,
+        is_dark_limb: from_bright_limb_deg.abs() > 90.0,
+        moon_altitude_deg: topocentric_altitude(&moon, jd_ut, longitude_deg, latitude_deg),
+        sun_altitude_deg: topocentric_altitude(&sun, jd_ut, longitude_deg, latitude_deg),
+    };
+}
+
+/// Altitude (degrees, without refraction) of a geocentric position in Earth radii, corrected for
+/// parallax.
+fn topocentric_altitude(
+    position: &pa_f::Vector3,
+    jd_ut: f64,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+) -> f64 {
+    let distance =
+        (position.x * position.x + position.y * position.y + position.z * position.z).sqrt();
+    let (right_ascension_deg, declination_deg) = pa_f::vector_to_spherical(position);
+
+    let hour_angle = (pa_e::apparent_sidereal_time_hours(jd_ut) * 15.0 + geog_longitude_deg
+        - right_ascension_deg)
+        .to_radians();
+    let declination = declination_deg.to_radians();
+    let latitude = geog_latitude_deg.to_radians();
+
+    let altitude = (latitude.sin() * declination.sin()
+        + latitude.cos() * declination.cos() * hour_angle.cos())
+    .asin();
+
+    return (altitude - (altitude.cos() / distance).asin()).to_degrees();
+}
+
+/// Angle (degrees) between two vectors.
+fn angle_between(a: &pa_f::Vector3, b: &pa_f::Vector3) -> f64 {
+    let cross = pa_f::cross_product(a, b);
+    let sine = (cross.x * cross.x + cross.y * cross.y + cross.z * cross.z).sqrt();
+    let cosine = a.x * b.x + a.y * b.y + a.z * b.z;
+
+    return sine.atan2(cosine).to_degrees();
+}
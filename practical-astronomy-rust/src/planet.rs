@@ -1,5 +1,9 @@
+use crate::datetime as pa_dt;
+use crate::frames as pa_f;
 use crate::macros as pa_m;
 use crate::planetdata as pa_pd;
+use crate::riseset as pa_rs;
+use crate::types as pa_t;
 use crate::util as pa_u;
 
 /// Calculate approximate position of a planet.
@@ -338,6 +342,53 @@ pub fn visual_aspects_of_a_planet(
     local_date_month: u32,
     local_date_year: u32,
     planet_name: String,
+) -> (f64, f64, f64, f64, f64, f64, f64, f64) {
+    return visual_aspects_of_a_planet_by_model(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        is_daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        planet_name,
+        pa_t::MagnitudeModel::Book,
+    );
+}
+
+/// Calculate several visual aspects of a planet, with a selectable magnitude model.
+///
+/// The Mallama & Hilton (2018) model uses the phase angle, with separate phase curves for
+/// Mercury, Venus, Mars and Jupiter, the ring tilt for Saturn, the sub-Earth latitude for Uranus
+/// and the secular change for Neptune. The Mars corrections for orbital and rotational longitude
+/// are not applied.
+///
+/// ## Arguments
+/// * `lct_hour` -- Local civil time, hour part.
+/// * `lct_min` -- Local civil time, minutes part.
+/// * `lct_sec` -- Local civil time, seconds part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `planet_name` -- Name of planet, e.g., "Jupiter".
+/// * `magnitude_model` -- Model for the apparent magnitude.
+///
+/// ## Returns
+/// * as `visual_aspects_of_a_planet`
+pub fn visual_aspects_of_a_planet_by_model(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    planet_name: String,
+    magnitude_model: pa_t::MagnitudeModel,
 ) -> (f64, f64, f64, f64, f64, f64, f64, f64) {
     let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
 
@@ -463,8 +514,50 @@ pub fn visual_aspects_of_a_planet(
 
     let chi_deg = pa_m::degrees(y.atan2(x));
     let radius_vector_au = planet_r_vect;
-    let approximate_magnitude1 =
-        5.0 * (radius_vector_au * planet_dist_au / (phase1).sqrt()).log10() + planet_info.v0;
+    let approximate_magnitude1 = match magnitude_model {
+        pa_t::MagnitudeModel::Book => {
+            5.0 * (radius_vector_au * planet_dist_au / (phase1).sqrt()).log10() + planet_info.v0
+        }
+        pa_t::MagnitudeModel::MallamaHilton2018 => {
+            let sun_dist_au = pa_m::sun_dist(
+                lct_hour,
+                lct_min,
+                lct_sec,
+                daylight_saving,
+                zone_correction_hours,
+                local_date_day,
+                local_date_month,
+                local_date_year,
+            );
+            let ut = pa_m::lct_ut(
+                lct_hour,
+                lct_min,
+                lct_sec,
+                daylight_saving,
+                zone_correction_hours,
+                local_date_day,
+                local_date_month,
+                local_date_year,
+            );
+            let jd_ut = pa_m::cd_jd(
+                greenwich_date_day,
+                greenwich_date_month,
+                greenwich_date_year,
+            ) + ut / 24.0;
+
+            mallama_hilton_magnitude(
+                &planet_name,
+                radius_vector_au,
+                planet_dist_au,
+                sun_dist_au,
+                jd_ut,
+            )
+            .unwrap_or(
+                5.0 * (radius_vector_au * planet_dist_au / (phase1).sqrt()).log10()
+                    + planet_info.v0,
+            )
+        }
+    };
 
     let distance_au = pa_u::round_f64(planet_dist_au, 5);
     let ang_dia_arcsec = pa_u::round_f64(angular_diameter_arcsec, 1);
@@ -486,3 +579,869 @@ pub fn visual_aspects_of_a_planet(
         approximate_magnitude,
     );
 }
+
+/// Apparent magnitude of a planet from Mallama & Hilton (2018), given its distances from the Sun
+/// and the Earth and the distance of the Sun from the Earth (AU), at a Julian date (UT). None if
+/// there is no formula for the planet.
+fn mallama_hilton_magnitude(
+    planet_name: &str,
+    radius_vector_au: f64,
+    distance_au: f64,
+    sun_distance_au: f64,
+    jd_ut: f64,
+) -> Option<f64> {
+    let cos_phase_angle = (radius_vector_au * radius_vector_au + distance_au * distance_au
+        - sun_distance_au * sun_distance_au)
+        / (2.0 * radius_vector_au * distance_au);
+    let a = cos_phase_angle.clamp(-1.0, 1.0).acos().to_degrees();
+    let distance_term = 5.0 * (radius_vector_au * distance_au).log10();
+
+    let magnitude = match planet_name {
+        "Mercury" => {
+            -0.613 + 6.328e-2 * a - 1.6336e-3 * a.powi(2) + 3.3644e-5 * a.powi(3)
+                - 3.4265e-7 * a.powi(4)
+                + 1.6893e-9 * a.powi(5)
+                - 3.0334e-12 * a.powi(6)
+        }
+        "Venus" => {
+            if a < 163.7 {
+                -4.384 - 1.044e-3 * a + 3.687e-4 * a.powi(2) - 2.814e-6 * a.powi(3)
+                    + 8.938e-9 * a.powi(4)
+            } else {
+                236.05828 - 2.81914 * a + 8.39034e-3 * a.powi(2)
+            }
+        }
+        "Mars" => {
+            if a <= 50.0 {
+                -1.601 + 2.267e-2 * a - 1.302e-4 * a.powi(2)
+            } else {
+                -0.367 - 0.02573 * a + 3.445e-4 * a.powi(2)
+            }
+        }
+        "Jupiter" => {
+            if a <= 12.0 {
+                -9.395 - 3.7e-4 * a + 6.16e-4 * a.powi(2)
+            } else {
+                let x = a / 180.0;
+                -9.428
+                    - 2.5
+                        * (1.0 - 1.507 * x - 0.363 * x.powi(2) - 0.062 * x.powi(3)
+                            + 2.809 * x.powi(4)
+                            - 1.876 * x.powi(5))
+                        .log10()
+            }
+        }
+        "Saturn" => {
+            // Globe and rings, with the ring tilt seen from the Earth.
+            let (ring_tilt_deg, _, _, _, _) = planet_orientation("Saturn", jd_ut)?;
+            let sin_tilt = ring_tilt_deg.to_radians().sin().abs();
+
+            -8.914 - 1.825 * sin_tilt + 0.026 * a - 0.378 * sin_tilt * (-2.25 * a).exp()
+        }
+        "Uranus" => {
+            // Planetographic latitude of the Earth, from the IAU north pole (ICRS).
+            let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+            let (planet_long_deg, planet_lat_deg, _, _, _, _) =
+                planet_and_sun_at(planet_name, jd_ut);
+            let pole = pa_f::rotate_vector(
+                &pa_f::transform(
+                    pa_t::ReferenceFrame::Icrs,
+                    pa_t::ReferenceFrame::EclipticOfDate,
+                    jd_tt,
+                    &pa_f::Observer {
+                        geographical_longitude_deg: 0.0,
+                        geographical_latitude_deg: 0.0,
+                    },
+                ),
+                &pa_f::spherical_to_vector(257.311, -15.175),
+            );
+            let planet = pa_f::spherical_to_vector(planet_long_deg, planet_lat_deg);
+            let planetocentric_latitude =
+                (-(pole.x * planet.x + pole.y * planet.y + pole.z * planet.z)).asin();
+            let planetographic_latitude_deg = (planetocentric_latitude.tan()
+                / (1.0 - 0.0229_f64).powi(2))
+            .atan()
+            .to_degrees();
+
+            -7.11 - 8.4e-4 * planetographic_latitude_deg.abs() + 6.587e-3 * a + 1.045e-4 * a.powi(2)
+        }
+        "Neptune" => {
+            let year = 2000.0 + (jd_ut - 2451545.0) / 365.25;
+            if year > 2000.0 {
+                -7.0
+            } else if year > 1980.0 {
+                -6.89 - 0.0054 * (year - 1980.0)
+            } else {
+                -6.89
+            }
+        }
+        _ => return None,
+    };
+
+    return Some(distance_term + magnitude);
+}
+
+/// Info about a planetary phenomenon:
+/// * `kind` -- Kind of phenomenon.
+/// * `planet_name` -- Name of the planet.
+/// * `other_body_name` -- Body the planet is in conjunction with ("Sun" for phenomena relative to the Sun, empty for stations and apsides).
+/// * `jd_ut` -- Julian date of the phenomenon (Universal Time).
+/// * `local_date_day` -- Local date of the phenomenon, day part.
+/// * `local_date_month` -- Local date of the phenomenon, month part.
+/// * `local_date_year` -- Local date of the phenomenon, year part.
+/// * `lct_hour` -- Local civil time of the phenomenon, hour part.
+/// * `lct_minutes` -- Local civil time of the phenomenon, minutes part.
+/// * `lct_seconds` -- Local civil time of the phenomenon, seconds part.
+/// * `elongation_deg` -- Angular distance of the planet from the Sun, in degrees.
+/// * `separation_deg` -- Angular distance of the planet from the other body, in degrees (-99.0 for stations and apsides).
+/// * `distance_au` -- Distance of the planet from the Earth, in AU.
+/// * `heliocentric_distance_au` -- Distance of the planet from the Sun, in AU.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanetaryPhenomenon {
+    pub kind: pa_t::PlanetaryPhenomenonKind,
+    pub planet_name: String,
+    pub other_body_name: String,
+    pub jd_ut: f64,
+    pub local_date_day: u32,
+    pub local_date_month: u32,
+    pub local_date_year: u32,
+    pub lct_hour: u32,
+    pub lct_minutes: u32,
+    pub lct_seconds: f64,
+    pub elongation_deg: f64,
+    pub separation_deg: f64,
+    pub distance_au: f64,
+    pub heliocentric_distance_au: f64,
+}
+
+/// Search a range of local dates for the phenomena of a planet, in time order: conjunctions with
+/// the Sun (inferior or superior), oppositions, greatest eastern and western elongations,
+/// stationary points (start and end of retrograde motion), perihelia and aphelia. Greatest
+/// elongations are only searched for Mercury and Venus.
+///
+/// Conjunctions and oppositions are in geocentric ecliptic longitude. Positions come from the
+/// same theory as `precise_position_of_planet`.
+///
+/// ## Arguments
+/// * `planet_name` -- Name of planet, e.g., "Jupiter".
+/// * `start_date_day` -- First local date of the range, day part.
+/// * `start_date_month` -- First local date of the range, month part.
+/// * `start_date_year` -- First local date of the range, year part.
+/// * `end_date_day` -- Last local date of the range (inclusive), day part.
+/// * `end_date_month` -- Last local date of the range (inclusive), month part.
+/// * `end_date_year` -- Last local date of the range (inclusive), year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+///
+/// ## Returns
+/// * Iterator over `PlanetaryPhenomenon`s (empty if the planet is not found).
+pub fn planetary_phenomena(
+    planet_name: String,
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) -> std::vec::IntoIter<PlanetaryPhenomenon> {
+    let (_planet_info, planet_info_status) = pa_pd::get_planet_info_vector(planet_name.clone());
+    if planet_info_status != "OK" {
+        return Vec::new().into_iter();
+    }
+
+    let local_offset_days = pa_dt::local_offset_days(is_daylight_saving, zone_correction_hours);
+    let start_jd_ut = pa_dt::local_date_start_jd_ut(
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        local_offset_days,
+    );
+    let end_jd_ut = pa_dt::local_date_start_jd_ut(
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        local_offset_days,
+    ) + 1.0;
+    let step = 1.0;
+    let delta = 0.05;
+
+    let mut events: Vec<PlanetaryPhenomenon> = Vec::new();
+    let mut push_event =
+        |kind: pa_t::PlanetaryPhenomenonKind, other_body_name: &str, jd_ut: f64| {
+            events.push(planetary_phenomenon(
+                kind,
+                &planet_name,
+                other_body_name,
+                jd_ut,
+                local_offset_days,
+            ));
+        };
+
+    // Conjunctions with the Sun, and oppositions.
+    for target_deg in [0.0, 180.0] {
+        let relative_longitude = |jd_ut: f64| {
+            let (planet_long_deg, _, _, _, sun_long_deg, _) =
+                planet_and_sun_at(&planet_name, jd_ut);
+            pa_u::wrap_deg(planet_long_deg - sun_long_deg - target_deg)
+        };
+        for (jd_ut, _) in
+            pa_rs::zero_crossings_with_step(&relative_longitude, start_jd_ut, end_jd_ut, step)
+        {
+            // Skip the jump from +180 to -180 degrees.
+            if relative_longitude(jd_ut).abs() > 1.0 {
+                continue;
+            }
+
+            let kind = if target_deg == 180.0 {
+                pa_t::PlanetaryPhenomenonKind::Opposition
+            } else if planet_and_sun_at(&planet_name, jd_ut).2 < 1.0 {
+                pa_t::PlanetaryPhenomenonKind::InferiorConjunction
+            } else {
+                pa_t::PlanetaryPhenomenonKind::SuperiorConjunction
+            };
+            push_event(kind, "Sun", jd_ut);
+        }
+    }
+
+    // Greatest elongations of Mercury and Venus: maxima of the angular distance from the Sun.
+    let is_inferior_planet = planet_name == "Mercury" || planet_name == "Venus";
+    let elongation_rate = |jd_ut: f64| {
+        elongation_at(&planet_name, jd_ut + delta) - elongation_at(&planet_name, jd_ut - delta)
+    };
+    if is_inferior_planet {
+        for (jd_ut, is_rising) in
+            pa_rs::zero_crossings_with_step(&elongation_rate, start_jd_ut, end_jd_ut, step)
+        {
+            if is_rising {
+                continue;
+            }
+
+            let (planet_long_deg, _, _, _, sun_long_deg, _) =
+                planet_and_sun_at(&planet_name, jd_ut);
+            push_event(
+                if pa_u::wrap_deg(planet_long_deg - sun_long_deg) > 0.0 {
+                    pa_t::PlanetaryPhenomenonKind::GreatestEasternElongation
+                } else {
+                    pa_t::PlanetaryPhenomenonKind::GreatestWesternElongation
+                },
+                "Sun",
+                jd_ut,
+            );
+        }
+    }
+
+    // Stationary points: the geocentric longitude stops increasing or decreasing.
+    let longitude_rate = |jd_ut: f64| {
+        pa_u::wrap_deg(
+            planet_and_sun_at(&planet_name, jd_ut + delta).0
+                - planet_and_sun_at(&planet_name, jd_ut - delta).0,
+        )
+    };
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&longitude_rate, start_jd_ut, end_jd_ut, step)
+    {
+        push_event(
+            if is_rising {
+                pa_t::PlanetaryPhenomenonKind::StationaryDirect
+            } else {
+                pa_t::PlanetaryPhenomenonKind::StationaryRetrograde
+            },
+            "",
+            jd_ut,
+        );
+    }
+
+    // Apsides: extremes of the distance from the Sun.
+    let heliocentric_distance_rate = |jd_ut: f64| {
+        planet_and_sun_at(&planet_name, jd_ut + delta).3
+            - planet_and_sun_at(&planet_name, jd_ut - delta).3
+    };
+    for (jd_ut, is_rising) in
+        pa_rs::zero_crossings_with_step(&heliocentric_distance_rate, start_jd_ut, end_jd_ut, step)
+    {
+        push_event(
+            if is_rising {
+                pa_t::PlanetaryPhenomenonKind::Perihelion
+            } else {
+                pa_t::PlanetaryPhenomenonKind::Aphelion
+            },
+            "",
+            jd_ut,
+        );
+    }
+
+    events.sort_by(|a, b| a.jd_ut.partial_cmp(&b.jd_ut).unwrap());
+
+    return events.into_iter();
+}
+
+/// Search a range of local dates for the conjunctions of a planet with another planet or with
+/// the Moon.
+///
+/// Each conjunction in geocentric ecliptic longitude is reported at the least (geocentric)
+/// separation of the two bodies near it.
+///
+/// ## Arguments
+/// * `planet_name` -- Name of planet, e.g., "Jupiter".
+/// * `other_body_name` -- Name of the other planet, or "Moon".
+/// * `start_date_day` -- First local date of the range, day part.
+/// * `start_date_month` -- First local date of the range, month part.
+/// * `start_date_year` -- First local date of the range, year part.
+/// * `end_date_day` -- Last local date of the range (inclusive), day part.
+/// * `end_date_month` -- Last local date of the range (inclusive), month part.
+/// * `end_date_year` -- Last local date of the range (inclusive), year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+///
+/// ## Returns
+/// * Iterator over `PlanetaryPhenomenon`s of kind `Conjunction` (empty if a body is not found).
+pub fn planetary_conjunctions(
+    planet_name: String,
+    other_body_name: String,
+    start_date_day: u32,
+    start_date_month: u32,
+    start_date_year: u32,
+    end_date_day: u32,
+    end_date_month: u32,
+    end_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) -> std::vec::IntoIter<PlanetaryPhenomenon> {
+    let (_planet_info, planet_info_status) = pa_pd::get_planet_info_vector(planet_name.clone());
+    let (_other_info, other_info_status) = pa_pd::get_planet_info_vector(other_body_name.clone());
+    let is_moon = other_body_name == "Moon";
+    if planet_info_status != "OK" || (other_info_status != "OK" && !is_moon) {
+        return Vec::new().into_iter();
+    }
+
+    let local_offset_days = pa_dt::local_offset_days(is_daylight_saving, zone_correction_hours);
+    let start_jd_ut = pa_dt::local_date_start_jd_ut(
+        start_date_day,
+        start_date_month,
+        start_date_year,
+        local_offset_days,
+    );
+    let end_jd_ut = pa_dt::local_date_start_jd_ut(
+        end_date_day,
+        end_date_month,
+        end_date_year,
+        local_offset_days,
+    ) + 1.0;
+    let (step, window) = if is_moon { (0.25, 0.5) } else { (1.0, 5.0) };
+
+    let relative_longitude = |jd_ut: f64| {
+        pa_u::wrap_deg(
+            body_ecliptic_at(&planet_name, jd_ut).0 - body_ecliptic_at(&other_body_name, jd_ut).0,
+        )
+    };
+    let separation = |jd_ut: f64| separation_at(&planet_name, &other_body_name, jd_ut);
+
+    let mut events: Vec<PlanetaryPhenomenon> = Vec::new();
+    for (jd_ut, _) in
+        pa_rs::zero_crossings_with_step(&relative_longitude, start_jd_ut, end_jd_ut, step)
+    {
+        // Skip the jump from +180 to -180 degrees.
+        if relative_longitude(jd_ut).abs() > 1.0 {
+            continue;
+        }
+
+        let mut low = jd_ut - window;
+        let mut high = jd_ut + window;
+        for _ in 0..60 {
+            let m1 = low + (high - low) / 3.0;
+            let m2 = high - (high - low) / 3.0;
+            if separation(m1) < separation(m2) {
+                high = m2;
+            } else {
+                low = m1;
+            }
+        }
+        let closest_jd_ut = (low + high) / 2.0;
+
+        events.push(planetary_phenomenon(
+            pa_t::PlanetaryPhenomenonKind::Conjunction,
+            &planet_name,
+            &other_body_name,
+            closest_jd_ut,
+            local_offset_days,
+        ));
+    }
+
+    return events.into_iter();
+}
+
+/// Info about the physical ephemeris of Mars, Jupiter or Saturn:
+/// * `planet_name` -- Name of the planet.
+/// * `status` -- "OK", "NotSupported" (no rotation model for the planet) or "NotFound".
+/// * `sub_earth_latitude_deg` -- Planetocentric latitude of the Earth, in degrees (for Saturn, the ring tilt B).
+/// * `sub_solar_latitude_deg` -- Planetocentric latitude of the Sun, in degrees (for Saturn, the ring tilt B').
+/// * `position_angle_of_axis_deg` -- Position angle of the planet's north pole, from north through east, in degrees.
+/// * `central_meridian_deg` -- Longitude of the central meridian, in degrees (System III for Jupiter and Saturn).
+/// * `central_meridian_system_i_deg` -- Jupiter: longitude of the central meridian in System I, in degrees (-99.0 for other planets).
+/// * `central_meridian_system_ii_deg` -- Jupiter: longitude of the central meridian in System II, in degrees (-99.0 for other planets).
+/// * `ring_major_axis_arcsec` -- Saturn: major axis of the outer edge of the rings, in arcseconds (-99.0 for other planets).
+/// * `ring_minor_axis_arcsec` -- Saturn: minor axis of the outer edge of the rings, in arcseconds (-99.0 for other planets).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanetaryPhysicalEphemeris {
+    pub planet_name: String,
+    pub status: String,
+    pub sub_earth_latitude_deg: f64,
+    pub sub_solar_latitude_deg: f64,
+    pub position_angle_of_axis_deg: f64,
+    pub central_meridian_deg: f64,
+    pub central_meridian_system_i_deg: f64,
+    pub central_meridian_system_ii_deg: f64,
+    pub ring_major_axis_arcsec: f64,
+    pub ring_minor_axis_arcsec: f64,
+}
+
+/// Calculate the physical ephemeris of Mars, Jupiter or Saturn: central meridian longitudes,
+/// latitudes of the Earth and the Sun on the planet, position angle of the axis and, for
+/// Saturn, the geometry of the rings.
+///
+/// Uses the IAU rotational elements (WGCCRE 2009) with light time, and the planet positions of
+/// `precise_position_of_planet`.
+///
+/// ## Arguments
+/// * `lct_hour` -- Local civil time, hour part.
+/// * `lct_min` -- Local civil time, minutes part.
+/// * `lct_sec` -- Local civil time, seconds part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `planet_name` -- "Mars", "Jupiter" or "Saturn".
+///
+/// ## Returns
+/// * PlanetaryPhysicalEphemeris structure (angles rounded to 2 decimal places).
+pub fn planetary_physical_ephemeris(
+    lct_hour: f64,
+    lct_min: f64,
+    lct_sec: f64,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    planet_name: String,
+) -> PlanetaryPhysicalEphemeris {
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+
+    let ut = pa_m::lct_ut(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gd = pa_m::lct_gday(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gm = pa_m::lct_gmonth(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let gy = pa_m::lct_gyear(
+        lct_hour,
+        lct_min,
+        lct_sec,
+        daylight_saving,
+        zone_correction_hours,
+        local_date_day,
+        local_date_month,
+        local_date_year,
+    );
+    let jd_ut = pa_m::cd_jd(gd, gm, gy) + ut / 24.0;
+
+    let (_planet_info, planet_info_status) = pa_pd::get_planet_info_vector(planet_name.clone());
+    let orientation = planet_orientation(&planet_name, jd_ut);
+    let status = if planet_info_status != "OK" {
+        "NotFound"
+    } else if orientation.is_none() {
+        "NotSupported"
+    } else {
+        "OK"
+    };
+
+    return match orientation {
+        Some((
+            sub_earth_latitude_deg,
+            sub_solar_latitude_deg,
+            position_angle_of_axis_deg,
+            central_meridians_deg,
+            distance_au,
+        )) => {
+            let is_jupiter = planet_name == "Jupiter";
+            let is_saturn = planet_name == "Saturn";
+            let ring_major_axis_arcsec = 375.35 / distance_au;
+
+            PlanetaryPhysicalEphemeris {
+                planet_name,
+                status: status.to_string(),
+                sub_earth_latitude_deg: pa_u::round_f64(sub_earth_latitude_deg, 2),
+                sub_solar_latitude_deg: pa_u::round_f64(sub_solar_latitude_deg, 2),
+                position_angle_of_axis_deg: pa_u::round_f64(position_angle_of_axis_deg, 2),
+                central_meridian_deg: pa_u::round_f64(central_meridians_deg[0], 2),
+                central_meridian_system_i_deg: if is_jupiter {
+                    pa_u::round_f64(central_meridians_deg[1], 2)
+                } else {
+                    -99.0
+                },
+                central_meridian_system_ii_deg: if is_jupiter {
+                    pa_u::round_f64(central_meridians_deg[2], 2)
+                } else {
+                    -99.0
+                },
+                ring_major_axis_arcsec: if is_saturn {
+                    pa_u::round_f64(ring_major_axis_arcsec, 2)
+                } else {
+                    -99.0
+                },
+                ring_minor_axis_arcsec: if is_saturn {
+                    pa_u::round_f64(
+                        ring_major_axis_arcsec * sub_earth_latitude_deg.to_radians().sin().abs(),
+                        2,
+                    )
+                } else {
+                    -99.0
+                },
+            }
+        }
+        None => PlanetaryPhysicalEphemeris {
+            planet_name,
+            status: status.to_string(),
+            sub_earth_latitude_deg: -99.0,
+            sub_solar_latitude_deg: -99.0,
+            position_angle_of_axis_deg: -99.0,
+            central_meridian_deg: -99.0,
+            central_meridian_system_i_deg: -99.0,
+            central_meridian_system_ii_deg: -99.0,
+            ring_major_axis_arcsec: -99.0,
+            ring_minor_axis_arcsec: -99.0,
+        },
+    };
+}
+
+/// Calculate the times on a local date when Jupiter's Great Red Spot crosses the central
+/// meridian.
+///
+/// The Great Red Spot drifts in System II longitude, so its current longitude must be supplied
+/// (from recent observations).
+///
+/// ## Arguments
+/// * `grs_longitude_deg` -- System II longitude of the Great Red Spot, in degrees.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+///
+/// ## Returns
+/// * list of transit times (local civil time hour, minutes and seconds), in order of time
+pub fn great_red_spot_transits(
+    grs_longitude_deg: f64,
+    local_date_day: u32,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+) -> Vec<(u32, u32, f64)> {
+    let local_offset_days = pa_dt::local_offset_days(is_daylight_saving, zone_correction_hours);
+    let start_jd_ut = pa_dt::local_date_start_jd_ut(
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        local_offset_days,
+    );
+
+    let system_ii_offset = |jd_ut: f64| match planet_orientation("Jupiter", jd_ut) {
+        Some((_, _, _, central_meridians_deg, _)) => {
+            pa_u::wrap_deg(central_meridians_deg[2] - grs_longitude_deg)
+        }
+        None => 0.0,
+    };
+
+    return pa_rs::zero_crossings(&system_ii_offset, start_jd_ut, start_jd_ut + 1.0)
+        .into_iter()
+        .filter(|(_, is_rising)| *is_rising)
+        .map(|(jd_ut, _)| {
+            let lct_hours = (jd_ut - start_jd_ut) * 24.0;
+
+            (
+                pa_m::dh_hour(lct_hours),
+                pa_m::dh_min(lct_hours),
+                pa_m::dh_sec(lct_hours),
+            )
+        })
+        .collect();
+}
+
+/// IAU rotational elements of a planet: right ascension and declination (ICRS) of the north
+/// pole, and prime meridian angles (System III, then Systems I and II for Jupiter), all in
+/// degrees, `days` after J2000.0 (TT). None if there is no rotation model for the planet.
+fn rotation_elements(planet_name: &str, days: f64) -> Option<(f64, f64, Vec<f64>)> {
+    let t = days / 36525.0;
+
+    return match planet_name {
+        "Mars" => Some((
+            317.68143 - 0.1061 * t,
+            52.8865 - 0.0609 * t,
+            vec![176.63 + 350.89198226 * days],
+        )),
+        "Jupiter" => Some((
+            268.056595 - 0.006499 * t,
+            64.495303 + 0.002413 * t,
+            vec![
+                284.95 + 870.536 * days,
+                67.1 + 877.9 * days,
+                43.3 + 870.27 * days,
+            ],
+        )),
+        "Saturn" => Some((
+            40.589 - 0.036 * t,
+            83.537 - 0.004 * t,
+            vec![38.9 + 810.7939024 * days],
+        )),
+        _ => None,
+    };
+}
+
+/// Orientation of a planet seen from the Earth at a Julian date (UT): planetocentric latitudes
+/// of the Earth and the Sun, position angle of the axis, central meridian longitudes (one per
+/// system of `rotation_elements`; all degrees), and the distance of the planet (AU).
+fn planet_orientation(planet_name: &str, jd_ut: f64) -> Option<(f64, f64, f64, Vec<f64>, f64)> {
+    let (planet_long_deg, planet_lat_deg, distance_au, _, sun_long_deg, sun_distance_au) =
+        planet_and_sun_at(planet_name, jd_ut);
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+    let light_time_days = distance_au * 0.0057755183;
+    let (pole_ra_deg, pole_dec_deg, prime_meridians_deg) =
+        rotation_elements(planet_name, jd_tt - light_time_days - 2451545.0)?;
+
+    let observer = pa_f::Observer {
+        geographical_longitude_deg: 0.0,
+        geographical_latitude_deg: 0.0,
+    };
+    let ecliptic_to_icrs = pa_f::transform(
+        pa_t::ReferenceFrame::EclipticOfDate,
+        pa_t::ReferenceFrame::Icrs,
+        jd_tt,
+        &observer,
+    );
+    let ecliptic_to_true = pa_f::transform(
+        pa_t::ReferenceFrame::EclipticOfDate,
+        pa_t::ReferenceFrame::TrueOfDate,
+        jd_tt,
+        &observer,
+    );
+    let icrs_to_true = pa_f::transform(
+        pa_t::ReferenceFrame::Icrs,
+        pa_t::ReferenceFrame::TrueOfDate,
+        jd_tt,
+        &observer,
+    );
+
+    // Directions from the planet to the Earth and to the Sun, and the pole, in the ICRS.
+    let planet = pa_f::spherical_to_vector(planet_long_deg, planet_lat_deg);
+    let sun = pa_f::spherical_to_vector(sun_long_deg, 0.0);
+    let to_earth = pa_f::rotate_vector(
+        &ecliptic_to_icrs,
+        &pa_f::Vector3 {
+            x: -planet.x,
+            y: -planet.y,
+            z: -planet.z,
+        },
+    );
+    let to_sun = pa_f::rotate_vector(
+        &ecliptic_to_icrs,
+        &pa_f::Vector3 {
+            x: sun.x * sun_distance_au - planet.x * distance_au,
+            y: sun.y * sun_distance_au - planet.y * distance_au,
+            z: sun.z * sun_distance_au - planet.z * distance_au,
+        },
+    );
+    let pole = pa_f::spherical_to_vector(pole_ra_deg, pole_dec_deg);
+    let latitude_of = |direction: &pa_f::Vector3| {
+        let length =
+            (direction.x * direction.x + direction.y * direction.y + direction.z * direction.z)
+                .sqrt();
+        ((pole.x * direction.x + pole.y * direction.y + pole.z * direction.z) / length)
+            .asin()
+            .to_degrees()
+    };
+
+    // Longitude of the Earth in the planet's equator, from the ascending node of the equator on
+    // the ICRS equator; the central meridian is the prime meridian angle less this.
+    let node = pa_f::spherical_to_vector(pole_ra_deg + 90.0, 0.0);
+    let node_normal = pa_f::cross_product(&pole, &node);
+    let earth_longitude_deg =
+        (to_earth.x * node_normal.x + to_earth.y * node_normal.y + to_earth.z * node_normal.z)
+            .atan2(to_earth.x * node.x + to_earth.y * node.y + to_earth.z * node.z)
+            .to_degrees();
+    let central_meridians_deg = prime_meridians_deg
+        .iter()
+        .map(|prime_meridian_deg| {
+            let angle_deg = prime_meridian_deg - earth_longitude_deg;
+            angle_deg - 360.0 * (angle_deg / 360.0).floor()
+        })
+        .collect();
+
+    // Position angle of the pole, in the true equator and equinox of date.
+    let (planet_ra_deg, planet_dec_deg) =
+        pa_f::vector_to_spherical(&pa_f::rotate_vector(&ecliptic_to_true, &planet));
+    let (pole_ra_of_date_deg, pole_dec_of_date_deg) =
+        pa_f::vector_to_spherical(&pa_f::rotate_vector(&icrs_to_true, &pole));
+    let ra_difference = (pole_ra_of_date_deg - planet_ra_deg).to_radians();
+    let planet_dec = planet_dec_deg.to_radians();
+    let pole_dec = pole_dec_of_date_deg.to_radians();
+    let position_angle_deg = (pole_dec.cos() * ra_difference.sin())
+        .atan2(
+            pole_dec.sin() * planet_dec.cos()
+                - pole_dec.cos() * planet_dec.sin() * ra_difference.cos(),
+        )
+        .to_degrees();
+
+    return Some((
+        latitude_of(&to_earth),
+        latitude_of(&to_sun),
+        position_angle_deg - 360.0 * (position_angle_deg / 360.0).floor(),
+        central_meridians_deg,
+        distance_au,
+    ));
+}
+
+/// Build a PlanetaryPhenomenon for an instant.
+fn planetary_phenomenon(
+    kind: pa_t::PlanetaryPhenomenonKind,
+    planet_name: &str,
+    other_body_name: &str,
+    jd_ut: f64,
+    local_offset_days: f64,
+) -> PlanetaryPhenomenon {
+    let local_jd = jd_ut + local_offset_days;
+    let local_day = pa_m::jdc_day(local_jd);
+    let lct_hours = (local_day - local_day.floor()) * 24.0;
+    let (_, _, distance_au, heliocentric_distance_au, _, _) = planet_and_sun_at(planet_name, jd_ut);
+
+    return PlanetaryPhenomenon {
+        kind,
+        planet_name: planet_name.to_string(),
+        other_body_name: other_body_name.to_string(),
+        jd_ut,
+        local_date_day: local_day.floor() as u32,
+        local_date_month: pa_m::jdc_month(local_jd),
+        local_date_year: pa_m::jdc_year(local_jd),
+        lct_hour: pa_m::dh_hour(lct_hours),
+        lct_minutes: pa_m::dh_min(lct_hours),
+        lct_seconds: pa_m::dh_sec(lct_hours),
+        elongation_deg: pa_u::round_f64(elongation_at(planet_name, jd_ut), 2),
+        separation_deg: if other_body_name.is_empty() {
+            -99.0
+        } else {
+            pa_u::round_f64(separation_at(planet_name, other_body_name, jd_ut), 2)
+        },
+        distance_au: pa_u::round_f64(distance_au, 4),
+        heliocentric_distance_au: pa_u::round_f64(heliocentric_distance_au, 4),
+    };
+}
+
+/// Geocentric ecliptic longitude (degrees), latitude (degrees) and distance (AU) of a planet,
+/// its distance from the Sun (AU), and the longitude (degrees) and distance (AU) of the Sun, at a
+/// Julian date (UT).
+fn planet_and_sun_at(planet_name: &str, jd_ut: f64) -> (f64, f64, f64, f64, f64, f64) {
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    let (planet_long_deg, planet_lat_deg, planet_distance_au, _, _, _, planet_r_vect) =
+        pa_m::planet_coordinates(hours, 0.0, 0.0, 0, 0, gd, gm, gy, planet_name.to_string());
+
+    return (
+        planet_long_deg,
+        planet_lat_deg,
+        planet_distance_au,
+        planet_r_vect,
+        pa_m::sun_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::sun_dist(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+    );
+}
+
+/// Geocentric ecliptic longitude and latitude (degrees) of a planet or the Moon at a Julian date
+/// (UT), without nutation.
+fn body_ecliptic_at(body_name: &str, jd_ut: f64) -> (f64, f64) {
+    if body_name != "Moon" {
+        let (long_deg, lat_deg, _, _, _, _) = planet_and_sun_at(body_name, jd_ut);
+        return (long_deg, lat_deg);
+    }
+
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    return (
+        pa_m::moon_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::moon_lat(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+    );
+}
+
+/// Angular distance (degrees) of a planet from the Sun at a Julian date (UT).
+fn elongation_at(planet_name: &str, jd_ut: f64) -> f64 {
+    let (planet_long_deg, planet_lat_deg, _, _, sun_long_deg, _) =
+        planet_and_sun_at(planet_name, jd_ut);
+
+    return angle_between(
+        &pa_f::spherical_to_vector(planet_long_deg, planet_lat_deg),
+        &pa_f::spherical_to_vector(sun_long_deg, 0.0),
+    );
+}
+
+/// Angular distance (degrees) between a planet and another planet, the Moon or the Sun at a
+/// Julian date (UT).
+fn separation_at(planet_name: &str, other_body_name: &str, jd_ut: f64) -> f64 {
+    if other_body_name == "Sun" {
+        return elongation_at(planet_name, jd_ut);
+    }
+
+    let (planet_long_deg, planet_lat_deg) = body_ecliptic_at(planet_name, jd_ut);
+    let (other_long_deg, other_lat_deg) = body_ecliptic_at(other_body_name, jd_ut);
+
+    return angle_between(
+        &pa_f::spherical_to_vector(planet_long_deg, planet_lat_deg),
+        &pa_f::spherical_to_vector(other_long_deg, other_lat_deg),
+    );
+}
+
+/// Angle (degrees) between two unit vectors.
+fn angle_between(a: &pa_f::Vector3, b: &pa_f::Vector3) -> f64 {
+    let cross = pa_f::cross_product(a, b);
+    let cosine = a.x * b.x + a.y * b.y + a.z * b.z;
+
+    return (cross.x * cross.x + cross.y * cross.y + cross.z * cross.z)
+        .sqrt()
+        .atan2(cosine)
+        .to_degrees();
+}
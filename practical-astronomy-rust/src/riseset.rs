@@ -0,0 +1,501 @@
+use crate::comet as pa_cm;
+use crate::cometdata as pa_cd;
+use crate::datetime as pa_dt;
+use crate::eclipses as pa_e;
+use crate::frames as pa_f;
+use crate::macros as pa_m;
+use crate::transits as pa_tr;
+use crate::types as pa_t;
+
+/// Info about a body to be risen, transited and set:
+/// * `kind` -- Sun, Moon, planet, comet or star.
+/// * `name` -- Name of the planet (e.g. "Jupiter"), comet (e.g. "Halley") or star.
+/// * `right_ascension_hours` -- Right ascension (J2000, decimal hours). Only used for stars.
+/// * `declination_deg` -- Declination (J2000, decimal degrees). Only used for stars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiseSetBody {
+    pub kind: pa_t::RiseSetBodyKind,
+    pub name: String,
+    pub right_ascension_hours: f64,
+    pub declination_deg: f64,
+}
+
+/// Info about a rising, setting or transit of a body:
+/// * `kind` -- Rise, set, upper transit or lower transit.
+/// * `jd_ut` -- Julian date of the event (Universal Time).
+/// * `lct_hour` -- Time of the event (hour, local civil time).
+/// * `lct_minutes` -- Time of the event (minutes, local civil time).
+/// * `lct_seconds` -- Time of the event (seconds, local civil time).
+/// * `altitude_deg` -- Topocentric altitude of the body's center, without refraction.
+/// * `azimuth_deg` -- Azimuth of the body, measured from north through east.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RiseSetEvent {
+    pub kind: pa_t::RiseSetEventKind,
+    pub jd_ut: f64,
+    pub lct_hour: u32,
+    pub lct_minutes: u32,
+    pub lct_seconds: f64,
+    pub altitude_deg: f64,
+    pub azimuth_deg: f64,
+}
+
+/// Info about the rising, transits and setting of a body on a local day:
+/// * `status` -- "OK", "circumpolar" (above the horizon all day) or "never rises" (below the horizon all day).
+/// * `horizon_altitude_deg` -- Altitude of the body's center at rising and setting, including the dip of the horizon.
+/// * `rise` -- The body rises. None if it does not rise on the day.
+/// * `upper_transit` -- The body crosses the meridian above the pole. None if it does not on the day.
+/// * `set` -- The body sets. None if it does not set on the day.
+/// * `lower_transit` -- The body crosses the meridian below the pole. None if it does not on the day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiseTransitSet {
+    pub status: String,
+    pub horizon_altitude_deg: f64,
+    pub rise: Option<RiseSetEvent>,
+    pub upper_transit: Option<RiseSetEvent>,
+    pub set: Option<RiseSetEvent>,
+    pub lower_transit: Option<RiseSetEvent>,
+}
+
+/// Conventional altitude of a body's center at rising and setting, for a topocentric position.
+///
+/// ## Arguments
+/// * `kind` -- Kind of body.
+///
+/// ## Returns
+/// * Altitude, in degrees: -0.8333 for the Sun and Moon (refraction and semidiameter), -0.5667
+///   for planets, comets and stars (refraction only).
+pub fn standard_horizon_altitude(kind: pa_t::RiseSetBodyKind) -> f64 {
+    return match kind {
+        pa_t::RiseSetBodyKind::Sun | pa_t::RiseSetBodyKind::Moon => -0.8333333,
+        _ => -0.5666667,
+    };
+}
+
+/// Dip of the horizon for an observer above the surrounding terrain, including refraction.
+///
+/// ## Arguments
+/// * `observer_height_m` -- Height of the observer above the horizon, in metres.
+///
+/// ## Returns
+/// * Dip, in degrees.
+pub fn horizon_dip(observer_height_m: f64) -> f64 {
+    return 0.0293 * observer_height_m.max(0.0).sqrt();
+}
+
+/// Rising, setting, and upper and lower transit of a body on a local day.
+///
+/// The body's topocentric position is searched through the day for the times its center crosses
+/// the horizon altitude and the meridian.
+///
+/// ## Arguments
+/// * `body` -- Body to rise and set.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `geog_longitude_deg` -- Geographical longitude of the observer, in degrees (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of the observer, in degrees.
+/// * `observer_height_m` -- Height of the observer above sea level, in metres.
+/// * `horizon_altitude_deg` -- Altitude of the body's center at rising and setting, in degrees (see `standard_horizon_altitude`).
+/// * `include_horizon_dip` -- Lower the horizon by the dip for the observer's height (e.g. at sea).
+///
+/// ## Returns
+/// * RiseTransitSet structure.
+pub fn rise_transit_set(
+    body: &RiseSetBody,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    observer_height_m: f64,
+    horizon_altitude_deg: f64,
+    include_horizon_dip: bool,
+) -> RiseTransitSet {
+    let (start_jd_ut, threshold_deg, observer) = day_and_horizon(
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        observer_height_m,
+        horizon_altitude_deg,
+        include_horizon_dip,
+    );
+    let events = day_events(body, start_jd_ut, &observer, threshold_deg);
+
+    let first = |kind: pa_t::RiseSetEventKind| events.iter().find(|e| e.kind == kind).copied();
+
+    let status = if events
+        .iter()
+        .any(|e| e.kind == pa_t::RiseSetEventKind::Rise || e.kind == pa_t::RiseSetEventKind::Set)
+    {
+        "OK"
+    } else if horizontal_position(body, start_jd_ut, &observer).0 > threshold_deg {
+        "circumpolar"
+    } else {
+        "never rises"
+    };
+
+    return RiseTransitSet {
+        status: status.to_string(),
+        horizon_altitude_deg: threshold_deg,
+        rise: first(pa_t::RiseSetEventKind::Rise),
+        upper_transit: first(pa_t::RiseSetEventKind::UpperTransit),
+        set: first(pa_t::RiseSetEventKind::Set),
+        lower_transit: first(pa_t::RiseSetEventKind::LowerTransit),
+    };
+}
+
+/// All risings, settings, and upper and lower transits of a body on a local day, in time order.
+///
+/// At high latitudes the Moon can rise or set twice on the same day, or not at all; every event
+/// is returned.
+///
+/// ## Arguments
+/// * `body` -- Body to rise and set.
+/// * `local_date_day` -- Local date, day part.
+/// * `local_date_month` -- Local date, month part.
+/// * `local_date_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction_hours` -- Time zone correction, in hours.
+/// * `geog_longitude_deg` -- Geographical longitude of the observer, in degrees (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of the observer, in degrees.
+/// * `observer_height_m` -- Height of the observer above sea level, in metres.
+/// * `horizon_altitude_deg` -- Altitude of the body's center at rising and setting, in degrees (see `standard_horizon_altitude`).
+/// * `include_horizon_dip` -- Lower the horizon by the dip for the observer's height (e.g. at sea).
+///
+/// ## Returns
+/// * Vector of RiseSetEvent structures (empty if the body neither crosses the horizon nor the meridian).
+pub fn rise_set_events(
+    body: &RiseSetBody,
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    observer_height_m: f64,
+    horizon_altitude_deg: f64,
+    include_horizon_dip: bool,
+) -> Vec<RiseSetEvent> {
+    let (start_jd_ut, threshold_deg, observer) = day_and_horizon(
+        local_date_day,
+        local_date_month,
+        local_date_year,
+        is_daylight_saving,
+        zone_correction_hours,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        observer_height_m,
+        horizon_altitude_deg,
+        include_horizon_dip,
+    );
+
+    return day_events(body, start_jd_ut, &observer, threshold_deg);
+}
+
+/// Start of the local day (Julian date, UT), horizon altitude including any dip, and the observer
+/// as (longitude east, latitude, height in metres).
+pub(crate) fn day_and_horizon(
+    local_date_day: f64,
+    local_date_month: u32,
+    local_date_year: u32,
+    is_daylight_saving: bool,
+    zone_correction_hours: i32,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    observer_height_m: f64,
+    horizon_altitude_deg: f64,
+    include_horizon_dip: bool,
+) -> (f64, f64, (f64, f64, f64)) {
+    let start_jd_ut = pa_dt::local_date_start_jd_ut(
+        local_date_day.floor() as u32,
+        local_date_month,
+        local_date_year,
+        pa_dt::local_offset_days(is_daylight_saving, zone_correction_hours),
+    );
+
+    let threshold_deg = if include_horizon_dip {
+        horizon_altitude_deg - horizon_dip(observer_height_m)
+    } else {
+        horizon_altitude_deg
+    };
+
+    return (
+        start_jd_ut,
+        threshold_deg,
+        (geog_longitude_deg, geog_latitude_deg, observer_height_m),
+    );
+}
+
+/// Horizon and meridian crossings of a body during the day starting at a Julian date (UT).
+fn day_events(
+    body: &RiseSetBody,
+    start_jd_ut: f64,
+    observer: &(f64, f64, f64),
+    threshold_deg: f64,
+) -> Vec<RiseSetEvent> {
+    let end_jd_ut = start_jd_ut + 1.0;
+    let altitude = |jd_ut: f64| horizontal_position(body, jd_ut, observer).0 - threshold_deg;
+    let hour_angle_sine = |jd_ut: f64| horizontal_position(body, jd_ut, observer).2.sin();
+
+    let event = |jd_ut: f64, kind: pa_t::RiseSetEventKind| {
+        event_at(body, jd_ut, start_jd_ut, observer, kind)
+    };
+
+    let mut events: Vec<RiseSetEvent> = Vec::new();
+    for (jd_ut, is_rising) in zero_crossings(&altitude, start_jd_ut, end_jd_ut) {
+        events.push(if is_rising {
+            event(jd_ut, pa_t::RiseSetEventKind::Rise)
+        } else {
+            event(jd_ut, pa_t::RiseSetEventKind::Set)
+        });
+    }
+    for (jd_ut, is_rising) in zero_crossings(&hour_angle_sine, start_jd_ut, end_jd_ut) {
+        events.push(if is_rising {
+            event(jd_ut, pa_t::RiseSetEventKind::UpperTransit)
+        } else {
+            event(jd_ut, pa_t::RiseSetEventKind::LowerTransit)
+        });
+    }
+    events.sort_by(|a, b| a.jd_ut.total_cmp(&b.jd_ut));
+
+    return events;
+}
+
+/// Rising, setting or transit event of a body at a Julian date (UT), with local civil time measured
+/// from the start of the local day.
+pub(crate) fn event_at(
+    body: &RiseSetBody,
+    jd_ut: f64,
+    start_jd_ut: f64,
+    observer: &(f64, f64, f64),
+    kind: pa_t::RiseSetEventKind,
+) -> RiseSetEvent {
+    let (altitude_deg, azimuth_deg, _hour_angle) = horizontal_position(body, jd_ut, observer);
+    let lct_hours = (jd_ut - start_jd_ut) * 24.0;
+
+    return RiseSetEvent {
+        kind,
+        jd_ut,
+        lct_hour: pa_m::dh_hour(lct_hours),
+        lct_minutes: pa_m::dh_min(lct_hours),
+        lct_seconds: pa_m::dh_sec(lct_hours),
+        altitude_deg,
+        azimuth_deg,
+    };
+}
+
+/// Topocentric altitude and azimuth (degrees, without refraction) and local hour angle (radians)
+/// of a body at a Julian date (UT). The observer is (longitude east, latitude, height in metres).
+pub(crate) fn horizontal_position(
+    body: &RiseSetBody,
+    jd_ut: f64,
+    observer: &(f64, f64, f64),
+) -> (f64, f64, f64) {
+    let (longitude_deg, latitude_deg, height_m) = *observer;
+    let geocentric = body_position(body, jd_ut);
+
+    let latitude = latitude_deg.to_radians();
+    let u = (0.99664719 * latitude.tan()).atan();
+    let rho_sin_phi = 0.99664719 * u.sin() + height_m / 6378137.0 * latitude.sin();
+    let rho_cos_phi = u.cos() + height_m / 6378137.0 * latitude.cos();
+    let local_sidereal_time =
+        (pa_e::apparent_sidereal_time_hours(jd_ut) * 15.0 + longitude_deg).to_radians();
+
+    let topocentric = pa_f::Vector3 {
+        x: geocentric.x - rho_cos_phi * local_sidereal_time.cos(),
+        y: geocentric.y - rho_cos_phi * local_sidereal_time.sin(),
+        z: geocentric.z - rho_sin_phi,
+    };
+    let (right_ascension_deg, declination_deg) = pa_f::vector_to_spherical(&topocentric);
+
+    return equatorial_to_horizontal(
+        right_ascension_deg,
+        declination_deg,
+        local_sidereal_time,
+        latitude,
+    );
+}
+
+/// Geocentric altitude and azimuth (degrees, without refraction) and local hour angle (radians)
+/// of a body at a Julian date (UT), i.e. as seen from the centre of the Earth with the observer's
+/// horizon. The observer is (longitude east, latitude, height in metres).
+pub(crate) fn geocentric_horizontal_position(
+    body: &RiseSetBody,
+    jd_ut: f64,
+    observer: &(f64, f64, f64),
+) -> (f64, f64, f64) {
+    let (longitude_deg, latitude_deg, _height_m) = *observer;
+    let (right_ascension_deg, declination_deg) =
+        pa_f::vector_to_spherical(&body_position(body, jd_ut));
+    let local_sidereal_time =
+        (pa_e::apparent_sidereal_time_hours(jd_ut) * 15.0 + longitude_deg).to_radians();
+
+    return equatorial_to_horizontal(
+        right_ascension_deg,
+        declination_deg,
+        local_sidereal_time,
+        latitude_deg.to_radians(),
+    );
+}
+
+/// Altitude and azimuth (degrees) and hour angle (radians) of an equatorial position, for a local
+/// sidereal time and latitude (radians).
+fn equatorial_to_horizontal(
+    right_ascension_deg: f64,
+    declination_deg: f64,
+    local_sidereal_time: f64,
+    latitude: f64,
+) -> (f64, f64, f64) {
+    let hour_angle = local_sidereal_time - right_ascension_deg.to_radians();
+    let declination = declination_deg.to_radians();
+
+    let altitude = (latitude.sin() * declination.sin()
+        + latitude.cos() * declination.cos() * hour_angle.cos())
+    .asin();
+    let azimuth = (-declination.cos() * hour_angle.sin()).atan2(
+        declination.sin() * latitude.cos() - declination.cos() * latitude.sin() * hour_angle.cos(),
+    );
+
+    return (
+        altitude.to_degrees(),
+        (azimuth.to_degrees() + 360.0) % 360.0,
+        hour_angle,
+    );
+}
+
+/// Geocentric apparent equatorial position of a body (Earth radii) at a Julian date (UT).
+pub(crate) fn body_position(body: &RiseSetBody, jd_ut: f64) -> pa_f::Vector3 {
+    let jd_tt = jd_ut + pa_dt::delta_t_days(jd_ut);
+
+    return match body.kind {
+        pa_t::RiseSetBodyKind::Sun => pa_e::sun_and_moon_position(jd_tt).0,
+        pa_t::RiseSetBodyKind::Moon => pa_e::sun_and_moon_position(jd_tt).1,
+        pa_t::RiseSetBodyKind::Planet => pa_tr::planet_and_sun_position(&body.name, jd_tt).0,
+        pa_t::RiseSetBodyKind::Comet => comet_position(&body.name, jd_ut),
+        pa_t::RiseSetBodyKind::Star => {
+            let catalogue =
+                pa_f::spherical_to_vector(body.right_ascension_hours * 15.0, body.declination_deg);
+            let of_date = pa_f::rotate_vector(
+                &pa_f::transform(
+                    pa_t::ReferenceFrame::Icrs,
+                    pa_t::ReferenceFrame::TrueOfDate,
+                    jd_tt,
+                    &pa_f::Observer {
+                        geographical_longitude_deg: 0.0,
+                        geographical_latitude_deg: 0.0,
+                    },
+                ),
+                &catalogue,
+            );
+            let distance = 1.0e10;
+
+            pa_f::Vector3 {
+                x: of_date.x * distance,
+                y: of_date.y * distance,
+                z: of_date.z * distance,
+            }
+        }
+    };
+}
+
+/// Geocentric equatorial position of a comet (Earth radii) at a Julian date (UT), from the
+/// unrounded `comet::elliptical_comet_position` or `comet::parabolic_comet_position`.
+fn comet_position(comet_name: &str, jd_ut: f64) -> pa_f::Vector3 {
+    let day = pa_m::jdc_day(jd_ut);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_ut);
+    let gy = pa_m::jdc_year(jd_ut);
+    let hours = (day - gd) * 24.0;
+
+    let (ra_hours, dec_deg, distance_au) =
+        if pa_cd::get_comet_info_elliptical_vector(comet_name.to_string()).1 == "OK" {
+            pa_cm::elliptical_comet_position(
+                hours,
+                0.0,
+                0.0,
+                false,
+                0,
+                gd,
+                gm,
+                gy,
+                comet_name.to_string(),
+            )
+        } else {
+            pa_cm::parabolic_comet_position(
+                hours,
+                0.0,
+                0.0,
+                false,
+                0,
+                gd,
+                gm,
+                gy,
+                comet_name.to_string(),
+            )
+        };
+
+    let equatorial = pa_f::spherical_to_vector(ra_hours * 15.0, dec_deg);
+    let distance = distance_au * 149597870.7 / 6378.137;
+
+    return pa_f::Vector3 {
+        x: equatorial.x * distance,
+        y: equatorial.y * distance,
+        z: equatorial.z * distance,
+    };
+}
+
+/// Times in an interval where a function crosses zero, and whether it is rising through zero.
+pub(crate) fn zero_crossings(
+    function: &dyn Fn(f64) -> f64,
+    start: f64,
+    end: f64,
+) -> Vec<(f64, bool)> {
+    return zero_crossings_with_step(function, start, end, 10.0 / 1440.0);
+}
+
+/// As `zero_crossings`, scanning the interval in steps of `step` days. The function must not
+/// cross zero twice within one step.
+pub(crate) fn zero_crossings_with_step(
+    function: &dyn Fn(f64) -> f64,
+    start: f64,
+    end: f64,
+    step: f64,
+) -> Vec<(f64, bool)> {
+    let steps = ((end - start) / step).ceil() as i32;
+    let mut crossings: Vec<(f64, bool)> = Vec::new();
+    let mut previous_t = start;
+    let mut previous_value = function(start);
+
+    for i in 1..=steps {
+        let t = (start + i as f64 * step).min(end);
+        let value = function(t);
+
+        if (previous_value <= 0.0) != (value <= 0.0) {
+            let mut low = previous_t;
+            let mut high = t;
+            for _ in 0..40 {
+                let middle = (low + high) / 2.0;
+                if (function(middle) <= 0.0) == (previous_value <= 0.0) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            crossings.push(((low + high) / 2.0, value > 0.0));
+        }
+
+        previous_t = t;
+        previous_value = value;
+    }
+
+    return crossings;
+}
@@ -1,7 +1,88 @@
+pub mod spa;
+
+use crate::datetime as pa_dt;
 use crate::macros as pa_m;
+use crate::riseset as pa_rs;
 use crate::types as pa_t;
 use crate::util as pa_u;
 
+/// Info about a period when the Sun is between two altitudes:
+/// * `start` -- The Sun enters the altitude range. None if it is within the range at the start of the day.
+/// * `end` -- The Sun leaves the altitude range. None if it is within the range at the end of the day.
+/// * `is_rising` -- The Sun is rising during the period (a morning period).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarAltitudeInterval {
+    pub start: Option<pa_rs::RiseSetEvent>,
+    pub end: Option<pa_rs::RiseSetEvent>,
+    pub is_rising: bool,
+}
+
+/// Info about the instant when the Sun reaches an apparent ecliptic longitude:
+/// * `name` -- Name of the equinox, solstice, solar term or cross-quarter day.
+/// * `solar_longitude_deg` -- Apparent geocentric ecliptic longitude of the Sun, in degrees.
+/// * `jd_tt` -- Julian date of the instant (TT).
+/// * `jd_ut` -- Julian date of the instant (UT).
+/// * `tt_date_day`, `tt_date_month`, `tt_date_year` -- Date of the instant (TT).
+/// * `tt_hour`, `tt_minutes`, `tt_seconds` -- Time of the instant (TT).
+/// * `ut_date_day`, `ut_date_month`, `ut_date_year` -- Date of the instant (UT).
+/// * `ut_hour`, `ut_minutes`, `ut_seconds` -- Time of the instant (UT).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolarTerm {
+    pub name: String,
+    pub solar_longitude_deg: f64,
+    pub jd_tt: f64,
+    pub jd_ut: f64,
+    pub tt_date_day: u32,
+    pub tt_date_month: u32,
+    pub tt_date_year: u32,
+    pub tt_hour: u32,
+    pub tt_minutes: u32,
+    pub tt_seconds: f64,
+    pub ut_date_day: u32,
+    pub ut_date_month: u32,
+    pub ut_date_year: u32,
+    pub ut_hour: u32,
+    pub ut_minutes: u32,
+    pub ut_seconds: f64,
+}
+
+/// Info about the Sun at 12h UT on one day of a year:
+/// * `day`, `month`, `year` -- Date.
+/// * `equation_of_time_min` -- Equation of time (apparent minus mean solar time), in decimal minutes.
+/// * `declination_deg` -- Geocentric apparent declination of the Sun, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquationOfTimeEntry {
+    pub day: u32,
+    pub month: u32,
+    pub year: u32,
+    pub equation_of_time_min: f64,
+    pub declination_deg: f64,
+}
+
+/// Info about one point of the analemma:
+/// * `day`, `month`, `year` -- Local date.
+/// * `altitude_deg` -- Altitude of the Sun, without refraction, in degrees.
+/// * `azimuth_deg` -- Azimuth of the Sun, measured eastward from north, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnalemmaPoint {
+    pub day: u32,
+    pub month: u32,
+    pub year: u32,
+    pub altitude_deg: f64,
+    pub azimuth_deg: f64,
+}
+
+/// Info about one hour line of a sundial:
+/// * `hour` -- Clock hour of the line.
+/// * `hour_angle_deg` -- Hour angle of the Sun at that clock hour, in degrees.
+/// * `line_angle_deg` -- Angle of the hour line from the noon line on the dial face, in degrees (positive towards the afternoon lines).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SundialHourLine {
+    pub hour: u32,
+    pub hour_angle_deg: f64,
+    pub line_angle_deg: f64,
+}
+
 /// Calculate approximate position of the sun for a local date and time.
 ///
 /// ## Arguments
@@ -346,7 +427,7 @@ pub fn sun_distance_and_angular_size(
 /// * local_sunset_minute -- Local sunset, minutes part
 /// * azimuth_of_sunrise_deg -- Azimuth (horizon direction) of sunrise, in degrees
 /// * azimuth_of_sunset_deg -- Azimuth (horizon direction) of sunset, in degrees
-/// * status -- Calculation status
+/// * status -- Calculation status: "OK", "** polar day" (the Sun does not set) or "** polar night" (the Sun does not rise)
 pub fn sunrise_and_sunset(
     local_day: f64,
     local_month: u32,
@@ -439,7 +520,7 @@ pub fn sunrise_and_sunset(
     } else {
         0.0
     };
-    let status = sun_rise_set_status.to_string();
+    let status = polar_status(&sun_rise_set_status, "** polar day", "** polar night");
 
     return (
         local_sunrise_hour,
@@ -469,7 +550,7 @@ pub fn sunrise_and_sunset(
 /// * `am_twilight_begins_min` -- Beginning of AM twilight (minutes part)
 /// * `pm_twilight_ends_hour` -- Ending of PM twilight (hour part)
 /// * `pm_twilight_ends_min` -- Ending of PM twilight (minutes part)
-/// * `status` -- Calculation status
+/// * `status` -- Calculation status: "OK", "** polar day" (the Sun does not set), "** continuous twilight" (the Sun sets but twilight lasts all night) or "** polar night" (the Sun stays below the twilight altitude)
 pub fn morning_and_evening_twilight(
     local_day: f64,
     local_month: u32,
@@ -538,7 +619,35 @@ pub fn morning_and_evening_twilight(
     } else {
         -99.0
     };
-    let status = twilight_status;
+    // The polar states follow from the Sun's altitude at local noon and midnight.
+    let status = if twilight_status == "OK" {
+        twilight_status
+    } else {
+        let twilight_altitude_deg = match twilight_type {
+            pa_t::TwilightType::Civil => -6.0,
+            pa_t::TwilightType::Nautical => -12.0,
+            pa_t::TwilightType::Astronomical => -18.0,
+        };
+        let sun_dec_deg = sun_declination_at_local_noon(
+            local_day,
+            local_month,
+            local_year,
+            daylight_saving,
+            zone_correction,
+        );
+        let noon_altitude_deg = 90.0 - (geographical_lat_deg - sun_dec_deg).abs();
+        let midnight_altitude_deg = (geographical_lat_deg + sun_dec_deg).abs() - 90.0;
+
+        if noon_altitude_deg < twilight_altitude_deg {
+            "** polar night".to_string()
+        } else if midnight_altitude_deg > -0.8333333 {
+            "** polar day".to_string()
+        } else if midnight_altitude_deg > twilight_altitude_deg {
+            "** continuous twilight".to_string()
+        } else {
+            twilight_status
+        }
+    };
 
     return (
         am_twilight_begins_hour,
@@ -549,6 +658,255 @@ pub fn morning_and_evening_twilight(
     );
 }
 
+/// Declination of the Sun (degrees) at local noon, as used by the sunrise and twilight macros.
+fn sun_declination_at_local_noon(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    daylight_saving: i32,
+    zone_correction: i32,
+) -> f64 {
+    let gd = pa_m::lct_gday(
+        12.0,
+        0.0,
+        0.0,
+        daylight_saving,
+        zone_correction,
+        local_day,
+        local_month,
+        local_year,
+    );
+    let gm = pa_m::lct_gmonth(
+        12.0,
+        0.0,
+        0.0,
+        daylight_saving,
+        zone_correction,
+        local_day,
+        local_month,
+        local_year,
+    );
+    let gy = pa_m::lct_gyear(
+        12.0,
+        0.0,
+        0.0,
+        daylight_saving,
+        zone_correction,
+        local_day,
+        local_month,
+        local_year,
+    );
+    let sun_long_deg = pa_m::sun_long(
+        12.0,
+        0.0,
+        0.0,
+        daylight_saving,
+        zone_correction,
+        local_day,
+        local_month,
+        local_year,
+    ) + pa_m::nutat_long(gd, gm, gy)
+        - 0.005694;
+
+    return pa_m::ec_dec(sun_long_deg, 0.0, 0.0, 0.0, 0.0, 0.0, gd, gm, gy);
+}
+
+/// Replace the "circumpolar" and "never rises" statuses of the sunrise macro with the state of
+/// the day.
+fn polar_status(status: &str, circumpolar_status: &str, never_rises_status: &str) -> String {
+    if status.starts_with("** circumpolar") {
+        return circumpolar_status.to_string();
+    }
+    if status.starts_with("** never rises") {
+        return never_rises_status.to_string();
+    }
+
+    return status.to_string();
+}
+
+/// Calculate the times the Sun's center crosses an altitude.
+///
+/// ## Arguments
+/// * `local_day` -- Local date, day part.
+/// * `local_month` -- Local date, month part.
+/// * `local_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction` -- Time zone correction, in hours.
+/// * `geographical_long_deg` -- Geographical longitude, in degrees.
+/// * `geographical_lat_deg` -- Geographical latitude, in degrees.
+/// * `altitude_deg` -- Altitude of the Sun's center (topocentric, without refraction), in degrees.
+///
+/// ## Returns
+/// * Vector of RiseSetEvent structures, kind Rise (the Sun climbs through the altitude) or Set (the Sun sinks through it).
+pub fn solar_altitude_crossings(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+    altitude_deg: f64,
+) -> Vec<pa_rs::RiseSetEvent> {
+    return pa_rs::rise_set_events(
+        &sun_body(),
+        local_day,
+        local_month,
+        local_year,
+        is_daylight_saving,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        0.0,
+        altitude_deg,
+        false,
+    )
+    .into_iter()
+    .filter(|event| {
+        event.kind == pa_t::RiseSetEventKind::Rise || event.kind == pa_t::RiseSetEventKind::Set
+    })
+    .collect();
+}
+
+/// Calculate the periods of a local day when the Sun's center is between two altitudes.
+///
+/// ## Arguments
+/// * `local_day` -- Local date, day part.
+/// * `local_month` -- Local date, month part.
+/// * `local_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction` -- Time zone correction, in hours.
+/// * `geographical_long_deg` -- Geographical longitude, in degrees.
+/// * `geographical_lat_deg` -- Geographical latitude, in degrees.
+/// * `lower_altitude_deg` -- Lower altitude of the Sun's center (topocentric, without refraction), in degrees.
+/// * `upper_altitude_deg` -- Upper altitude of the Sun's center (topocentric, without refraction), in degrees.
+///
+/// ## Returns
+/// * Vector of SolarAltitudeInterval structures, in time order.
+pub fn solar_altitude_intervals(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+    lower_altitude_deg: f64,
+    upper_altitude_deg: f64,
+) -> Vec<SolarAltitudeInterval> {
+    let sun = sun_body();
+    let (start_jd_ut, _threshold_deg, observer) = pa_rs::day_and_horizon(
+        local_day,
+        local_month,
+        local_year,
+        is_daylight_saving,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        0.0,
+        0.0,
+        false,
+    );
+    let end_jd_ut = start_jd_ut + 1.0;
+
+    let altitude = |jd_ut: f64| pa_rs::horizontal_position(&sun, jd_ut, &observer).0;
+    let within_range = |jd_ut: f64| {
+        (altitude(jd_ut) - lower_altitude_deg).min(upper_altitude_deg - altitude(jd_ut))
+    };
+    let is_rising = |jd_ut: f64| altitude(jd_ut + 0.0005) > altitude(jd_ut - 0.0005);
+    let event = |jd_ut: f64| {
+        let kind = if is_rising(jd_ut) {
+            pa_t::RiseSetEventKind::Rise
+        } else {
+            pa_t::RiseSetEventKind::Set
+        };
+        pa_rs::event_at(&sun, jd_ut, start_jd_ut, &observer, kind)
+    };
+
+    let mut intervals: Vec<SolarAltitudeInterval> = Vec::new();
+    let mut start: Option<f64> = None;
+    let mut is_open = within_range(start_jd_ut) > 0.0;
+
+    for (jd_ut, is_entering) in pa_rs::zero_crossings(&within_range, start_jd_ut, end_jd_ut) {
+        if is_entering {
+            start = Some(jd_ut);
+            is_open = true;
+        } else if is_open {
+            intervals.push(SolarAltitudeInterval {
+                start: start.map(event),
+                end: Some(event(jd_ut)),
+                is_rising: is_rising((start.unwrap_or(start_jd_ut) + jd_ut) / 2.0),
+            });
+            start = None;
+            is_open = false;
+        }
+    }
+    if is_open {
+        intervals.push(SolarAltitudeInterval {
+            start: start.map(event),
+            end: None,
+            is_rising: is_rising((start.unwrap_or(start_jd_ut) + end_jd_ut) / 2.0),
+        });
+    }
+
+    return intervals;
+}
+
+/// Calculate the golden hour or blue hour periods of a local day.
+///
+/// The golden hour is when the Sun's center is between -4 and +6 degrees altitude, and the blue
+/// hour when it is between -6 and -4 degrees.
+///
+/// ## Arguments
+/// * `local_day` -- Local date, day part.
+/// * `local_month` -- Local date, month part.
+/// * `local_year` -- Local date, year part.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction` -- Time zone correction, in hours.
+/// * `geographical_long_deg` -- Geographical longitude, in degrees.
+/// * `geographical_lat_deg` -- Geographical latitude, in degrees.
+/// * `photographic_light` -- Golden hour or blue hour.
+///
+/// ## Returns
+/// * Vector of SolarAltitudeInterval structures, in time order (usually a morning and an evening period).
+pub fn golden_and_blue_hour(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geographical_long_deg: f64,
+    geographical_lat_deg: f64,
+    photographic_light: pa_t::PhotographicLight,
+) -> Vec<SolarAltitudeInterval> {
+    let (lower_altitude_deg, upper_altitude_deg) = match photographic_light {
+        pa_t::PhotographicLight::GoldenHour => (-4.0, 6.0),
+        pa_t::PhotographicLight::BlueHour => (-6.0, -4.0),
+    };
+
+    return solar_altitude_intervals(
+        local_day,
+        local_month,
+        local_year,
+        is_daylight_saving,
+        zone_correction,
+        geographical_long_deg,
+        geographical_lat_deg,
+        lower_altitude_deg,
+        upper_altitude_deg,
+    );
+}
+
+/// The Sun, as a body for the rise/set solver.
+fn sun_body() -> pa_rs::RiseSetBody {
+    return pa_rs::RiseSetBody {
+        kind: pa_t::RiseSetBodyKind::Sun,
+        name: "Sun".to_string(),
+        right_ascension_hours: 0.0,
+        declination_deg: 0.0,
+    };
+}
+
 /// Calculate the equation of time. (The difference between the real Sun time and the mean Sun time.)
 ///
 /// ## Arguments
@@ -589,6 +947,185 @@ pub fn equation_of_time(gwdate_day: f64, gwdate_month: u32, gwdate_year: u32) ->
     return (equation_of_time_min, equation_of_time_sec);
 }
 
+/// Calculate the equation of time and the Sun's declination at 12h UT on every day of a year,
+/// from the Solar Position Algorithm (see `spa`) rather than the book's `sun_long`.
+///
+/// ## Arguments
+/// * `year` -- Year.
+///
+/// ## Returns
+/// * One `EquationOfTimeEntry` per day, from 1 January to 31 December.
+pub fn equation_of_time_table(year: u32) -> Vec<EquationOfTimeEntry> {
+    let first_day_jd = pa_m::cd_jd(1.0, 1, year);
+    let days = (pa_m::cd_jd(1.0, 1, year + 1) - first_day_jd).round() as u32;
+
+    return (0..days)
+        .map(|i| {
+            let jd = first_day_jd + i as f64;
+            let day = pa_m::jdc_day(jd);
+            let month = pa_m::jdc_month(jd);
+            let position = spa::solar_position(
+                12.0,
+                0.0,
+                0.0,
+                day,
+                month,
+                year,
+                0.0,
+                0.0,
+                pa_dt::delta_t(day, month, year),
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                10.0,
+                0.0,
+                0.0,
+                0.5667,
+            );
+
+            EquationOfTimeEntry {
+                day: day as u32,
+                month,
+                year,
+                equation_of_time_min: position.equation_of_time_min,
+                declination_deg: position.geocentric_declination_deg,
+            }
+        })
+        .collect();
+}
+
+/// Calculate the analemma: the position of the Sun at the same clock time through a year.
+///
+/// ## Arguments
+/// * `lct_hours` -- Local civil time, in hours.
+/// * `lct_minutes` -- Local civil time, in minutes.
+/// * `lct_seconds` -- Local civil time, in seconds.
+/// * `is_daylight_saving` -- Is daylight savings in effect?
+/// * `zone_correction` -- Time zone correction, in hours.
+/// * `geog_long_deg` -- Geographical longitude, in degrees.
+/// * `geog_lat_deg` -- Geographical latitude, in degrees.
+/// * `year` -- Year.
+/// * `interval_days` -- Days between successive points.
+///
+/// ## Returns
+/// * `AnalemmaPoint`s from 1 January, every `interval_days` days.
+pub fn analemma(
+    lct_hours: f64,
+    lct_minutes: f64,
+    lct_seconds: f64,
+    is_daylight_saving: bool,
+    zone_correction: i32,
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    year: u32,
+    interval_days: u32,
+) -> Vec<AnalemmaPoint> {
+    let daylight_saving = if is_daylight_saving == true { 1 } else { 0 };
+    let first_day_jd = pa_m::cd_jd(1.0, 1, year);
+    let days = (pa_m::cd_jd(1.0, 1, year + 1) - first_day_jd).round() as u32;
+
+    return (0..days)
+        .step_by(interval_days.max(1) as usize)
+        .map(|i| {
+            let jd = first_day_jd + i as f64;
+            let day = pa_m::jdc_day(jd);
+            let month = pa_m::jdc_month(jd);
+            let position = spa::solar_position(
+                lct_hours,
+                lct_minutes,
+                lct_seconds,
+                day,
+                month,
+                year,
+                (zone_correction + daylight_saving) as f64,
+                0.0,
+                pa_dt::delta_t(day, month, year),
+                geog_long_deg,
+                geog_lat_deg,
+                0.0,
+                0.0,
+                10.0,
+                0.0,
+                0.0,
+                0.5667,
+            );
+
+            AnalemmaPoint {
+                day: day as u32,
+                month,
+                year,
+                altitude_deg: 90.0 - position.zenith_deg,
+                azimuth_deg: position.azimuth_deg,
+            }
+        })
+        .collect();
+}
+
+/// Calculate the hour lines of a horizontal or vertical sundial whose style (gnomon edge) is
+/// parallel to the Earth's axis.
+///
+/// Clock hours are converted to hour angles with the longitude correction to the zone meridian,
+/// but not the equation of time, so the lines show mean time on the zone meridian.
+///
+/// ## Arguments
+/// * `geog_long_deg` -- Geographical longitude, in degrees.
+/// * `geog_lat_deg` -- Geographical latitude, in degrees.
+/// * `zone_correction` -- Time zone correction, in hours.
+/// * `sundial_type` -- Horizontal or vertical dial.
+/// * `wall_declination_deg` -- Vertical dials: direction the wall faces, measured from the equator-facing direction (due south in the northern hemisphere), west positive, in degrees.
+///
+/// ## Returns
+/// * style_height_deg -- Angle between the style and the dial face, in degrees.
+/// * hour lines from 6 to 18 hours. Vertical dials omit hours when the Sun is behind the wall; on horizontal dials, lines for hour angles beyond 90 degrees run below the 6 o'clock line.
+pub fn sundial_hour_lines(
+    geog_long_deg: f64,
+    geog_lat_deg: f64,
+    zone_correction: i32,
+    sundial_type: pa_t::SundialType,
+    wall_declination_deg: f64,
+) -> (f64, Vec<SundialHourLine>) {
+    let latitude = geog_lat_deg.abs().to_radians();
+    let wall_declination = wall_declination_deg.to_radians();
+
+    let style_height_deg = match sundial_type {
+        pa_t::SundialType::Horizontal => pa_m::degrees(latitude),
+        pa_t::SundialType::Vertical => {
+            pa_m::degrees((latitude.cos() * wall_declination.cos()).asin())
+        }
+    };
+
+    let hour_lines = (6..=18)
+        .filter_map(|hour: u32| {
+            let hour_angle_deg =
+                15.0 * (hour as f64 - 12.0) + geog_long_deg - 15.0 * zone_correction as f64;
+            let hour_angle = hour_angle_deg.to_radians();
+
+            let (numerator, denominator) = match sundial_type {
+                pa_t::SundialType::Horizontal => {
+                    (latitude.sin() * hour_angle.sin(), hour_angle.cos())
+                }
+                pa_t::SundialType::Vertical => (
+                    latitude.cos() * hour_angle.sin(),
+                    wall_declination.cos() * hour_angle.cos()
+                        + wall_declination.sin() * latitude.sin() * hour_angle.sin(),
+                ),
+            };
+            if sundial_type == pa_t::SundialType::Vertical && denominator <= 0.0 {
+                return None;
+            }
+
+            Some(SundialHourLine {
+                hour,
+                hour_angle_deg: pa_u::round_f64(hour_angle_deg, 2),
+                line_angle_deg: pa_u::round_f64(pa_m::degrees(numerator.atan2(denominator)), 2),
+            })
+        })
+        .collect();
+
+    return (pa_u::round_f64(style_height_deg, 2), hour_lines);
+}
+
 /// Calculate solar elongation for a celestial body.
 ///
 /// Solar elongation is the angle between the lines of sight from the Earth to the Sun and from the Earth to the celestial body.
@@ -659,3 +1196,143 @@ pub fn solar_elongation(
 
     return pa_u::round_f64(solar_elongation_deg, 2);
 }
+
+/// Calculate the instant in a year when the Sun reaches an apparent ecliptic longitude.
+///
+/// The solar longitude comes from the Solar Position Algorithm (see `spa`), which makes the
+/// instant accurate to well under a minute.
+///
+/// ## Arguments
+/// * `solar_longitude_deg` -- Apparent geocentric ecliptic longitude of the Sun, in degrees.
+/// * `year` -- Year.
+///
+/// ## Returns
+/// * `SolarTerm` (with an empty name).
+pub fn time_of_solar_longitude(solar_longitude_deg: f64, year: u32) -> SolarTerm {
+    return solar_term("", solar_longitude_deg, year);
+}
+
+/// Calculate the instants of the equinoxes and solstices in a year.
+///
+/// ## Arguments
+/// * `year` -- Year.
+///
+/// ## Returns
+/// * March equinox, June solstice, September equinox and December solstice.
+pub fn equinoxes_and_solstices(year: u32) -> Vec<SolarTerm> {
+    return [
+        ("March equinox", 0.0),
+        ("June solstice", 90.0),
+        ("September equinox", 180.0),
+        ("December solstice", 270.0),
+    ]
+    .iter()
+    .map(|(name, solar_longitude_deg)| solar_term(name, *solar_longitude_deg, year))
+    .collect();
+}
+
+/// Calculate the instants of the 24 Chinese solar terms (jieqi) in a year, from Xiaohan
+/// (285 degrees, early January) to Dongzhi (270 degrees, December solstice).
+///
+/// ## Arguments
+/// * `year` -- Year.
+///
+/// ## Returns
+/// * The 24 solar terms, in date order.
+pub fn solar_terms(year: u32) -> Vec<SolarTerm> {
+    let names = [
+        "Xiaohan",
+        "Dahan",
+        "Lichun",
+        "Yushui",
+        "Jingzhe",
+        "Chunfen",
+        "Qingming",
+        "Guyu",
+        "Lixia",
+        "Xiaoman",
+        "Mangzhong",
+        "Xiazhi",
+        "Xiaoshu",
+        "Dashu",
+        "Liqiu",
+        "Chushu",
+        "Bailu",
+        "Qiufen",
+        "Hanlu",
+        "Shuangjiang",
+        "Lidong",
+        "Xiaoxue",
+        "Daxue",
+        "Dongzhi",
+    ];
+
+    return names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| solar_term(name, (285.0 + 15.0 * i as f64) % 360.0, year))
+        .collect();
+}
+
+/// Calculate the instants of the astronomical cross-quarter days in a year, when the Sun is
+/// midway in longitude between an equinox and a solstice.
+///
+/// ## Arguments
+/// * `year` -- Year.
+///
+/// ## Returns
+/// * Imbolc (315 degrees), Beltane (45 degrees), Lughnasadh (135 degrees) and Samhain (225 degrees).
+pub fn cross_quarter_days(year: u32) -> Vec<SolarTerm> {
+    return [
+        ("Imbolc", 315.0),
+        ("Beltane", 45.0),
+        ("Lughnasadh", 135.0),
+        ("Samhain", 225.0),
+    ]
+    .iter()
+    .map(|(name, solar_longitude_deg)| solar_term(name, *solar_longitude_deg, year))
+    .collect();
+}
+
+/// Instant in a year when the Sun reaches an apparent ecliptic longitude.
+fn solar_term(name: &str, solar_longitude_deg: f64, year: u32) -> SolarTerm {
+    // The Sun's longitude is about 280 degrees at the start of the year.
+    let mut jd_tt = pa_m::cd_jd(0.0, 1, year)
+        + (solar_longitude_deg - 280.0).rem_euclid(360.0) * 365.2422 / 360.0;
+
+    for _ in 0..10 {
+        let correction = 58.0
+            * (solar_longitude_deg - spa::apparent_longitude(jd_tt))
+                .to_radians()
+                .sin();
+        jd_tt += correction;
+        if correction.abs() < 0.000001 {
+            break;
+        }
+    }
+
+    let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+    let tt_day = pa_m::jdc_day(jd_tt);
+    let tt_hours = (tt_day - tt_day.floor()) * 24.0;
+    let ut_day = pa_m::jdc_day(jd_ut);
+    let ut_hours = (ut_day - ut_day.floor()) * 24.0;
+
+    return SolarTerm {
+        name: name.to_string(),
+        solar_longitude_deg,
+        jd_tt,
+        jd_ut,
+        tt_date_day: tt_day.floor() as u32,
+        tt_date_month: pa_m::jdc_month(jd_tt),
+        tt_date_year: pa_m::jdc_year(jd_tt),
+        tt_hour: pa_m::dh_hour(tt_hours),
+        tt_minutes: pa_m::dh_min(tt_hours),
+        tt_seconds: pa_m::dh_sec(tt_hours),
+        ut_date_day: ut_day.floor() as u32,
+        ut_date_month: pa_m::jdc_month(jd_ut),
+        ut_date_year: pa_m::jdc_year(jd_ut),
+        ut_hour: pa_m::dh_hour(ut_hours),
+        ut_minutes: pa_m::dh_min(ut_hours),
+        ut_seconds: pa_m::dh_sec(ut_hours),
+    };
+}
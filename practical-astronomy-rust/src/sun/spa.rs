@@ -0,0 +1,484 @@
+//! Solar position for solar energy applications, after the NREL Solar Position Algorithm
+//! (I. Reda and A. Andreas, "Solar Position Algorithm for Solar Radiation Applications",
+//! NREL/TP-560-34302, 2008), accurate to about ±0.0003° between the years -2000 and 6000.
+
+use crate::coordinates as pa_c;
+use crate::macros as pa_m;
+use crate::spadata as pa_sd;
+use crate::types as pa_t;
+
+/// Angular radius of the Sun used for sunrise and sunset, in degrees.
+const SUN_RADIUS: f64 = 0.26667;
+
+/// Info about the Sun's position from the Solar Position Algorithm:
+/// * `julian_day` -- Julian day (UT1).
+/// * `heliocentric_longitude_deg` -- Earth's heliocentric longitude, in degrees.
+/// * `heliocentric_latitude_deg` -- Earth's heliocentric latitude, in degrees.
+/// * `radius_vector_au` -- Earth's radius vector, in AU.
+/// * `geocentric_right_ascension_deg` -- Geocentric apparent right ascension of the Sun, in degrees.
+/// * `geocentric_declination_deg` -- Geocentric apparent declination of the Sun, in degrees.
+/// * `topocentric_right_ascension_deg` -- Topocentric right ascension of the Sun, in degrees.
+/// * `topocentric_declination_deg` -- Topocentric declination of the Sun, in degrees.
+/// * `topocentric_hour_angle_deg` -- Topocentric local hour angle of the Sun, in degrees.
+/// * `zenith_deg` -- Topocentric zenith angle, corrected for refraction, in degrees.
+/// * `azimuth_deg` -- Topocentric azimuth, measured eastward from north, in degrees.
+/// * `incidence_deg` -- Angle of incidence of sunlight on the surface, in degrees.
+/// * `equation_of_time_min` -- Equation of time, in minutes.
+/// * `sunrise_hours` -- Local sunrise time, in hours (-99.0 if the Sun does not rise or set).
+/// * `sun_transit_hours` -- Local time of the Sun's transit, in hours (-99.0 if the Sun does not rise or set).
+/// * `sunset_hours` -- Local sunset time, in hours (-99.0 if the Sun does not rise or set).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarPosition {
+    pub julian_day: f64,
+    pub heliocentric_longitude_deg: f64,
+    pub heliocentric_latitude_deg: f64,
+    pub radius_vector_au: f64,
+    pub geocentric_right_ascension_deg: f64,
+    pub geocentric_declination_deg: f64,
+    pub topocentric_right_ascension_deg: f64,
+    pub topocentric_declination_deg: f64,
+    pub topocentric_hour_angle_deg: f64,
+    pub zenith_deg: f64,
+    pub azimuth_deg: f64,
+    pub incidence_deg: f64,
+    pub equation_of_time_min: f64,
+    pub sunrise_hours: f64,
+    pub sun_transit_hours: f64,
+    pub sunset_hours: f64,
+}
+
+/// Calculate the position of the Sun with the Solar Position Algorithm.
+///
+/// ## Arguments
+/// * `local_hour` -- Local time, hour part.
+/// * `local_minute` -- Local time, minutes part.
+/// * `local_second` -- Local time, seconds part.
+/// * `local_day` -- Local date, day part.
+/// * `local_month` -- Local date, month part.
+/// * `local_year` -- Local date, year part.
+/// * `zone_correction_hours` -- Time zone, in hours (negative west of Greenwich), including any daylight saving.
+/// * `delta_ut1_seconds` -- UT1 - UTC, in seconds.
+/// * `delta_t_seconds` -- TT - UT1, in seconds.
+/// * `geog_longitude_deg` -- Geographical longitude of the observer, in degrees (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of the observer, in degrees.
+/// * `elevation_m` -- Elevation of the observer, in metres.
+/// * `pressure_mbar` -- Annual average local pressure, in millibars.
+/// * `temperature_c` -- Annual average local temperature, in degrees Celsius.
+/// * `surface_slope_deg` -- Slope of the surface, measured from the horizontal, in degrees.
+/// * `surface_azimuth_rotation_deg` -- Azimuth rotation of the surface, measured from south to the projection of the surface normal on the horizontal plane (east negative), in degrees.
+/// * `atmospheric_refraction_deg` -- Atmospheric refraction at sunrise and sunset, in degrees (0.5667 typical).
+///
+/// ## Returns
+/// * SolarPosition structure.
+pub fn solar_position(
+    local_hour: f64,
+    local_minute: f64,
+    local_second: f64,
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    zone_correction_hours: f64,
+    delta_ut1_seconds: f64,
+    delta_t_seconds: f64,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    elevation_m: f64,
+    pressure_mbar: f64,
+    temperature_c: f64,
+    surface_slope_deg: f64,
+    surface_azimuth_rotation_deg: f64,
+    atmospheric_refraction_deg: f64,
+) -> SolarPosition {
+    let day_fraction = (local_hour - zone_correction_hours
+        + (local_minute + (local_second + delta_ut1_seconds) / 60.0) / 60.0)
+        / 24.0;
+    let julian_day = pa_m::cd_jd(local_day + day_fraction, local_month, local_year);
+
+    let geocentric = geocentric_sun(julian_day, delta_t_seconds);
+
+    // Topocentric right ascension, declination and hour angle.
+    let hour_angle_deg = limit_degrees(
+        geocentric.sidereal_time_deg + geog_longitude_deg - geocentric.right_ascension_deg,
+    );
+    let latitude = geog_latitude_deg.to_radians();
+    let declination = geocentric.declination_deg.to_radians();
+    let hour_angle = hour_angle_deg.to_radians();
+    let xi = (8.794 / (3600.0 * geocentric.radius_vector_au)).to_radians();
+    let u = (0.99664719 * latitude.tan()).atan();
+    let x = u.cos() + elevation_m / 6378140.0 * latitude.cos();
+    let y = 0.99664719 * u.sin() + elevation_m / 6378140.0 * latitude.sin();
+
+    let delta_alpha = (-x * xi.sin() * hour_angle.sin())
+        .atan2(declination.cos() - x * xi.sin() * hour_angle.cos());
+    let topocentric_declination = ((declination.sin() - y * xi.sin()) * delta_alpha.cos())
+        .atan2(declination.cos() - x * xi.sin() * hour_angle.cos());
+    let topocentric_hour_angle = hour_angle - delta_alpha;
+
+    // Topocentric zenith and azimuth.
+    let elevation_deg = (latitude.sin() * topocentric_declination.sin()
+        + latitude.cos() * topocentric_declination.cos() * topocentric_hour_angle.cos())
+    .asin()
+    .to_degrees();
+    let refraction_deg = if elevation_deg >= -(SUN_RADIUS + atmospheric_refraction_deg) {
+        (pressure_mbar / 1010.0) * (283.0 / (273.0 + temperature_c)) * 1.02
+            / (60.0
+                * (elevation_deg + 10.3 / (elevation_deg + 5.11))
+                    .to_radians()
+                    .tan())
+    } else {
+        0.0
+    };
+    let zenith_deg = 90.0 - (elevation_deg + refraction_deg);
+
+    let azimuth_from_south_deg = limit_degrees(
+        topocentric_hour_angle
+            .sin()
+            .atan2(
+                topocentric_hour_angle.cos() * latitude.sin()
+                    - topocentric_declination.tan() * latitude.cos(),
+            )
+            .to_degrees(),
+    );
+
+    let (sunrise_hours, sun_transit_hours, sunset_hours) = sunrise_transit_sunset(
+        local_day,
+        local_month,
+        local_year,
+        zone_correction_hours,
+        delta_t_seconds,
+        geog_longitude_deg,
+        geog_latitude_deg,
+        atmospheric_refraction_deg,
+    );
+
+    return SolarPosition {
+        julian_day,
+        heliocentric_longitude_deg: geocentric.heliocentric_longitude_deg,
+        heliocentric_latitude_deg: geocentric.heliocentric_latitude_deg,
+        radius_vector_au: geocentric.radius_vector_au,
+        geocentric_right_ascension_deg: geocentric.right_ascension_deg,
+        geocentric_declination_deg: geocentric.declination_deg,
+        topocentric_right_ascension_deg: limit_degrees(
+            geocentric.right_ascension_deg + delta_alpha.to_degrees(),
+        ),
+        topocentric_declination_deg: topocentric_declination.to_degrees(),
+        topocentric_hour_angle_deg: limit_degrees(topocentric_hour_angle.to_degrees()),
+        zenith_deg,
+        azimuth_deg: limit_degrees(azimuth_from_south_deg + 180.0),
+        incidence_deg: incidence_angle(
+            zenith_deg,
+            limit_degrees(azimuth_from_south_deg + 180.0),
+            surface_slope_deg,
+            surface_azimuth_rotation_deg,
+        ),
+        equation_of_time_min: geocentric.equation_of_time_min,
+        sunrise_hours,
+        sun_transit_hours,
+        sunset_hours,
+    };
+}
+
+/// Calculate the angle of incidence of sunlight on an arbitrarily oriented surface.
+///
+/// ## Arguments
+/// * `zenith_deg` -- Zenith angle of the Sun, in degrees.
+/// * `azimuth_deg` -- Azimuth of the Sun, measured eastward from north, in degrees.
+/// * `surface_slope_deg` -- Slope of the surface, measured from the horizontal, in degrees.
+/// * `surface_azimuth_rotation_deg` -- Azimuth rotation of the surface, measured from south to the projection of the surface normal on the horizontal plane (east negative), in degrees.
+///
+/// ## Returns
+/// * Angle between the Sun and the surface normal, in degrees.
+pub fn incidence_angle(
+    zenith_deg: f64,
+    azimuth_deg: f64,
+    surface_slope_deg: f64,
+    surface_azimuth_rotation_deg: f64,
+) -> f64 {
+    let zenith = zenith_deg.to_radians();
+    let slope = surface_slope_deg.to_radians();
+
+    return (zenith.cos() * slope.cos()
+        + slope.sin()
+            * zenith.sin()
+            * (azimuth_deg - 180.0 - surface_azimuth_rotation_deg)
+                .to_radians()
+                .cos())
+    .acos()
+    .to_degrees();
+}
+
+/// Calculate local sunrise, transit and sunset times with the Solar Position Algorithm.
+///
+/// ## Arguments
+/// * `local_day` -- Date, day part.
+/// * `local_month` -- Date, month part.
+/// * `local_year` -- Date, year part.
+/// * `zone_correction_hours` -- Time zone, in hours (negative west of Greenwich), including any daylight saving.
+/// * `delta_t_seconds` -- TT - UT1, in seconds.
+/// * `geog_longitude_deg` -- Geographical longitude of the observer, in degrees (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of the observer, in degrees.
+/// * `atmospheric_refraction_deg` -- Atmospheric refraction at sunrise and sunset, in degrees (0.5667 typical).
+///
+/// ## Returns
+/// * `sunrise_hours` -- Local sunrise time, in hours (-99.0 if the Sun does not rise or set).
+/// * `sun_transit_hours` -- Local time of the Sun's transit, in hours (-99.0 if the Sun does not rise or set).
+/// * `sunset_hours` -- Local sunset time, in hours (-99.0 if the Sun does not rise or set).
+pub fn sunrise_transit_sunset(
+    local_day: f64,
+    local_month: u32,
+    local_year: u32,
+    zone_correction_hours: f64,
+    delta_t_seconds: f64,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    atmospheric_refraction_deg: f64,
+) -> (f64, f64, f64) {
+    let julian_day = pa_m::cd_jd(local_day.floor(), local_month, local_year);
+    let sidereal_time_deg = geocentric_sun(julian_day, delta_t_seconds).sidereal_time_deg;
+
+    // Right ascension and declination on the previous, same and next days, at 0h TT.
+    let positions: Vec<GeocentricSun> = (-1..=1)
+        .map(|offset| geocentric_sun(julian_day + offset as f64, 0.0))
+        .collect();
+    let right_ascensions: Vec<f64> = positions
+        .iter()
+        .map(|position| position.right_ascension_deg)
+        .collect();
+    let declinations: Vec<f64> = positions
+        .iter()
+        .map(|position| position.declination_deg)
+        .collect();
+
+    let latitude = geog_latitude_deg.to_radians();
+    let h0_prime_deg = -(SUN_RADIUS + atmospheric_refraction_deg);
+    let declination = declinations[1].to_radians();
+    let cos_h0 = (h0_prime_deg.to_radians().sin() - latitude.sin() * declination.sin())
+        / (latitude.cos() * declination.cos());
+    if cos_h0.abs() > 1.0 {
+        return (-99.0, -99.0, -99.0);
+    }
+    let h0_deg = cos_h0.acos().to_degrees();
+
+    let transit_approx = (right_ascensions[1] - geog_longitude_deg - sidereal_time_deg) / 360.0;
+    let day_fractions = [
+        limit_zero_to_one(transit_approx),
+        limit_zero_to_one(transit_approx - h0_deg / 360.0),
+        limit_zero_to_one(transit_approx + h0_deg / 360.0),
+    ];
+
+    // Local hour angle, declination and altitude at transit, rise and set.
+    let mut hour_angles_deg = [0.0; 3];
+    let mut declinations_prime_deg = [0.0; 3];
+    let mut altitudes_deg = [0.0; 3];
+    for (i, m) in day_fractions.iter().enumerate() {
+        let n = m + delta_t_seconds / 86400.0;
+        let right_ascension_prime = interpolate(&right_ascensions, n);
+        declinations_prime_deg[i] = interpolate(&declinations, n);
+        hour_angles_deg[i] = limit_degrees_180_pm(
+            sidereal_time_deg + 360.985647 * m + geog_longitude_deg - right_ascension_prime,
+        );
+        let declination_prime = declinations_prime_deg[i].to_radians();
+        altitudes_deg[i] = (latitude.sin() * declination_prime.sin()
+            + latitude.cos() * declination_prime.cos() * hour_angles_deg[i].to_radians().cos())
+        .asin()
+        .to_degrees();
+    }
+
+    let rise_or_set = |i: usize| {
+        day_fractions[i]
+            + (altitudes_deg[i] - h0_prime_deg)
+                / (360.0
+                    * declinations_prime_deg[i].to_radians().cos()
+                    * latitude.cos()
+                    * hour_angles_deg[i].to_radians().sin())
+    };
+    let to_local_hours =
+        |day_fraction: f64| 24.0 * limit_zero_to_one(day_fraction + zone_correction_hours / 24.0);
+
+    return (
+        to_local_hours(rise_or_set(1)),
+        to_local_hours(day_fractions[0] - hour_angles_deg[0] / 360.0),
+        to_local_hours(rise_or_set(2)),
+    );
+}
+
+/// Geocentric quantities of the Sun at a Julian day (UT1).
+struct GeocentricSun {
+    heliocentric_longitude_deg: f64,
+    heliocentric_latitude_deg: f64,
+    radius_vector_au: f64,
+    right_ascension_deg: f64,
+    declination_deg: f64,
+    sidereal_time_deg: f64,
+    equation_of_time_min: f64,
+}
+
+/// Geocentric apparent right ascension and declination of the Sun, apparent sidereal time at
+/// Greenwich, and equation of time, at a Julian day (UT1).
+fn geocentric_sun(julian_day: f64, delta_t_seconds: f64) -> GeocentricSun {
+    let julian_ephemeris_day = julian_day + delta_t_seconds / 86400.0;
+    let julian_century = (julian_day - 2451545.0) / 36525.0;
+    let julian_ephemeris_millennium = (julian_ephemeris_day - 2451545.0) / 365250.0;
+
+    let heliocentric_longitude_deg = limit_degrees(
+        earth_periodic_sum(&pa_sd::EARTH_LONGITUDE_TERMS, julian_ephemeris_millennium).to_degrees(),
+    );
+    let heliocentric_latitude_deg =
+        earth_periodic_sum(&pa_sd::EARTH_LATITUDE_TERMS, julian_ephemeris_millennium).to_degrees();
+    let radius_vector_au = earth_periodic_sum(
+        &pa_sd::EARTH_RADIUS_VECTOR_TERMS,
+        julian_ephemeris_millennium,
+    );
+
+    let geocentric_longitude_deg = limit_degrees(heliocentric_longitude_deg + 180.0);
+    let geocentric_latitude_deg = -heliocentric_latitude_deg;
+
+    let (nutation_longitude_deg, nutation_obliquity_deg) =
+        pa_c::nutation_in_ecliptic_longitude_and_obliquity_by_model(
+            pa_m::jdc_day(julian_ephemeris_day),
+            pa_m::jdc_month(julian_ephemeris_day),
+            pa_m::jdc_year(julian_ephemeris_day),
+            pa_t::NutationModel::Iau1980,
+        );
+
+    let u = julian_ephemeris_millennium / 10.0;
+    let mean_obliquity_arcsec = 84381.448
+        + u * (-4680.93
+            + u * (-1.55
+                + u * (1999.25
+                    + u * (-51.38
+                        + u * (-249.67
+                            + u * (-39.05 + u * (7.12 + u * (27.87 + u * (5.79 + u * 2.45)))))))));
+    let obliquity = (mean_obliquity_arcsec / 3600.0 + nutation_obliquity_deg).to_radians();
+
+    let aberration_deg = -20.4898 / (3600.0 * radius_vector_au);
+    let apparent_longitude =
+        (geocentric_longitude_deg + nutation_longitude_deg + aberration_deg).to_radians();
+    let latitude = geocentric_latitude_deg.to_radians();
+
+    let right_ascension_deg = limit_degrees(
+        (apparent_longitude.sin() * obliquity.cos() - latitude.tan() * obliquity.sin())
+            .atan2(apparent_longitude.cos())
+            .to_degrees(),
+    );
+    let declination_deg = (latitude.sin() * obliquity.cos()
+        + latitude.cos() * obliquity.sin() * apparent_longitude.sin())
+    .asin()
+    .to_degrees();
+
+    let mean_sidereal_time_deg = limit_degrees(
+        280.46061837
+            + 360.98564736629 * (julian_day - 2451545.0)
+            + julian_century * julian_century * (0.000387933 - julian_century / 38710000.0),
+    );
+    let sidereal_time_deg = mean_sidereal_time_deg + nutation_longitude_deg * obliquity.cos();
+
+    let tau = julian_ephemeris_millennium;
+    let sun_mean_longitude_deg = limit_degrees(
+        280.4664567
+            + tau
+                * (360007.6982779
+                    + tau
+                        * (0.03032028
+                            + tau * (1.0 / 49931.0 - tau * (1.0 / 15300.0 + tau / 2000000.0)))),
+    );
+    let equation_of_time_min = limit_minutes(
+        4.0 * (sun_mean_longitude_deg - 0.0057183 - right_ascension_deg
+            + nutation_longitude_deg * obliquity.cos()),
+    );
+
+    return GeocentricSun {
+        heliocentric_longitude_deg,
+        heliocentric_latitude_deg,
+        radius_vector_au,
+        right_ascension_deg,
+        declination_deg,
+        sidereal_time_deg,
+        equation_of_time_min,
+    };
+}
+
+/// Geocentric apparent ecliptic longitude of the Sun (degrees) at a Julian ephemeris day (TT),
+/// with the same Earth periodic terms, nutation and aberration as `solar_position`.
+pub(crate) fn apparent_longitude(julian_ephemeris_day: f64) -> f64 {
+    let julian_ephemeris_millennium = (julian_ephemeris_day - 2451545.0) / 365250.0;
+
+    let heliocentric_longitude_deg =
+        earth_periodic_sum(&pa_sd::EARTH_LONGITUDE_TERMS, julian_ephemeris_millennium).to_degrees();
+    let radius_vector_au = earth_periodic_sum(
+        &pa_sd::EARTH_RADIUS_VECTOR_TERMS,
+        julian_ephemeris_millennium,
+    );
+    let (nutation_longitude_deg, _nutation_obliquity_deg) =
+        pa_c::nutation_in_ecliptic_longitude_and_obliquity_by_model(
+            pa_m::jdc_day(julian_ephemeris_day),
+            pa_m::jdc_month(julian_ephemeris_day),
+            pa_m::jdc_year(julian_ephemeris_day),
+            pa_t::NutationModel::Iau1980,
+        );
+    let aberration_deg = -20.4898 / (3600.0 * radius_vector_au);
+
+    return limit_degrees(
+        heliocentric_longitude_deg + 180.0 + nutation_longitude_deg + aberration_deg,
+    );
+}
+
+/// Sum of a series of Earth periodic terms at a time in Julian millennia.
+fn earth_periodic_sum(series: &[&[[f64; 3]]], julian_ephemeris_millennium: f64) -> f64 {
+    let mut sum = 0.0;
+    for (power, terms) in series.iter().enumerate() {
+        let term_sum: f64 = terms
+            .iter()
+            .map(|term| term[0] * (term[1] + term[2] * julian_ephemeris_millennium).cos())
+            .sum();
+        sum = sum + term_sum * julian_ephemeris_millennium.powi(power as i32);
+    }
+
+    return sum / 1.0e8;
+}
+
+/// Interpolate a quantity tabulated on the previous, same and next days.
+fn interpolate(values: &[f64], n: f64) -> f64 {
+    let mut a = values[1] - values[0];
+    let mut b = values[2] - values[1];
+    if a.abs() >= 2.0 {
+        a = limit_zero_to_one(a);
+    }
+    if b.abs() >= 2.0 {
+        b = limit_zero_to_one(b);
+    }
+
+    return values[1] + n * (a + b + (b - a) * n) / 2.0;
+}
+
+/// Reduce an angle to the range 0 to 360 degrees.
+fn limit_degrees(degrees: f64) -> f64 {
+    return degrees.rem_euclid(360.0);
+}
+
+/// Reduce an angle to the range -180 to 180 degrees.
+fn limit_degrees_180_pm(degrees: f64) -> f64 {
+    let limited = limit_degrees(degrees);
+
+    return if limited > 180.0 {
+        limited - 360.0
+    } else {
+        limited
+    };
+}
+
+/// Reduce a value to its fractional part, in the range 0 to 1.
+fn limit_zero_to_one(value: f64) -> f64 {
+    return value.rem_euclid(1.0);
+}
+
+/// Reduce an equation of time to the range -20 to 20 minutes.
+fn limit_minutes(minutes: f64) -> f64 {
+    return if minutes < -20.0 {
+        minutes + 1440.0
+    } else if minutes > 20.0 {
+        minutes - 1440.0
+    } else {
+        minutes
+    };
+}
@@ -0,0 +1,367 @@
+use crate::datetime as pa_dt;
+use crate::eclipses as pa_e;
+use crate::frames as pa_f;
+use crate::macros as pa_m;
+use crate::planetdata as pa_pd;
+
+/// Info about one contact (or greatest transit) of a planet with the Sun's disk:
+/// * `jd_ut` -- Julian date of the contact (Universal Time).
+/// * `date_day` -- Date of the contact (day, Universal Time).
+/// * `date_month` -- Date of the contact (month, Universal Time).
+/// * `date_year` -- Date of the contact (year, Universal Time).
+/// * `ut_hour` -- Time of the contact (hour, Universal Time).
+/// * `ut_minutes` -- Time of the contact (minutes, Universal Time).
+/// * `ut_seconds` -- Time of the contact (seconds, Universal Time).
+/// * `position_angle_deg` -- Position angle of the planet from the Sun's center, measured from north through east.
+/// * `sun_altitude_deg` -- Altitude of the Sun, without refraction (-99.0 for geocentric circumstances).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransitContact {
+    pub jd_ut: f64,
+    pub date_day: f64,
+    pub date_month: u32,
+    pub date_year: u32,
+    pub ut_hour: u32,
+    pub ut_minutes: u32,
+    pub ut_seconds: f64,
+    pub position_angle_deg: f64,
+    pub sun_altitude_deg: f64,
+}
+
+/// Info about a transit of Mercury or Venus across the Sun:
+/// * `planet_name` -- "Mercury" or "Venus".
+/// * `first_contact` -- Contact I, the planet's disk touches the Sun's limb from outside.
+/// * `second_contact` -- Contact II, the planet's disk is entirely inside the Sun's (None for a grazing transit).
+/// * `greatest_transit` -- Least separation of the centers of the planet and the Sun.
+/// * `third_contact` -- Contact III, the planet's disk touches the Sun's limb from inside (None for a grazing transit).
+/// * `fourth_contact` -- Contact IV, the planet leaves the Sun's disk.
+/// * `minimum_separation_arcsec` -- Least separation of the centers of the planet and the Sun.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanetaryTransit {
+    pub planet_name: String,
+    pub first_contact: TransitContact,
+    pub second_contact: Option<TransitContact>,
+    pub greatest_transit: TransitContact,
+    pub third_contact: Option<TransitContact>,
+    pub fourth_contact: TransitContact,
+    pub minimum_separation_arcsec: f64,
+}
+
+/// Search for transits of Mercury or Venus across the Sun, as seen from the Earth's center.
+///
+/// Positions come from the same theory as `planet::precise_position_of_planet` and
+/// `sun::precise_position_of_sun`.
+///
+/// ## Arguments
+/// * `planet_name` -- "Mercury" or "Venus".
+/// * `start_year` -- First year to search.
+/// * `end_year` -- Last year to search.
+///
+/// ## Returns
+/// list of PlanetaryTransit structures, in order of time
+pub fn transit_search(
+    planet_name: String,
+    start_year: u32,
+    end_year: u32,
+) -> Vec<PlanetaryTransit> {
+    let mut transits: Vec<PlanetaryTransit> = Vec::new();
+    let start_jd_tt = pa_m::cd_jd(1.0, 1, start_year);
+    let end_jd_tt = pa_m::cd_jd(1.0, 1, end_year + 1);
+
+    // Difference in geocentric ecliptic longitude of the planet and the Sun (-180 to 180).
+    let elongation = |jd_tt: f64| {
+        let (planet_long_deg, _, _, sun_long_deg, _, _) =
+            planet_and_sun_ecliptic(&planet_name, jd_tt);
+        pa_m::unwind_deg(planet_long_deg - sun_long_deg + 180.0) - 180.0
+    };
+
+    let step_days = 4.0;
+    let mut jd_tt = start_jd_tt;
+    let mut previous = elongation(jd_tt);
+
+    while jd_tt < end_jd_tt {
+        let next_jd_tt = jd_tt + step_days;
+        let next = elongation(next_jd_tt);
+
+        if previous.signum() != next.signum() && (previous - next).abs() < 90.0 {
+            let mut low = jd_tt;
+            let mut high = next_jd_tt;
+            for _ in 0..40 {
+                let middle = (low + high) / 2.0;
+                if elongation(middle).signum() == previous.signum() {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            let conjunction_jd_tt = (low + high) / 2.0;
+            let (_, _, planet_distance_au, _, sun_distance_au, _) =
+                planet_and_sun_ecliptic(&planet_name, conjunction_jd_tt);
+
+            if planet_distance_au < sun_distance_au {
+                if let Some(transit) = transit_circumstances(&planet_name, conjunction_jd_tt, None)
+                {
+                    transits.push(transit);
+                }
+            }
+        }
+
+        jd_tt = next_jd_tt;
+        previous = next;
+    }
+
+    return transits;
+}
+
+/// Calculate the circumstances of a transit for an observer.
+///
+/// ## Arguments
+/// * `transit` -- Transit from `transit_search`.
+/// * `geog_longitude_deg` -- Geographical longitude of observer (east positive).
+/// * `geog_latitude_deg` -- Geographical latitude of observer.
+/// * `height_m` -- Height of observer above sea level, in metres.
+///
+/// ## Returns
+/// PlanetaryTransit structure with topocentric contact times, or None if the planet misses the
+/// Sun's disk from this location (ignoring whether the Sun is above the horizon).
+pub fn local_transit_circumstances(
+    transit: &PlanetaryTransit,
+    geog_longitude_deg: f64,
+    geog_latitude_deg: f64,
+    height_m: f64,
+) -> Option<PlanetaryTransit> {
+    let greatest_jd_tt =
+        transit.greatest_transit.jd_ut + pa_dt::delta_t_days(transit.greatest_transit.jd_ut);
+
+    return transit_circumstances(
+        &transit.planet_name,
+        greatest_jd_tt,
+        Some((geog_longitude_deg, geog_latitude_deg, height_m)),
+    );
+}
+
+/// Contacts and greatest transit within half a day of a time, if the planet crosses the Sun's
+/// disk. Topocentric when an observer (longitude east, latitude, height in metres) is given.
+fn transit_circumstances(
+    planet_name: &str,
+    around_jd_tt: f64,
+    observer: Option<(f64, f64, f64)>,
+) -> Option<PlanetaryTransit> {
+    let planet_semidiameter_deg = pa_pd::get_planet_info_vector(planet_name.to_string())
+        .0
+        .theta0
+        / 2.0
+        / 3600.0;
+    let jd_tt = |t: f64| around_jd_tt + t / 24.0;
+
+    // Separation of centers, Sun's semidiameter and planet's semidiameter (degrees).
+    let geometry = |t: f64| {
+        let (planet, sun) = apparent_positions(planet_name, jd_tt(t), &observer);
+        let sun_distance_au = vector_length(&sun) * 6378.137 / 149597870.7;
+        let planet_distance_au = vector_length(&planet) * 6378.137 / 149597870.7;
+        (
+            angle_between(&planet, &sun),
+            959.63 / 3600.0 / sun_distance_au,
+            planet_semidiameter_deg / planet_distance_au,
+        )
+    };
+    let external = |t: f64| {
+        let (separation, sun_semidiameter, planet_semidiameter) = geometry(t);
+        sun_semidiameter + planet_semidiameter - separation
+    };
+    let internal = |t: f64| {
+        let (separation, sun_semidiameter, planet_semidiameter) = geometry(t);
+        sun_semidiameter - planet_semidiameter - separation
+    };
+
+    let (first_t, fourth_t) = pa_e::crossing_times(&external, -12.0, 12.0)?;
+    let internal_contacts = pa_e::crossing_times(&internal, first_t, fourth_t);
+
+    let mut low = first_t;
+    let mut high = fourth_t;
+    for _ in 0..80 {
+        let m1 = low + (high - low) / 3.0;
+        let m2 = high - (high - low) / 3.0;
+        if geometry(m1).0 < geometry(m2).0 {
+            high = m2;
+        } else {
+            low = m1;
+        }
+    }
+    let greatest_t = (low + high) / 2.0;
+
+    let contact = |t: f64| transit_contact(planet_name, jd_tt(t), &observer);
+
+    return Some(PlanetaryTransit {
+        planet_name: planet_name.to_string(),
+        first_contact: contact(first_t),
+        second_contact: internal_contacts.map(|(second_t, _third_t)| contact(second_t)),
+        greatest_transit: contact(greatest_t),
+        third_contact: internal_contacts.map(|(_second_t, third_t)| contact(third_t)),
+        fourth_contact: contact(fourth_t),
+        minimum_separation_arcsec: geometry(greatest_t).0 * 3600.0,
+    });
+}
+
+/// Time, position angle and Sun's altitude at a Julian date (TT).
+fn transit_contact(
+    planet_name: &str,
+    jd_tt: f64,
+    observer: &Option<(f64, f64, f64)>,
+) -> TransitContact {
+    let (planet, sun) = apparent_positions(planet_name, jd_tt, observer);
+    let (planet_ra_deg, planet_dec_deg) = pa_f::vector_to_spherical(&planet);
+    let (sun_ra_deg, sun_dec_deg) = pa_f::vector_to_spherical(&sun);
+
+    let planet_dec = planet_dec_deg.to_radians();
+    let sun_dec = sun_dec_deg.to_radians();
+    let ra_difference = (planet_ra_deg - sun_ra_deg).to_radians();
+    let position_angle_deg = pa_m::unwind_deg(
+        (planet_dec.cos() * ra_difference.sin())
+            .atan2(
+                planet_dec.sin() * sun_dec.cos()
+                    - planet_dec.cos() * sun_dec.sin() * ra_difference.cos(),
+            )
+            .to_degrees(),
+    );
+
+    let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+    let day = pa_m::jdc_day(jd_ut);
+    let ut_hours = (day - day.floor()) * 24.0;
+
+    let sun_altitude_deg = match observer {
+        Some((longitude_deg, latitude_deg, _height_m)) => {
+            let hour_angle = (pa_e::apparent_sidereal_time_hours(jd_ut) * 15.0 + longitude_deg
+                - sun_ra_deg)
+                .to_radians();
+            let latitude = latitude_deg.to_radians();
+            (latitude.sin() * sun_dec.sin() + latitude.cos() * sun_dec.cos() * hour_angle.cos())
+                .asin()
+                .to_degrees()
+        }
+        None => -99.0,
+    };
+
+    return TransitContact {
+        jd_ut,
+        date_day: day.floor(),
+        date_month: pa_m::jdc_month(jd_ut),
+        date_year: pa_m::jdc_year(jd_ut),
+        ut_hour: pa_m::dh_hour(ut_hours),
+        ut_minutes: pa_m::dh_min(ut_hours),
+        ut_seconds: pa_m::dh_sec(ut_hours),
+        position_angle_deg,
+        sun_altitude_deg,
+    };
+}
+
+/// Positions of the planet and the Sun (Earth radii), seen from the Earth's center or, if given,
+/// from an observer.
+fn apparent_positions(
+    planet_name: &str,
+    jd_tt: f64,
+    observer: &Option<(f64, f64, f64)>,
+) -> (pa_f::Vector3, pa_f::Vector3) {
+    let (planet, sun) = planet_and_sun_position(planet_name, jd_tt);
+
+    return match observer {
+        Some((longitude_deg, latitude_deg, height_m)) => {
+            let latitude = latitude_deg.to_radians();
+            let u = (0.99664719 * latitude.tan()).atan();
+            let rho_sin_phi = 0.99664719 * u.sin() + height_m / 6378137.0 * latitude.sin();
+            let rho_cos_phi = u.cos() + height_m / 6378137.0 * latitude.cos();
+
+            let jd_ut = jd_tt - pa_dt::delta_t_days(jd_tt);
+            let local_sidereal_time =
+                (pa_e::apparent_sidereal_time_hours(jd_ut) * 15.0 + longitude_deg).to_radians();
+            let position = pa_f::Vector3 {
+                x: rho_cos_phi * local_sidereal_time.cos(),
+                y: rho_cos_phi * local_sidereal_time.sin(),
+                z: rho_sin_phi,
+            };
+
+            (
+                pa_f::Vector3 {
+                    x: planet.x - position.x,
+                    y: planet.y - position.y,
+                    z: planet.z - position.z,
+                },
+                pa_f::Vector3 {
+                    x: sun.x - position.x,
+                    y: sun.y - position.y,
+                    z: sun.z - position.z,
+                },
+            )
+        }
+        None => (planet, sun),
+    };
+}
+
+/// Geocentric equatorial positions (Earth radii) of a planet and the Sun at a Julian date (TT).
+pub(crate) fn planet_and_sun_position(
+    planet_name: &str,
+    jd_tt: f64,
+) -> (pa_f::Vector3, pa_f::Vector3) {
+    let (
+        planet_long_deg,
+        planet_lat_deg,
+        planet_distance_au,
+        sun_long_deg,
+        sun_distance_au,
+        obliquity_deg,
+    ) = planet_and_sun_ecliptic(planet_name, jd_tt);
+
+    let to_equatorial = |long_deg: f64, lat_deg: f64, distance_au: f64| {
+        let equatorial = pa_f::rotate_vector(
+            &pa_f::rotation_matrix_x(-obliquity_deg),
+            &pa_f::spherical_to_vector(long_deg, lat_deg),
+        );
+        let distance = distance_au * 149597870.7 / 6378.137;
+        pa_f::Vector3 {
+            x: equatorial.x * distance,
+            y: equatorial.y * distance,
+            z: equatorial.z * distance,
+        }
+    };
+
+    return (
+        to_equatorial(planet_long_deg, planet_lat_deg, planet_distance_au),
+        to_equatorial(sun_long_deg, 0.0, sun_distance_au),
+    );
+}
+
+/// Geocentric ecliptic coordinates of a planet and the Sun at a Julian date (TT), as used by
+/// `planet::precise_position_of_planet` and `sun::precise_position_of_sun`:
+/// planet longitude, latitude and distance (AU), Sun longitude and distance (AU), and the
+/// obliquity of the ecliptic.
+fn planet_and_sun_ecliptic(planet_name: &str, jd_tt: f64) -> (f64, f64, f64, f64, f64, f64) {
+    let day = pa_m::jdc_day(jd_tt);
+    let gd = day.floor();
+    let gm = pa_m::jdc_month(jd_tt);
+    let gy = pa_m::jdc_year(jd_tt);
+    let hours = (day - gd) * 24.0;
+
+    let (planet_long_deg, planet_lat_deg, planet_distance_au, _, _, _, _) =
+        pa_m::planet_coordinates(hours, 0.0, 0.0, 0, 0, gd, gm, gy, planet_name.to_string());
+
+    return (
+        planet_long_deg,
+        planet_lat_deg,
+        planet_distance_au,
+        pa_m::sun_long(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::sun_dist(hours, 0.0, 0.0, 0, 0, gd, gm, gy),
+        pa_m::obliq(gd, gm, gy),
+    );
+}
+
+/// Length of a vector.
+fn vector_length(vector: &pa_f::Vector3) -> f64 {
+    return (vector.x * vector.x + vector.y * vector.y + vector.z * vector.z).sqrt();
+}
+
+/// Angle (degrees) between two vectors.
+fn angle_between(a: &pa_f::Vector3, b: &pa_f::Vector3) -> f64 {
+    let cross = pa_f::cross_product(a, b);
+    let cosine = a.x * b.x + a.y * b.y + a.z * b.z;
+
+    return vector_length(&cross).atan2(cosine).to_degrees();
+}
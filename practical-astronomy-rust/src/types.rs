@@ -5,8 +5,196 @@ pub enum TwilightType {
     Astronomical,
 }
 
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhotographicLight {
+    GoldenHour,
+    BlueHour,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LunarFeatureLighting {
+    Illuminated,
+    Terminator,
+    Dark,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LunarEventKind {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+    Perigee,
+    Apogee,
+    AscendingNode,
+    DescendingNode,
+    MaximumNorthDeclination,
+    MaximumSouthDeclination,
+    MajorStandstill,
+    MinorStandstill,
+    Supermoon,
+    BlueMoon,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlanetaryPhenomenonKind {
+    InferiorConjunction,
+    SuperiorConjunction,
+    Opposition,
+    GreatestEasternElongation,
+    GreatestWesternElongation,
+    StationaryRetrograde,
+    StationaryDirect,
+    Perihelion,
+    Aphelion,
+    Conjunction,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum YallopVisibility {
+    /// Easily visible (q > +0.216).
+    A,
+    /// Visible under perfect conditions (q > -0.014).
+    B,
+    /// May need optical aid to find the crescent (q > -0.160).
+    C,
+    /// Will need optical aid to find the crescent (q > -0.232).
+    D,
+    /// Not visible with a telescope (q > -0.293).
+    E,
+    /// Not visible, below the Danjon limit.
+    F,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OdehVisibility {
+    NakedEye,
+    OpticalAidOrNakedEye,
+    OpticalAidOnly,
+    NotVisible,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SundialType {
+    Horizontal,
+    Vertical,
+}
+
 #[allow(dead_code)]
 pub enum AngleMeasure {
     Hours,
     Degrees,
 }
+
+#[allow(dead_code)]
+pub enum NutationModel {
+    Iau1980,
+    Iau2000B,
+}
+
+#[allow(dead_code)]
+pub enum SiderealTimeModel {
+    Iau1982,
+    Iau2006,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReferenceFrame {
+    Icrs,
+    MeanOfDate,
+    TrueOfDate,
+    EclipticOfDate,
+    EclipticJ2000,
+    Galactic,
+    Supergalactic,
+    Horizon,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MagnitudeModel {
+    /// The book's model, from `v0` and the illuminated fraction.
+    Book,
+    /// Mallama & Hilton (2018), with phase curves and Saturn's rings.
+    MallamaHilton2018,
+}
+
+#[allow(dead_code)]
+pub enum EclipticEquinox {
+    J2000,
+    OfDate,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RefractionModel {
+    Book,
+    Bennett,
+    Saemundsson,
+    RayTracing,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoordinateType {
+    True,
+    Apparent,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EclipseKind {
+    Solar,
+    Lunar,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EclipseType {
+    Total,
+    Annular,
+    Hybrid,
+    Partial,
+    Penumbral,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShadowEnlargement {
+    Chauvenet,
+    Danjon,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OccultationTargetKind {
+    Star,
+    Planet,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RiseSetBodyKind {
+    Sun,
+    Moon,
+    Planet,
+    Comet,
+    Star,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RiseSetEventKind {
+    Rise,
+    Set,
+    UpperTransit,
+    LowerTransit,
+}
@@ -26,6 +26,11 @@ pub fn round_f64(input_value: f64, places: usize) -> f64 {
         .unwrap();
 }
 
+/// Convert an angle in degrees to the equivalent angle in the range -180 to 180 degrees.
+pub(crate) fn wrap_deg(angle_deg: f64) -> f64 {
+    return angle_deg - 360.0 * ((angle_deg + 180.0) / 360.0).floor();
+}
+
 /// Convert a Universal Time hour to Local Time
 pub fn get_local_hour_from_ut(
     input_hour: f64,